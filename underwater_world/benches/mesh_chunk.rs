@@ -0,0 +1,48 @@
+// Times meshing the densest chunks around the origin, run with `cargo bench`
+//
+// Per chunk, on the same machine, before and after verts were looked up by cube edge
+// instead of scanning every vert so far (the old scan also welded fewer of them):
+//           before     after
+// Full      5.57ms     1.73ms
+// Half      581us      423us
+// Quarter   137us      115us
+// (1155 verts on average before, 722 after)
+use underwater_world::{chunk, density};
+
+const SEED: u32 = 1;
+const SEARCH_DIST: i32 = 4;
+const DENSE_CHUNKS: usize = 16;
+const ROUNDS: u32 = 10;
+
+fn main() {
+    let density = density::default_density(SEED);
+
+    // dense = the most verts, which is where the vertex lookups cost the most
+    let mut chunks = Vec::new();
+    for x in -SEARCH_DIST..SEARCH_DIST {
+        for y in -SEARCH_DIST..SEARCH_DIST {
+            for z in -2..=2 {
+                let pos = (x, y, z);
+                let mesh = chunk::mesh_chunk(pos, chunk::Lod::Full, chunk::Borders::Seamless, density.as_ref());
+                chunks.push((mesh.verts.len(), pos));
+            }
+        }
+    }
+    chunks.sort_unstable_by(|a, b| b.cmp(a));
+    chunks.truncate(DENSE_CHUNKS);
+
+    let total_verts: usize = chunks.iter().map(|(verts, _pos)| verts).sum();
+    println!("{} densest chunks, {} verts on average", DENSE_CHUNKS, total_verts / DENSE_CHUNKS);
+
+    for lod in [chunk::Lod::Full, chunk::Lod::Half, chunk::Lod::Quarter] {
+        let start = std::time::Instant::now();
+        for _ in 0..ROUNDS {
+            for (_verts, pos) in &chunks {
+                let mesh = chunk::mesh_chunk(*pos, lod, chunk::Borders::Seamless, density.as_ref());
+                std::hint::black_box(mesh);
+            }
+        }
+        let per_chunk = start.elapsed() / (ROUNDS * DENSE_CHUNKS as u32);
+        println!("{:?}: {:?} per chunk", lod, per_chunk);
+    }
+}
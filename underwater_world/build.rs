@@ -0,0 +1,25 @@
+// Builds every file in fish/ into the game (see species.rs), for the web build and for
+// native runs without the fish folder next to them
+use std::io::Write;
+
+fn main() {
+    let fish_dir = std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("fish");
+    println!("cargo:rerun-if-changed={}", fish_dir.display());
+
+    let mut files: Vec<_> = std::fs::read_dir(&fish_dir)
+        .expect("fish/ should be next to Cargo.toml")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+
+    let out_path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("fish_files.rs");
+    let mut out = std::fs::File::create(out_path).unwrap();
+    writeln!(out, "const FISH_FILES: &[(&str, &[u8])] = &[").unwrap();
+    for path in files {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let name = path.file_name().unwrap().to_str().unwrap();
+        writeln!(out, "    ({:?}, include_bytes!({:?})),", name, path.display().to_string()).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
@@ -0,0 +1,199 @@
+// Mouse and gamepad steering for the sub, combined with the keys in sub::Keys
+
+// pixels per second of mouse movement for a full turn rate
+const MOUSE_FULL_TURN_SPEED: f32 = 600.0;
+// stick values under this are treated as centered, worn sticks rarely rest at exactly 0
+const STICK_DEADZONE: f32 = 0.15;
+
+
+// Each axis is from -1 to 1, with the same sign as the keys.
+// With the default keys pitch is S - W, yaw is A - D, roll is E - Q and throttle is space - control
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AnalogAxes {
+    pub pitch: f32,
+    pub yaw: f32,
+    pub roll: f32,
+    pub throttle: f32,
+}
+impl AnalogAxes {
+    fn add(&mut self, other: AnalogAxes) {
+        self.pitch += other.pitch;
+        self.yaw += other.yaw;
+        self.roll += other.roll;
+        self.throttle += other.throttle;
+    }
+}
+
+pub struct AnalogInput {
+    // the cursor is locked to the window and its movement steers the sub
+    mouse_steering: bool,
+    // pixels moved that no step has used yet
+    mouse_delta: (f64, f64),
+
+    gamepads: Gamepads,
+}
+
+impl AnalogInput {
+    pub fn new() -> Self {
+        Self {
+            mouse_steering: false,
+            mouse_delta: (0.0, 0.0),
+            gamepads: Gamepads::new(),
+        }
+    }
+
+    pub fn mouse_steering(&self) -> bool { self.mouse_steering }
+
+    pub fn set_mouse_steering(&mut self, enabled: bool, window: &winit::window::Window) {
+        self.mouse_steering = enabled;
+        self.mouse_delta = (0.0, 0.0);
+
+        let grab = if enabled {
+            // not every platform can lock the cursor in place, confining it still keeps it in the window
+            window.set_cursor_grab(winit::window::CursorGrabMode::Locked)
+                .or_else(|_| window.set_cursor_grab(winit::window::CursorGrabMode::Confined))
+        } else {
+            window.set_cursor_grab(winit::window::CursorGrabMode::None)
+        };
+        if let Err(e) = grab {
+            log::warn!("Couldn't grab the cursor: {}", e);
+        }
+        window.set_cursor_visible(!enabled);
+    }
+
+    // Raw mouse movement, which keeps coming while the cursor is locked
+    pub fn process_device_event(&mut self, event: &winit::event::DeviceEvent) {
+        if let winit::event::DeviceEvent::MouseMotion { delta } = event {
+            if self.mouse_steering {
+                self.mouse_delta.0 += delta.0;
+                self.mouse_delta.1 += delta.1;
+            }
+        }
+    }
+
+    // The mouse and every connected gamepad added together for one of the steps left this frame,
+    // clamped to -1 to 1. Each step takes an even share of the mouse movement, so the shares of
+    // the steps that don't get to run are left for the next frame
+    pub fn axes(&mut self, step_delta: f32, steps_left: u32) -> AnalogAxes {
+        let mut axes = self.gamepads.axes();
+
+        if self.mouse_steering && steps_left > 0 {
+            let share = 1.0 / steps_left as f64;
+            let (dx, dy) = (self.mouse_delta.0 * share, self.mouse_delta.1 * share);
+            self.mouse_delta.0 -= dx;
+            self.mouse_delta.1 -= dy;
+            axes.add(AnalogAxes {
+                pitch: dy as f32 / step_delta / MOUSE_FULL_TURN_SPEED,
+                yaw: -dx as f32 / step_delta / MOUSE_FULL_TURN_SPEED,
+                ..Default::default()
+            });
+        }
+
+        AnalogAxes {
+            pitch: axes.pitch.clamp(-1.0, 1.0),
+            yaw: axes.yaw.clamp(-1.0, 1.0),
+            roll: axes.roll.clamp(-1.0, 1.0),
+            throttle: axes.throttle.clamp(-1.0, 1.0),
+        }
+    }
+}
+
+// Rescales so the stick goes from 0 at the edge of the deadzone to 1 at the edge
+fn apply_deadzone(value: f32) -> f32 {
+    if value.abs() < STICK_DEADZONE {
+        0.0
+    } else {
+        value.signum() * (value.abs() - STICK_DEADZONE) / (1.0 - STICK_DEADZONE)
+    }
+}
+
+// Left stick pitches and yaws, right stick rolls, right trigger speeds up and left trigger slows down
+cfg_if::cfg_if! {
+    if #[cfg(target_arch = "wasm32")] {
+        use wasm_bindgen::JsCast;
+
+        // Indices into the browser's "standard" gamepad mapping
+        const LEFT_STICK_X: u32 = 0;
+        const LEFT_STICK_Y: u32 = 1;
+        const RIGHT_STICK_X: u32 = 2;
+        const LEFT_TRIGGER: u32 = 6;
+        const RIGHT_TRIGGER: u32 = 7;
+
+        struct Gamepads;
+        impl Gamepads {
+            fn new() -> Self { Self }
+
+            fn axes(&mut self) -> AnalogAxes {
+                let mut axes = AnalogAxes::default();
+
+                let gamepads = match web_sys::window().and_then(|win| win.navigator().get_gamepads().ok()) {
+                    Some(gamepads) => gamepads,
+                    None => return axes,
+                };
+                for gamepad in gamepads.iter() {
+                    // disconnected slots are null
+                    let gamepad = match gamepad.dyn_into::<web_sys::Gamepad>() {
+                        Ok(gamepad) if gamepad.connected() => gamepad,
+                        _ => continue,
+                    };
+
+                    let stick_axes = gamepad.axes();
+                    let stick = |i: u32| apply_deadzone(stick_axes.get(i).as_f64().unwrap_or(0.0) as f32);
+                    let buttons = gamepad.buttons();
+                    let trigger = |i: u32| buttons.get(i)
+                        .dyn_into::<web_sys::GamepadButton>()
+                        .map_or(0.0, |button| button.value() as f32);
+
+                    // web sticks are positive downwards
+                    axes.add(AnalogAxes {
+                        pitch: stick(LEFT_STICK_Y),
+                        yaw: -stick(LEFT_STICK_X),
+                        roll: stick(RIGHT_STICK_X),
+                        throttle: trigger(RIGHT_TRIGGER) - trigger(LEFT_TRIGGER),
+                    });
+                }
+
+                axes
+            }
+        }
+    } else {
+        struct Gamepads {
+            // None if the platform's gamepad backend couldn't start
+            gilrs: Option<gilrs::Gilrs>,
+        }
+        impl Gamepads {
+            fn new() -> Self {
+                let gilrs = gilrs::Gilrs::new()
+                    .map_err(|e| log::warn!("Gamepads are unavailable: {}", e))
+                    .ok();
+                Self { gilrs }
+            }
+
+            fn axes(&mut self) -> AnalogAxes {
+                let mut axes = AnalogAxes::default();
+                let gilrs = match self.gilrs.as_mut() {
+                    Some(gilrs) => gilrs,
+                    None => return axes,
+                };
+
+                // gilrs only updates its gamepad state while its events are read
+                while gilrs.next_event().is_some() {}
+
+                for (_id, gamepad) in gilrs.gamepads() {
+                    let stick = |axis| apply_deadzone(gamepad.value(axis));
+                    let trigger = |button| gamepad.button_data(button).map_or(0.0, |data| data.value());
+
+                    // gilrs sticks are positive upwards
+                    axes.add(AnalogAxes {
+                        pitch: -stick(gilrs::Axis::LeftStickY),
+                        yaw: -stick(gilrs::Axis::LeftStickX),
+                        roll: stick(gilrs::Axis::RightStickX),
+                        throttle: trigger(gilrs::Button::RightTrigger2) - trigger(gilrs::Button::LeftTrigger2),
+                    });
+                }
+
+                axes
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{chunk, consts};
+use noise::NoiseFn;
+
+// Biome noise is sampled in chunk units, so one "cell" is about this many chunks across
+const BIOME_SCALE: f64 = 12.0;
+// How far (in noise units) on each side of a border the biomes are blended
+const BIOME_BLEND: f32 = 0.12;
+// Keeps the biome noise independent from the terrain noise of the same seed
+const BIOME_SEED_OFFSET_A: u32 = 0x9e37_79b9;
+const BIOME_SEED_OFFSET_B: u32 = 0x7f4a_7c15;
+
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Biome {
+    KelpPlains = 0,
+    Trench = 1,
+    CoralReef = 2,
+    VolcanicVents = 3,
+}
+pub const ALL_BIOMES: [Biome; 4] = [Biome::KelpPlains, Biome::Trench, Biome::CoralReef, Biome::VolcanicVents];
+const BIOME_COUNT: usize = ALL_BIOMES.len();
+
+pub struct BiomeParams {
+    // density shape, see perlin_util::shaped_iso
+    pub height_offset: f32,
+    pub noise_amp: f32,
+    pub terrace: f32,
+
+    pub min_hue: f32,
+    pub max_hue: f32,
+
+    pub light_color: [f32; 3],
+    pub dark_color: [f32; 3],
+}
+
+// Kelp plains are the original look of the world
+const KELP_PLAINS: BiomeParams = BiomeParams {
+    height_offset: 0.0,
+    noise_amp: 1.0,
+    terrace: chunk::ADJ_Z_MOD,
+    min_hue: chunk::MIN_HUE,
+    max_hue: chunk::MAX_HUE,
+    light_color: consts::LIGHT_COLOR,
+    dark_color: consts::DARK_COLOR,
+};
+const TRENCH: BiomeParams = BiomeParams {
+    height_offset: 1.5,
+    noise_amp: 1.3,
+    terrace: 0.4,
+    min_hue: -210.0,
+    max_hue: -110.0,
+    light_color: [8.0, 22.0, 48.0],
+    dark_color: [2.0, 3.0, 18.0],
+};
+const CORAL_REEF: BiomeParams = BiomeParams {
+    height_offset: -0.3,
+    noise_amp: 1.2,
+    terrace: 0.15,
+    min_hue: -70.0,
+    max_hue: 40.0,
+    light_color: [22.0, 62.0, 82.0],
+    dark_color: [5.0, 12.0, 42.0],
+};
+const VOLCANIC_VENTS: BiomeParams = BiomeParams {
+    height_offset: 0.3,
+    noise_amp: 0.8,
+    terrace: 0.5,
+    min_hue: -30.0,
+    max_hue: 35.0,
+    light_color: [38.0, 30.0, 44.0],
+    dark_color: [14.0, 6.0, 20.0],
+};
+
+impl Biome {
+    pub fn params(&self) -> &'static BiomeParams {
+        match self {
+            Biome::KelpPlains => &KELP_PLAINS,
+            Biome::Trench => &TRENCH,
+            Biome::CoralReef => &CORAL_REEF,
+            Biome::VolcanicVents => &VOLCANIC_VENTS,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Biome::KelpPlains => "Kelp Plains",
+            Biome::Trench => "Trench",
+            Biome::CoralReef => "Coral Reef",
+            Biome::VolcanicVents => "Volcanic Vents",
+        }
+    }
+
+    // as used in fish/species.toml
+    pub fn key(&self) -> &'static str {
+        match self {
+            Biome::KelpPlains => "kelp_plains",
+            Biome::Trench => "trench",
+            Biome::CoralReef => "coral_reef",
+            Biome::VolcanicVents => "volcanic_vents",
+        }
+    }
+}
+
+
+// How much of each biome is at a point, always sums to 1
+#[derive(Copy, Clone, Debug)]
+pub struct BiomeWeights {
+    weights: [f32; BIOME_COUNT],
+}
+impl BiomeWeights {
+    pub fn single(biome: Biome) -> Self {
+        let mut weights = [0.0; BIOME_COUNT];
+        weights[biome as usize] = 1.0;
+        Self { weights }
+    }
+
+    // (biome, weight) for every biome with a weight above 0
+    pub fn iter(&self) -> impl Iterator<Item = (Biome, f32)> + '_ {
+        ALL_BIOMES.iter()
+            .map(|biome| (*biome, self.weights[*biome as usize]))
+            .filter(|(_biome, weight)| *weight > 0.0)
+    }
+
+    pub fn dominant(&self) -> Biome {
+        ALL_BIOMES.iter()
+            .copied()
+            .max_by(|a, b| self.weights[*a as usize].partial_cmp(&self.weights[*b as usize]).unwrap())
+            .unwrap()
+    }
+
+    fn blend(&self, f: impl Fn(&BiomeParams) -> f32) -> f32 {
+        self.iter().map(|(biome, weight)| f(biome.params()) * weight).sum()
+    }
+
+    fn blend_color(&self, f: impl Fn(&BiomeParams) -> [f32; 3]) -> [f32; 3] {
+        [
+            self.blend(|params| f(params)[0]),
+            self.blend(|params| f(params)[1]),
+            self.blend(|params| f(params)[2]),
+        ]
+    }
+
+    pub fn hue_range(&self) -> (f32, f32) {
+        (self.blend(|params| params.min_hue), self.blend(|params| params.max_hue))
+    }
+
+    pub fn light_color(&self) -> [f32; 3] { self.blend_color(|params| params.light_color) }
+    pub fn dark_color(&self) -> [f32; 3] { self.blend_color(|params| params.dark_color) }
+
+    // a per biome value (like a species' biome weight) blended the same way as the biome params
+    pub fn blend_by_biome(&self, f: impl Fn(Biome) -> f32) -> f32 {
+        self.iter().map(|(biome, weight)| f(biome) * weight).sum()
+    }
+}
+impl Default for BiomeWeights {
+    fn default() -> Self { Self::single(Biome::KelpPlains) }
+}
+
+
+// Two low frequency noise fields in XY pick the biome:
+// a < 0 && b < 0 -> kelp plains, a > 0 && b < 0 -> trench,
+// a < 0 && b > 0 -> coral reef,  a > 0 && b > 0 -> volcanic vents
+pub struct BiomeMap {
+    perlin_a: noise::Perlin,
+    perlin_b: noise::Perlin,
+}
+impl BiomeMap {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            perlin_a: noise::Perlin::new(seed ^ BIOME_SEED_OFFSET_A),
+            perlin_b: noise::Perlin::new(seed ^ BIOME_SEED_OFFSET_B),
+        }
+    }
+
+    // x and y are in chunk units
+    pub fn weights_at(&self, x: f64, y: f64) -> BiomeWeights {
+        let point = [x / BIOME_SCALE, y / BIOME_SCALE];
+        let a = self.perlin_a.get(point) as f32;
+        let b = self.perlin_b.get(point) as f32;
+
+        let u = blend_factor(a);
+        let v = blend_factor(b);
+
+        let mut weights = [0.0; BIOME_COUNT];
+        weights[Biome::KelpPlains as usize]    = (1.0 - u) * (1.0 - v);
+        weights[Biome::Trench as usize]        = u * (1.0 - v);
+        weights[Biome::CoralReef as usize]     = (1.0 - u) * v;
+        weights[Biome::VolcanicVents as usize] = u * v;
+
+        BiomeWeights { weights }
+    }
+}
+
+fn blend_factor(noise: f32) -> f32 {
+    let t = ((noise + BIOME_BLEND) / (2.0 * BIOME_BLEND)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
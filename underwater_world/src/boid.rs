@@ -1,213 +1,270 @@
 use std::collections::HashMap;
 
-use crate::{boid_obj, chunk, draw, perlin_util, sub, texture, util, world};
+use crate::{boid_compute, boid_step, chunk, density, draw, population, species, sub, texture, util, world};
 use cgmath::{InnerSpace, Zero, num_traits::Pow};
 use rand::prelude::*;
 use wgpu::util::DeviceExt;
 
-const MIN_SPEED: f32 = 3.0;
-const MAX_SPEED: f32 = 6.0;
-const MIDDLE_SPEED: f32 = (MIN_SPEED + MAX_SPEED) / 2.0;
-
-const PERCEPTION_RADIUS: f32 = 5.0;
+// Defaults for BoidSettings
 const AVOIDANCE_RADIUS: f32 = 2.0;
+// in chunks, about where the fog hides fish coming and going
+const POPULATION_DIST: i32 = 5;
+const MAX_POPULATES_PER_FRAME: usize = 8;
+const MAX_BOIDS: usize = 600;
 
 const WALL_RANGE: i32 = 3;
+// in chunks past population_dist, the fish see walls in the chunks next to theirs (diagonally too)
+const COLLISION_MARGIN: i32 = 2;
 const WALL_FORCE_MULT: f32 = 1.0;
 const WALL_FORCE_DECAY: f32 = 100.0;
 const RAY_DIRECTION_COUNT: usize = 16;
 
+// Defaults for FlockWeights
+const SEPARATION_WEIGHT: f32 = 1.0;
+const ALIGNMENT_WEIGHT: f32 = 1.0;
+const COHESION_WEIGHT: f32 = 1.0;
 const MAX_STEER_FORCE: f32 = 4.0;
-
-const DOWN_STEER_MID_Z: f32 = -5.0;
 const DOWN_STEER_MULT: f32 = 0.1;
 
-// Note: this is the number of boids per species
-const NUM_BOIDS: usize = 100;
+// Predators and prey
+const FLEE_WEIGHT: f32 = 2.0;
+const HUNT_WEIGHT: f32 = 1.5;
+// seconds prey keep fleeing after losing sight of the predator
+const PANIC_TIME: f32 = 2.0;
+// a predator catches prey this far past its own scale (about its length to the nose)
+const CATCH_DIST: f32 = 0.5;
+// seconds a predator leaves prey alone after catching one
+const FULL_TIME: f32 = 8.0;
+// seconds until caught prey is respawned somewhere out of sight
+const RESPAWN_TIME: f32 = 20.0;
+// seconds until trying again when there was nowhere to respawn it (or no room)
+const RESPAWN_RETRY_TIME: f32 = 1.0;
+
+// Reactions to the sub, see species::SubReaction
+// the sub is big, so it is seen from further away than other fish
+const SUB_SIGHT_MULT: f32 = 2.0;
+// skittish fish scatter when the sub closes in on them faster than this
+const SCATTER_SPEED: f32 = 3.0;
+// curious fish stay about this far behind the sub, light seekers this far in front of it
+const FOLLOW_DIST: f32 = 6.0;
+// light seekers notice the headlight from this far away
+const LIGHT_SIGHT_DIST: f32 = 24.0;
+const SUB_PULL_WEIGHT: f32 = 1.0;
 
-const WRAP_STRENGTH: f32 = 1.99;
 const ISO_PADDING: f32 = 0.075;
-const NEW_Z_STEP: f32 = 2.0;
-
-const POS_RANGE_XY: f32 = 30.0;
-const CENTER_OFFSET_XY: f32 = 20.0;
-const POS_RANGE_Z: f32 = 12.0;
-
-const SPAT_PART_SIZE: f32 = PERCEPTION_RADIUS;
-
-const FISH_SCALE: f32 = 0.75;
+// random spots tried when looking for water in a chunk to spawn a school in
+const SPAWN_TRIES: usize = 20;
+// how far from the school's center each fish starts
+const SCHOOL_SPREAD: f32 = 2.0;
+// how much each fish's starting heading differs from the school's
+const SCHOOL_HEADING_JITTER: f32 = 0.3;
+
+// the instance buffers start with room for this many fish, then double or halve as needed
+const INITIAL_INST_CAPACITY: usize = 64;
+
+
+// The [boids] section of the settings file
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+// (speeds, perception and flock sizes are per species, in fish/species.toml)
+pub struct BoidSettings {
+    // in chunks, fish are spawned in the chunks this close to the sub and despawned past it
+    pub population_dist: i32,
+    // per simulation step
+    pub max_populates_per_frame: usize,
+    // spawning stops at this many fish
+    pub max_boids: usize,
+    pub avoidance_radius: f32,
+    pub wall_force_decay: f32,
+    // run the flocking step in a compute shader where they are supported (not on WebGL)
+    pub gpu_step: bool,
+    // also run the step on the CPU and log a warning when they don't match
+    pub verify_gpu_step: bool,
+    // by species name
+    pub flocking: HashMap<String, FlockWeights>,
+}
+impl Default for BoidSettings {
+    fn default() -> Self {
+        Self {
+            population_dist: POPULATION_DIST,
+            max_populates_per_frame: MAX_POPULATES_PER_FRAME,
+            max_boids: MAX_BOIDS,
+            avoidance_radius: AVOIDANCE_RADIUS,
+            wall_force_decay: WALL_FORCE_DECAY,
+            gpu_step: true,
+            verify_gpu_step: false,
+            flocking: HashMap::new(),
+        }
+    }
+}
+impl BoidSettings {
+    // species left out of the settings file use the default weights
+    pub fn flock_weights(&self, species_name: &str) -> FlockWeights {
+        self.flocking.get(species_name).copied().unwrap_or_default()
+    }
 
+    pub fn flock_weights_mut(&mut self, species_name: &str) -> &mut FlockWeights {
+        self.flocking.entry(species_name.to_string()).or_default()
+    }
+}
 
+// How strongly one species steers, the [boids.flocking.<species>] sections of the settings file
+#[derive(Copy, Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct FlockWeights {
+    pub separation: f32,
+    pub alignment: f32,
+    pub cohesion: f32,
+    pub max_steer_force: f32,
+    pub wall_force_mult: f32,
+    // also steers back up below the species' min_z
+    pub down_steer_mult: f32,
+}
+impl Default for FlockWeights {
+    fn default() -> Self {
+        Self {
+            separation: SEPARATION_WEIGHT,
+            alignment: ALIGNMENT_WEIGHT,
+            cohesion: COHESION_WEIGHT,
+            max_steer_force: MAX_STEER_FORCE,
+            wall_force_mult: WALL_FORCE_MULT,
+            down_steer_mult: DOWN_STEER_MULT,
+        }
+    }
+}
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum Species {
-    Red = 0,
-    Green = 1,
-    Blue = 2,
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum BoidState {
+    // just flocking
+    Cruise,
+    // from the predators it saw, at up to its panic speed
+    Flee { timer: f32 },
+    // towards the nearest prey
+    Hunt,
 }
-pub const ALL_SPECIES: [Species; 3] = [Species::Red, Species::Green, Species::Blue];
-const SPECIES_COUNT: usize = ALL_SPECIES.len();
-const SPECIES_TEXTURE_PATHS: [&str; SPECIES_COUNT] = [
-    "red.jpg",
-    "green.png",
-    "blue.jpg",
-];
 
 struct Boid {
     pos: cgmath::Vector3<f32>,
     vel: cgmath::Vector3<f32>,
     wall_accel: cgmath::Vector3<f32>,
 
-    sum_flock_heading: cgmath::Vector3<f32>,    // alignment
-    sum_flock_center: cgmath::Vector3<f32>,     // cohesion
-    sum_flock_separation: cgmath::Vector3<f32>, // separation
-
-    spat_part_key: (i32, i32, i32),
-    spat_part_key_start: (i32, i32, i32),
-    spat_part_key_end: (i32, i32, i32),
+    // away from every predator seen in the last step (and the sub, if skittish), zero if none
+    sum_threat: cgmath::Vector3<f32>,
+    // (index, position, distance) of the nearest prey seen in the last step
+    nearest_prey: Option<(usize, cgmath::Vector3<f32>, f32)>,
+    // towards where the fish wants to be around the sub, zero if nowhere
+    sub_pull: cgmath::Vector3<f32>,
+    // keeping away from the sub, added to the separation from the other fish
+    sub_separation: cgmath::Vector3<f32>,
 
-    num_flockmates: usize,
+    state: BoidState,
+    // kept while the flee timer runs out, after the predator is gone
+    flee_dir: cgmath::Vector3<f32>,
+    full_timer: f32,
+    caught: bool,
 
-    species: Species,
+    // index into BoidManager::per_species
+    species: usize,
 
     rot_mat: cgmath::Matrix4<f32>,
-    inst: draw::InstanceTime,
     time: f32,
+    // how far it went in the last step, frames draw it part way back along this
+    moved: cgmath::Vector3<f32>,
 }
 
 impl Boid {
-    fn new(position: cgmath::Vector3<f32>, velocity: cgmath::Vector3<f32>, species: Species, time: f32) -> Self {
-        let rot_mat = vel_to_rot_mat(velocity);
-        let spat_part_key = pos_to_spat_part_key(position);
+    fn new(position: cgmath::Vector3<f32>, velocity: cgmath::Vector3<f32>, species: usize, time: f32) -> Self {
         Self {
             pos: position,
             vel: velocity,
             wall_accel: cgmath::Vector3::zero(),
 
-            sum_flock_heading: cgmath::Vector3::zero(),
-            sum_flock_center: cgmath::Vector3::zero(),
-            sum_flock_separation: cgmath::Vector3::zero(),
+            sum_threat: cgmath::Vector3::zero(),
+            nearest_prey: None,
+            sub_pull: cgmath::Vector3::zero(),
+            sub_separation: cgmath::Vector3::zero(),
 
-            spat_part_key,
-            spat_part_key_start: spat_part_key,
-            spat_part_key_end: spat_part_key,
-
-            num_flockmates: 0,
+            state: BoidState::Cruise,
+            flee_dir: cgmath::Vector3::zero(),
+            full_timer: 0.0,
+            caught: false,
 
             species,
 
-            rot_mat,
-            inst: pos_rot_mat_to_inst(position, rot_mat, time),
+            rot_mat: vel_to_rot_mat(velocity),
             time,
+            moved: cgmath::Vector3::zero(),
         }
     }
 
-    fn wrap(&mut self, sub: &sub::Sub, perlin: &noise::Perlin) -> cgmath::Vector3<f32> {
-        let mut accel = cgmath::Vector3::zero();
-
-        let sub_pos = sub.pos();
-
-        let sub_offset_z = self.pos.z - sub_pos.z;
-        if sub_offset_z < -POS_RANGE_Z {
-            let sub_force = self.steer_towards(-cgmath::Vector3::unit_z());
-            accel += sub_force;
-        }
-
-        let sub_vel = sub.bearing();
-        let sub_vel_xy = sub_vel.truncate().extend(0.0);
-        let sub_pos_xy = sub_pos.truncate().extend(0.0);
-        let center_xy = sub_pos_xy + util::safe_normalize_to(sub_vel_xy, CENTER_OFFSET_XY);
-        let pos_xy = self.pos.truncate().extend(0.0);
-
-        let center_offset_xy = center_xy - pos_xy;
-        let center_dist_xy = center_offset_xy.magnitude();
-
-        if center_dist_xy > POS_RANGE_XY {
-            let new_x = center_offset_xy.x * WRAP_STRENGTH + self.pos.x;
-            let new_y = center_offset_xy.y * WRAP_STRENGTH + self.pos.y;
-
-            let mut new_z = self.pos.z;
-            let mut new_z_in_wall = true;
-            while new_z_in_wall {
-                let iso = perlin_util::iso_at(
-                    perlin,
-                    new_x as f64 / chunk::CHUNK_SIZE as f64,
-                    new_y as f64 / chunk::CHUNK_SIZE as f64,
-                    new_z as f64 / chunk::CHUNK_SIZE as f64,
-                );
-                if iso > chunk::ISO_LEVEL + ISO_PADDING {
-                    new_z_in_wall = false;
-                } else {
-                    new_z += NEW_Z_STEP;
-                }
-            }
-
-            let new_pos = cgmath::Vector3::new(new_x, new_y, new_z);
-            self.pos = new_pos;
-        }
-
-        accel
+    // Everything but the flocking, which is left to boid_step
+    fn prepare_step(
+        &mut self,
+        world: &world::World,
+        avoidance_rays: &[cgmath::Vector3<f32>],
+        settings: &BoidSettings,
+        def: &species::SpeciesDef,
+        slot: usize,
+        delta: f32,
+    ) -> boid_step::StepIn {
+        let weights = settings.flock_weights(&def.name);
+        let (accel, max_speed) = self.steer(def, &weights, delta);
+        let accel = accel + self.avoid_walls(world, avoidance_rays, settings, &weights, max_speed, delta);
+        self.step_in(accel, max_speed, slot)
     }
 
-    fn update(&mut self, perlin: &noise::Perlin, sub: &sub::Sub, world: &world::World, avoidance_rays: &[cgmath::Vector3<f32>], delta: f32) {
+    // What the fish is doing (and how fast it can go doing it), and the depth it keeps to
+    fn steer(&mut self, def: &species::SpeciesDef, weights: &FlockWeights, delta: f32) -> (cgmath::Vector3<f32>, f32) {
         let mut accel = cgmath::Vector3::zero();
 
-        if self.num_flockmates > 0 {
-            let center_offset = self.sum_flock_center / self.num_flockmates as f32 - self.pos;
-
-            let separation_force = self.steer_towards(self.sum_flock_separation);
-            let alignment_force = self.steer_towards(self.sum_flock_heading);
-            let cohesion_force = self.steer_towards(center_offset);
-
-            accel += separation_force;
-            accel += alignment_force;
-            accel += cohesion_force;
+        self.update_state(delta);
+        let max_speed = match self.state {
+            BoidState::Cruise => def.max_speed(),
+            BoidState::Flee { .. } => def.panic_speed(),
+            BoidState::Hunt => def.hunt_speed(),
+        };
+        match self.state {
+            BoidState::Cruise => {
+                if !self.sub_pull.is_zero() {
+                    let sub_force = self.steer_towards(self.sub_pull, max_speed, weights) * SUB_PULL_WEIGHT;
+                    accel += sub_force;
+                }
+            }
+            BoidState::Flee { .. } => {
+                let flee_force = self.steer_towards(self.flee_dir, max_speed, weights) * FLEE_WEIGHT;
+                accel += flee_force;
+            }
+            BoidState::Hunt => {
+                if let Some((_prey_i, prey_pos, _dist)) = self.nearest_prey {
+                    let hunt_force = self.steer_towards(prey_pos - self.pos, max_speed, weights) * HUNT_WEIGHT;
+                    accel += hunt_force;
+                }
+            }
         }
 
-        let wrap_force = self.wrap(sub, perlin);
-        accel += wrap_force;
-
-        if self.pos.z > DOWN_STEER_MID_Z {
-            let down_force = self.steer_towards(-cgmath::Vector3::unit_z()) * DOWN_STEER_MULT;
+        if def.max_z.is_some_and(|max_z| self.pos.z > max_z) {
+            let down_force = self.steer_towards(-cgmath::Vector3::unit_z(), max_speed, weights) * weights.down_steer_mult;
             accel += down_force;
         }
+        if def.min_z.is_some_and(|min_z| self.pos.z < min_z) {
+            let up_force = self.steer_towards(cgmath::Vector3::unit_z(), max_speed, weights) * weights.down_steer_mult;
+            accel += up_force;
+        }
 
-        let mut all_tris = Vec::new();
-
-        let world_start_x = ((self.pos.x - WALL_RANGE as f32) / chunk::CHUNK_SIZE as f32).floor() as i32;
-        let world_start_y = ((self.pos.y - WALL_RANGE as f32) / chunk::CHUNK_SIZE as f32).floor() as i32;
-        let world_start_z = ((self.pos.z - WALL_RANGE as f32) / chunk::CHUNK_SIZE as f32).floor() as i32;
-
-        let world_end_x = ((self.pos.x + WALL_RANGE as f32) / chunk::CHUNK_SIZE as f32).floor() as i32;
-        let world_end_y = ((self.pos.y + WALL_RANGE as f32) / chunk::CHUNK_SIZE as f32).floor() as i32;
-        let world_end_z = ((self.pos.z + WALL_RANGE as f32) / chunk::CHUNK_SIZE as f32).floor() as i32;
-
-        for a in world_start_x..=world_end_x {
-            let local_x = self.pos.x - a as f32 * chunk::CHUNK_SIZE as f32;
-            let local_percent_x = local_x / chunk::CHUNK_SIZE as f32;
-
-            for b in world_start_y..=world_end_y {
-                let local_y = self.pos.y - b as f32 * chunk::CHUNK_SIZE as f32;
-                let local_percent_y = local_y / chunk::CHUNK_SIZE as f32;
-
-                for c in world_start_z..=world_end_z {
-                    let chunk_pos = (a, b, c);
-
-                    let chunk = match world.get_chunk(chunk_pos) {
-                        Some(chunk) => chunk,
-                        None => continue,
-                    };
-
-                    let local_z = self.pos.z - c as f32 * chunk::CHUNK_SIZE as f32;
-                    let local_percent_z = local_z / chunk::CHUNK_SIZE as f32;
-
-                    let local_pos_percent = (local_percent_x, local_percent_y, local_percent_z);
-                    let tris = chunk.tris_around(local_pos_percent, WALL_RANGE);
+        (accel, max_speed)
+    }
 
-                    all_tris.extend(tris);
-                }   
-            }
-        }
+    // Turns away when heading into the terrain, and keeps turning for a while after
+    fn avoid_walls(
+        &mut self,
+        world: &world::World,
+        avoidance_rays: &[cgmath::Vector3<f32>],
+        settings: &BoidSettings,
+        weights: &FlockWeights,
+        max_speed: f32,
+        delta: f32,
+    ) -> cgmath::Vector3<f32> {
+        let all_tris = world.tris_near(self.pos, WALL_RANGE as f32);
 
         let v_norm = util::safe_normalize(self.vel);
 
@@ -240,14 +297,14 @@ impl Boid {
                 });
 
                 if safe_dir {
-                    let force = self.steer_towards(ray) * WALL_FORCE_MULT;
+                    let force = self.steer_towards(ray, max_speed, weights) * weights.wall_force_mult;
                     self.wall_accel += force;
                     break 'ray;
                 }
             }
         }
 
-        let wall_decay = WALL_FORCE_DECAY * delta;
+        let wall_decay = settings.wall_force_decay * delta;
         if util::vec3_eq(old_wall_accel, self.wall_accel) {
             if self.wall_accel.magnitude() < wall_decay {
                 self.wall_accel = cgmath::Vector3::zero();
@@ -256,88 +313,130 @@ impl Boid {
             }
         }
 
-        accel += self.wall_accel;        
-        self.vel += accel * delta;
-        let target_speed = self.vel.magnitude().clamp(MIN_SPEED, MAX_SPEED);
-        self.vel = util::safe_normalize_to(self.vel, target_speed);
-
-        self.pos += self.vel * delta;
+        self.wall_accel
+    }
 
-        let wiggle = target_speed / MIDDLE_SPEED;
-        self.time += delta * wiggle;
+    fn step_in(&self, accel: cgmath::Vector3<f32>, max_speed: f32, slot: usize) -> boid_step::StepIn {
+        boid_step::StepIn {
+            pos: self.pos.into(),
+            species: self.species as u32,
+            vel: self.vel.into(),
+            time: self.time,
+            accel: accel.into(),
+            max_speed,
+            separation: self.sub_separation.into(),
+            slot: slot as u32,
+            hungry: (self.full_timer <= 0.0) as u32,
+            _padding: [0; 3],
+        }
+    }
 
+    // The step's results, except the prey which needs the other fish
+    fn apply_step(&mut self, out: &boid_step::StepOut) {
+        self.moved = cgmath::Vector3::from(out.pos) - self.pos;
+        self.pos = out.pos.into();
+        self.vel = out.vel.into();
+        self.time = out.time;
+        self.sum_threat = out.threat.into();
         self.rot_mat = vel_to_rot_mat(self.vel);
-        self.inst = pos_rot_mat_to_inst(self.pos, self.rot_mat, self.time);
+    }
 
-        self.spat_part_key = pos_to_spat_part_key(self.pos);
-        let spat_part_size_vec = cgmath::Vector3::new(SPAT_PART_SIZE, SPAT_PART_SIZE, SPAT_PART_SIZE);
-        self.spat_part_key_start = pos_to_spat_part_key(self.pos - spat_part_size_vec);
-        self.spat_part_key_end = pos_to_spat_part_key(self.pos + spat_part_size_vec);
+    // Seeing a predator always starts (or restarts) fleeing, which wins over hunting
+    fn update_state(&mut self, delta: f32) {
+        self.full_timer = (self.full_timer - delta).max(0.0);
+
+        self.state = match self.state {
+            _ if !self.sum_threat.is_zero() => {
+                self.flee_dir = self.sum_threat;
+                BoidState::Flee { timer: PANIC_TIME }
+            }
+            BoidState::Flee { timer } if timer > delta => BoidState::Flee { timer: timer - delta },
+            _ if self.nearest_prey.is_some() => BoidState::Hunt,
+            _ => BoidState::Cruise,
+        };
     }
 
-    fn steer_towards(&self, target: cgmath::Vector3<f32>) -> cgmath::Vector3<f32> {
-        let v = util::safe_normalize_to(target, MAX_SPEED) - self.vel;
-        let v_mag = v.magnitude().min(MAX_STEER_FORCE);
+    fn steer_towards(&self, target: cgmath::Vector3<f32>, max_speed: f32, weights: &FlockWeights) -> cgmath::Vector3<f32> {
+        let v = util::safe_normalize_to(target, max_speed) - self.vel;
+        let v_mag = v.magnitude().min(weights.max_steer_force);
         util::safe_normalize_to(v, v_mag)
     }
 }
 
+// Turns +x to face along vel without rolling, the same as in boid_compute.wgsl
 fn vel_to_rot_mat(vel: cgmath::Vector3<f32>) -> cgmath::Matrix4<f32> {
-    let xy_rot_quat = cgmath::Quaternion::from_arc(
-        cgmath::Vector3::unit_x(),
-        util::safe_normalize(cgmath::Vector3::new(vel.x, vel.y, 0.0)),
-        None,
-    );
-    let z_rot_quat = cgmath::Quaternion::from_arc(
-        util::safe_normalize(cgmath::Vector3::new(vel.x, vel.y, 0.0)),
-        util::safe_normalize(cgmath::Vector3::new(vel.x, vel.y, vel.z)),
-        None,
-    );
-    cgmath::Matrix4::from(z_rot_quat) * cgmath::Matrix4::from(xy_rot_quat)
+    let forward = util::safe_normalize(vel);
+    let flat = util::safe_normalize(cgmath::Vector3::new(vel.x, vel.y, 0.0));
+    let left = if flat.is_zero() {
+        cgmath::Vector3::unit_y()
+    } else {
+        cgmath::Vector3::new(-flat.y, flat.x, 0.0)
+    };
+    let up = forward.cross(left);
+    cgmath::Matrix4::from(cgmath::Matrix3::from_cols(forward, left, up))
 }
 
-fn pos_rot_mat_to_inst(pos: cgmath::Vector3<f32>, rot_mat: cgmath::Matrix4<f32>, time: f32) -> draw::InstanceTime {
+fn pos_rot_mat_to_inst(pos: cgmath::Vector3<f32>, rot_mat: cgmath::Matrix4<f32>, time: f32, moved: cgmath::Vector3<f32>) -> draw::InstanceTime {
     let mat = cgmath::Matrix4::from_translation(pos) * rot_mat;
-    draw::InstanceTime::new(mat, time)
+    draw::InstanceTime::new(mat, time, moved)
 }
 
-fn pos_to_spat_part_key(pos:cgmath::Vector3<f32>) -> (i32, i32, i32) {
-    let x = (pos.x / SPAT_PART_SIZE).floor() as i32;
-    let y = (pos.y / SPAT_PART_SIZE).floor() as i32;
-    let z = (pos.z / SPAT_PART_SIZE).floor() as i32;
-    (x, y, z)
+// Somewhere in the chunk that isn't in the terrain
+fn water_pos_in_chunk(rng: &mut impl Rng, density: &dyn density::Density, chunk_pos: (i32, i32, i32)) -> Option<cgmath::Vector3<f32>> {
+    let chunk_size = chunk::CHUNK_SIZE as f32;
+    let corner = cgmath::Vector3::new(chunk_pos.0 as f32, chunk_pos.1 as f32, chunk_pos.2 as f32) * chunk_size;
+
+    (0..SPAWN_TRIES)
+        .map(|_| corner + cgmath::Vector3::new(
+            rng.gen_range(0.0..chunk_size),
+            rng.gen_range(0.0..chunk_size),
+            rng.gen_range(0.0..chunk_size),
+        ))
+        .find(|pos| in_water(density, *pos))
 }
 
-fn random_pos(rng: &mut ThreadRng, perlin: &noise::Perlin, sub: &sub::Sub) -> cgmath::Vector3<f32> {
-    let sub_pos = sub.pos();
-
-    loop {
-        let x_range = (sub_pos.x - POS_RANGE_XY)..(sub_pos.x + POS_RANGE_XY);
-        let y_range = (sub_pos.y - POS_RANGE_XY)..(sub_pos.y + POS_RANGE_XY);
-        let z_range = (sub_pos.z - POS_RANGE_XY)..(sub_pos.z + POS_RANGE_Z);
-        let pos = cgmath::Vector3::new(
-            rng.gen_range(x_range),
-            rng.gen_range(y_range),
-            rng.gen_range(z_range),
-        );
+fn in_water(density: &dyn density::Density, pos: cgmath::Vector3<f32>) -> bool {
+    let iso = density.iso_at(
+        pos.x as f64 / chunk::CHUNK_SIZE as f64,
+        pos.y as f64 / chunk::CHUNK_SIZE as f64,
+        pos.z as f64 / chunk::CHUNK_SIZE as f64,
+    );
+    iso > chunk::ISO_LEVEL + ISO_PADDING
+}
 
-        let iso = perlin_util::iso_at(
-            perlin,
-            pos.x as f64 / chunk::CHUNK_SIZE as f64,
-            pos.y as f64 / chunk::CHUNK_SIZE as f64,
-            pos.z as f64 / chunk::CHUNK_SIZE as f64,
-        );
-        if iso > chunk::ISO_LEVEL + ISO_PADDING {
-            return pos;
-        }
-    }
+fn pos_to_chunk(pos: cgmath::Vector3<f32>) -> (i32, i32, i32) {
+    let chunk_size = chunk::CHUNK_SIZE as f32;
+    ((pos.x / chunk_size).floor() as i32, (pos.y / chunk_size).floor() as i32, (pos.z / chunk_size).floor() as i32)
+}
+
+fn random_unit_vec(rng: &mut impl Rng) -> cgmath::Vector3<f32> {
+    util::safe_normalize(cgmath::Vector3::new(
+        rng.gen_range(-1.0..1.0),
+        rng.gen_range(-1.0..1.0),
+        rng.gen_range(-1.0..1.0),
+    ))
+}
+
+fn create_inst_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Instance Buffer"),
+        size: (capacity * std::mem::size_of::<draw::InstanceTime>()) as wgpu::BufferAddress,
+        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    })
 }
 
 
 struct PerSpecies {
+    def: species::SpeciesDef,
+
     diffuse_bind_group: wgpu::BindGroup,
 
+    // rebuilt every frame from the boids of this species, when the step is on the CPU
     insts: Vec<draw::InstanceTime>,
+    num_inst: usize,
+    // in instances
+    inst_capacity: usize,
 
     verts_buffer: wgpu::Buffer,
     inds_buffer: wgpu::Buffer,
@@ -345,61 +444,55 @@ struct PerSpecies {
 
     num_inds: usize,
 }
+impl PerSpecies {
+    fn resize_insts(&mut self, device: &wgpu::Device, num_inst: usize) {
+        self.num_inst = num_inst;
+        let needed = num_inst.max(INITIAL_INST_CAPACITY);
+        // halving only when well under, so a count moving around a power of two doesn't keep reallocating
+        if needed > self.inst_capacity || needed * 4 <= self.inst_capacity {
+            self.inst_capacity = needed.next_power_of_two();
+            self.inst_buffer = create_inst_buffer(device, self.inst_capacity);
+        }
+    }
+
+    fn upload_insts(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.resize_insts(device, self.insts.len());
+        if !self.insts.is_empty() {
+            queue.write_buffer(&self.inst_buffer, 0, bytemuck::cast_slice(&self.insts));
+        }
+    }
+}
 
 pub struct BoidManager {
     boids: Vec<Boid>,
-    spat_part: HashMap<(i32, i32, i32), Vec<usize>>,
+    population: population::Population,
     per_species: Vec<PerSpecies>,
     avoidance_rays: Vec<cgmath::Vector3<f32>>,
+    // made when the first step runs on the GPU
+    compute: Option<boid_compute::BoidCompute>,
+    compute_supported: bool,
+    // see species::interaction_matrix
+    interactions: Vec<Vec<species::Interaction>>,
+    // (seconds left, species) for caught prey
+    respawns: Vec<(f32, usize)>,
+    settings: BoidSettings,
 }
 impl BoidManager {
     pub fn new(
-        sub: &sub::Sub,
-        perlin: &noise::Perlin,
+        settings: BoidSettings,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         texture_bind_group_layout: &wgpu::BindGroupLayout,
+        // see boid_compute::supported
+        compute_supported: bool,
     ) -> Self {
-        let mut rng = rand::thread_rng();
-        let mut boids = Vec::with_capacity(NUM_BOIDS * SPECIES_COUNT);
-        let mut spat_part: HashMap<(i32, i32, i32), Vec<usize>> = HashMap::new();
-        let mut per_species = Vec::new();
+        let loaded = species::load_all();
 
-        let diffuse_bytes_red = include_bytes!("red.jpg");
-        let diffuse_bytes_green = include_bytes!("green.png");
-        let diffuse_bytes_blue = include_bytes!("blue.jpg");
-
-        let mut boid_i = 0;
-
-        for species in &ALL_SPECIES {
-            let mut insts = Vec::with_capacity(NUM_BOIDS);
-            for _ in 0..NUM_BOIDS {
-                let position = random_pos(&mut rng, perlin, sub);
-                let velocity = util::safe_normalize_to(cgmath::Vector3::new(
-                    rng.gen_range(-1.0..1.0),
-                    rng.gen_range(-1.0..1.0),
-                    rng.gen_range(-1.0..1.0),
-                ), rng.gen_range(MIN_SPEED..MAX_SPEED));
-
-                let time = rng.gen_range(0.0..std::f32::consts::PI * 2.0);
-                let boid = Boid::new(position, velocity, *species, time);
-
-                let spat_part_key = boid.spat_part_key;
-                match spat_part.get_mut(&spat_part_key) {
-                    Some(boid_is) => boid_is.push(boid_i),
-                    None => { spat_part.insert(spat_part_key, vec![boid_i]); },
-                };
-
-                insts.push(boid.inst);
-                boids.push(boid);
-                boid_i += 1;
-            }
+        let interactions = species::interaction_matrix(&loaded.iter().map(|(def, _model)| def).collect::<Vec<_>>());
+        let mut per_species = Vec::new();
 
-            let diffuse_texture = match species {
-                Species::Red   => texture::Texture::from_bytes(device, queue, diffuse_bytes_red,   SPECIES_TEXTURE_PATHS[*species as usize]).unwrap(),
-                Species::Green => texture::Texture::from_bytes(device, queue, diffuse_bytes_green, SPECIES_TEXTURE_PATHS[*species as usize]).unwrap(),
-                Species::Blue  => texture::Texture::from_bytes(device, queue, diffuse_bytes_blue,  SPECIES_TEXTURE_PATHS[*species as usize]).unwrap(),
-            };
+        for (def, model) in loaded {
+            let diffuse_texture = texture::Texture::from_bytes(device, queue, &model.texture, &def.name).unwrap();
 
             let diffuse_bind_group = device.create_bind_group(
                 &wgpu::BindGroupDescriptor {
@@ -418,108 +511,34 @@ impl BoidManager {
                 }
             );
 
-            let mut vert_poses = Vec::new();
-            let mut vert_txs = Vec::new();
-
-            let mut verts = Vec::new();
-            let mut vert_pairs = Vec::new(); // (v, vt)
-            let mut inds = Vec::new();
-
-            let mut highest_v: f32 = 0.0;
-
-            let obj = match species {
-                Species::Red   => boid_obj::red_obj(),
-                Species::Green => boid_obj::green_obj(),
-                Species::Blue  => boid_obj::blue_obj(),
-            };
-
-            for line in obj.lines() {
-                let mut split = line.split_whitespace();
-                let first = split.next();
-                match first {
-                    Some("v") => {
-                        let x: f32 = split.next().unwrap().parse().unwrap();
-                        let y: f32 = split.next().unwrap().parse().unwrap();
-                        let z: f32 = split.next().unwrap().parse().unwrap();
-
-                        let pos = match species {
-                            Species::Red   => [z, x, y],
-                            Species::Green => [z, x, -y],
-                            Species::Blue  => [-x, y, z],
-                        };
-                        vert_poses.push(pos);
-
-                        highest_v = highest_v.max(x.abs()).max(y.abs()).max(z.abs());
-                    }
-                    Some("vt") => {
-                        let x: f32 = split.next().unwrap().parse().unwrap();
-                        let y: f32 = split.next().unwrap().parse().unwrap();
-                        vert_txs.push([x, y]);
-                    }
-                    Some("f") => {
-                        // 0 (i) (i + 1)  [for i in 1..(n - 2)]
-                        let remaining = split.collect::<Vec<&str>>();
-                        let n = remaining.len();
-                        for i in 1..(n - 1) {
-                            for j in [0, i, i + 1] {
-                                let mut piece = remaining[j].split('/');
-                                let v = piece.next().unwrap().parse::<usize>().unwrap() - 1;
-                                let vt = piece.next().unwrap().parse::<usize>().unwrap() - 1;
-
-                                let maybe_ind = vert_pairs.iter().position(|(v_, vt_)| *v_ == v && *vt_ == vt);
-                                let ind = match maybe_ind {
-                                    Some(ind) => ind,
-                                    None => {
-                                        let ind = vert_pairs.len();
-                                        vert_pairs.push((v, vt));
-                                        verts.push(draw::VertTex::new(vert_poses[v], vert_txs[vt]));
-                                        ind
-                                    }
-                                };
-                                inds.push(ind as u16);
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            verts.iter_mut().for_each(|v| {
-                v.pos[0] *= FISH_SCALE / highest_v;
-                v.pos[1] *= FISH_SCALE / highest_v;
-                v.pos[2] *= FISH_SCALE / highest_v;
-            });
-
             let verts_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some(&format!("{:?} Vertex Buffer", species)),
-                contents: bytemuck::cast_slice(&verts),
+                label: Some(&format!("{} Vertex Buffer", def.name)),
+                contents: bytemuck::cast_slice(&model.verts),
                 usage: wgpu::BufferUsages::VERTEX,
             });
 
             let inds_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some(&format!("{:?} Index Buffer", species)),
-                contents: bytemuck::cast_slice(&inds),
+                label: Some(&format!("{} Index Buffer", def.name)),
+                contents: bytemuck::cast_slice(&model.inds),
                 usage: wgpu::BufferUsages::INDEX,
             });
 
-            let inst_buffer = device.create_buffer_init(
-                &wgpu::util::BufferInitDescriptor {
-                    label: Some("Instance Buffer"),
-                    contents: bytemuck::cast_slice(&insts),
-                    usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-                }
-            );
+            let inst_buffer = create_inst_buffer(device, INITIAL_INST_CAPACITY);
 
             per_species.push(PerSpecies {
+                def,
+
                 diffuse_bind_group,
 
-                insts,
+                insts: Vec::new(),
+                num_inst: 0,
+                inst_capacity: INITIAL_INST_CAPACITY,
 
                 verts_buffer,
                 inds_buffer,
                 inst_buffer,
 
-                num_inds: inds.len(),
+                num_inds: model.inds.len(),
             });
         }
 
@@ -547,99 +566,360 @@ impl BoidManager {
             angle1.partial_cmp(&angle2).unwrap()
         });
 
-        Self { boids, spat_part, per_species, avoidance_rays }
+        Self {
+            boids: Vec::new(),
+            population: population::Population::new(),
+            per_species,
+            avoidance_rays,
+            compute: None,
+            compute_supported,
+            interactions,
+            respawns: Vec::new(),
+            settings,
+        }
     }
 
-    fn boids_near(&self, boid_i: usize) -> Vec<usize> {
-        let boid = &self.boids[boid_i];
-        let mut boids_near = Vec::new();
+    // picked up on the next update
+    pub fn set_settings(&mut self, settings: BoidSettings) {
+        self.settings = settings;
+    }
 
-        let start = boid.spat_part_key_start;
-        let end = boid.spat_part_key_end;
+    // A school of one species, picked by the biome, somewhere in the water of the chunk
+    fn spawn_school(&mut self, chunk_pos: (i32, i32, i32), density: &dyn density::Density, rng: &mut impl Rng) {
+        if self.boids.len() >= self.settings.max_boids { return; }
+        let Some(center) = water_pos_in_chunk(rng, density, chunk_pos) else { return; };
+
+        let biome = density.biome_at(center.x as f64 / chunk::CHUNK_SIZE as f64, center.y as f64 / chunk::CHUNK_SIZE as f64);
+        let weights = self.per_species.iter()
+            .map(|per_species| biome.blend_by_biome(|b| per_species.def.biome_weight(b)));
+        // all 0 if no species lives in this biome
+        let Ok(species_dist) = rand::distributions::WeightedIndex::new(weights) else { return; };
+        let species = species_dist.sample(rng);
+        let def = &self.per_species[species].def;
+
+        let heading = random_unit_vec(rng);
+        let count = def.flock_size.min(self.settings.max_boids - self.boids.len());
+        for _ in 0..count {
+            let offset = random_unit_vec(rng) * rng.gen_range(0.0..SCHOOL_SPREAD);
+            let position = if in_water(density, center + offset) { center + offset } else { center };
+            self.push_boid(species, position, heading, rng);
+        }
+    }
 
-        for x in start.0..=end.0 {
-            for y in start.1..=end.1 {
-                for z in start.2..=end.2 {
-                    let key = (x, y, z);
-                    if let Some(boid_is) = self.spat_part.get(&key) {
-                        boids_near.extend(boid_is);
-                    }
-                }
+    // Caught prey come back on their own, away from the sub. false if it couldn't be yet
+    fn respawn(&mut self, species: usize, sub: &sub::Sub, density: &dyn density::Density, rng: &mut impl Rng) -> bool {
+        if self.boids.len() >= self.settings.max_boids { return false; }
+        let Some(chunk_pos) = self.population.random_chunk_away_from(sub.chunk(), self.settings.population_dist / 2, rng) else { return false; };
+        let Some(position) = water_pos_in_chunk(rng, density, chunk_pos) else { return false; };
+
+        let heading = random_unit_vec(rng);
+        self.push_boid(species, position, heading, rng);
+        true
+    }
+
+    fn push_boid(&mut self, species: usize, position: cgmath::Vector3<f32>, heading: cgmath::Vector3<f32>, rng: &mut impl Rng) {
+        let def = &self.per_species[species].def;
+        let velocity = util::safe_normalize_to(
+            heading + random_unit_vec(rng) * SCHOOL_HEADING_JITTER,
+            rng.gen_range(def.min_speed()..=def.max_speed()),
+        );
+
+        let time = rng.gen_range(0.0..std::f32::consts::PI * 2.0);
+        self.boids.push(Boid::new(position, velocity, species, time));
+    }
+
+    // Spawns fish in newly populated chunks and despawns the ones in chunks that no longer are
+    // (including fish that swam out of the populated area)
+    fn update_population(&mut self, world: &world::World, sub: &sub::Sub, rng: &mut impl Rng, delta: f32) {
+        let density = world.density();
+        let spawn = self.population.update(
+            sub.chunk(),
+            world,
+            self.settings.population_dist,
+            self.settings.max_populates_per_frame,
+            rng,
+        );
+
+        let population = &self.population;
+        self.boids.retain(|boid| population.is_populated(pos_to_chunk(boid.pos)));
+
+        for (chunk_pos, schools) in spawn {
+            for _ in 0..schools {
+                self.spawn_school(chunk_pos, density, rng);
             }
         }
 
-        boids_near
+        self.respawns.iter_mut().for_each(|(timer, _species)| *timer -= delta);
+        let (ready, waiting): (Vec<_>, Vec<_>) = self.respawns.drain(..).partition(|(timer, _species)| *timer <= 0.0);
+        self.respawns = waiting;
+        for (_timer, species) in ready {
+            if !self.respawn(species, sub, density, rng) {
+                self.respawns.push((RESPAWN_RETRY_TIME, species));
+            }
+        }
     }
 
-    pub fn update(&mut self, queue: &wgpu::Queue, perlin: &noise::Perlin, sub: &sub::Sub, world: &world::World, delta: f32) {
+    // A GPU step is finished at the start of the next update, so it runs while the frame is drawn.
+    // Fish can't be added or removed while it is on the GPU, which is why that comes first.
+    // rng is the session's, see State::rng
+    pub fn update(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        sub: &sub::Sub,
+        world: &world::World,
+        rng: &mut impl Rng,
+        delta: f32,
+    ) {
+        self.finish_gpu_step(device);
+
+        self.catch_prey();
+        self.update_population(world, sub, rng, delta);
+
+        let mut num_insts = vec![0; self.per_species.len()];
+        for boid in self.boids.iter() {
+            num_insts[boid.species] += 1;
+        }
+        // where each species' instances start
+        let first_slots: Vec<usize> = num_insts.iter()
+            .scan(0, |next, num_inst| {
+                let first = *next;
+                *next += num_inst;
+                Some(first)
+            })
+            .collect();
+
+        let mut next_slots = first_slots.clone();
+        let mut inputs = Vec::with_capacity(self.boids.len());
         for i in 0..self.boids.len() {
-            self.boids[i].num_flockmates = 0;
-            self.boids[i].sum_flock_heading = cgmath::Vector3::zero();
-            self.boids[i].sum_flock_center = cgmath::Vector3::zero();
-            self.boids[i].sum_flock_separation = cgmath::Vector3::zero();
-
-            let nearby = self.boids_near(i);
-            for j in nearby {
-                if i == j {
-                    continue;
-                }
+            self.react_to_sub(i, sub);
 
-                let offset = self.boids[j].pos - self.boids[i].pos;
-                let distance = offset.magnitude();
-
-                let i_species = self.boids[i].species;
-                let j_species = self.boids[j].species;
-
-                if distance < PERCEPTION_RADIUS  {
-                    if i_species == j_species {
-                        self.boids[i].num_flockmates += 1;
-                        
-                        let boid_j_vel = self.boids[j].vel;
-                        self.boids[i].sum_flock_heading += boid_j_vel;
-    
-                        let boid_j_pos = self.boids[j].pos;
-                        self.boids[i].sum_flock_center += boid_j_pos;
-                    }
-    
-                    if i_species != j_species || distance < AVOIDANCE_RADIUS {
-                        self.boids[i].sum_flock_separation -= offset / distance.pow(2);
-                    }
-                }
+            let boid = &mut self.boids[i];
+            let slot = next_slots[boid.species];
+            next_slots[boid.species] += 1;
+            inputs.push(boid.prepare_step(world, &self.avoidance_rays, &self.settings, &self.per_species[boid.species].def, slot, delta));
+        }
+
+        let step_species = self.step_species();
+        let globals = boid_step::StepGlobals::new(self.settings.avoidance_radius, delta, &step_species, &inputs);
+
+        let use_gpu = self.settings.gpu_step
+            && self.compute_supported
+            && !inputs.is_empty()
+            && self.compute.get_or_insert_with(|| boid_compute::BoidCompute::new(device)).can_dispatch();
+        if use_gpu {
+            for (per_species, num_inst) in self.per_species.iter_mut().zip(num_insts.iter()) {
+                per_species.resize_insts(device, *num_inst);
+            }
+            let inst_copies: Vec<_> = self.per_species.iter()
+                .zip(first_slots.iter())
+                .map(|(per_species, first_slot)| (&per_species.inst_buffer, *first_slot, per_species.num_inst))
+                .collect();
+
+            let compute = self.compute.as_mut().unwrap();
+            compute.dispatch(device, queue, globals, step_species, inputs, &inst_copies);
+        } else {
+            let outputs = boid_step::cpu_step(&globals, &step_species, &inputs);
+            self.apply_step(&outputs);
+
+            for per_species in self.per_species.iter_mut() {
+                per_species.insts.clear();
+            }
+            for boid in self.boids.iter() {
+                let inst = pos_rot_mat_to_inst(boid.pos, boid.rot_mat, boid.time, boid.moved);
+                self.per_species[boid.species].insts.push(inst);
+            }
+            for per_species in self.per_species.iter_mut() {
+                per_species.upload_insts(device, queue);
+            }
+        }
+    }
+
+    // Applies the step the last update left on the GPU, if there is one
+    fn finish_gpu_step(&mut self, device: &wgpu::Device) {
+        let Some(compute) = &mut self.compute else { return; };
+        let Some(step) = compute.finish(device) else { return; };
+        if step.failed {
+            log::warn!("Running the boid step on the CPU from now on");
+            self.compute_supported = false;
+            self.compute = None;
+        }
+
+        if self.settings.verify_gpu_step && step.from_gpu {
+            let cpu_outputs = boid_step::cpu_step(&step.globals, &step.species, &step.inputs);
+            let diff = boid_step::compare(&step.inputs, &cpu_outputs, &step.outputs);
+            if diff > boid_step::GPU_STEP_TOLERANCE {
+                log::warn!("The GPU boid step is {} away from the CPU one with {} fish", diff, step.inputs.len());
             }
+        }
+
+        self.apply_step(&step.outputs);
+    }
 
-            let offset = sub.pos() - self.boids[i].pos;
-            let distance = offset.magnitude();
+    fn apply_step(&mut self, outputs: &[boid_step::StepOut]) {
+        for (boid, out) in self.boids.iter_mut().zip(outputs.iter()) {
+            boid.apply_step(out);
+        }
+        // after every fish has moved, so the prey's position is where it is now
+        for (i, out) in outputs.iter().enumerate() {
+            self.boids[i].nearest_prey = (out.prey >= 0).then(|| {
+                let prey_i = out.prey as usize;
+                (prey_i, self.boids[prey_i].pos, out.prey_dist)
+            });
+        }
+    }
 
-            if distance < PERCEPTION_RADIUS {
-                self.boids[i].sum_flock_separation -= offset / distance.pow(2);
+    // Predators that got close enough to their prey in the last step eat it
+    fn catch_prey(&mut self) {
+        for i in 0..self.boids.len() {
+            let Some((prey_i, _prey_pos, prey_dist)) = self.boids[i].nearest_prey else { continue; };
+            let scale = self.per_species[self.boids[i].species].def.scale();
+            if prey_dist >= CATCH_DIST + scale || self.boids[i].caught || self.boids[prey_i].caught {
+                continue;
             }
+
+            self.boids[prey_i].caught = true;
+            self.respawns.push((RESPAWN_TIME, self.boids[prey_i].species));
+
+            self.boids[i].full_timer = FULL_TIME;
+            self.boids[i].nearest_prey = None;
         }
 
-        self.spat_part.clear();
+        self.boids.retain(|boid| !boid.caught);
+    }
+
+    fn step_species(&self) -> Vec<boid_step::StepSpecies> {
+        self.per_species.iter().zip(self.interactions.iter()).map(|(per_species, interactions)| {
+            let weights = self.settings.flock_weights(&per_species.def.name);
+            boid_step::StepSpecies::new(
+                &per_species.def,
+                weights.separation,
+                weights.alignment,
+                weights.cohesion,
+                weights.max_steer_force,
+                interactions,
+            )
+        }).collect()
+    }
 
-        for (boid_i, boid) in self.boids.iter_mut().enumerate() {
-            boid.update(perlin, sub, world, &self.avoidance_rays, delta);
+    // Every fish keeps its distance, then each species reacts in its own way
+    fn react_to_sub(&mut self, i: usize, sub: &sub::Sub) {
+        let def = &self.per_species[self.boids[i].species].def;
+        let boid = &mut self.boids[i];
+        boid.sub_pull = cgmath::Vector3::zero();
+        boid.sub_separation = cgmath::Vector3::zero();
 
-            let spat_part_key = boid.spat_part_key;
-            match self.spat_part.get_mut(&spat_part_key) {
-                Some(boid_is) => boid_is.push(boid_i),
-                None => { self.spat_part.insert(spat_part_key, vec![boid_i]); },
-            };
+        let offset = sub.pos() - boid.pos;
+        let distance = offset.magnitude();
+
+        if distance < def.perception_radius {
+            boid.sub_separation -= offset / distance.pow(2);
+        }
 
-            let species_i = boid_i % NUM_BOIDS;
-            self.per_species[boid.species as usize].insts[species_i] = boid.inst;
+        let sees_sub = distance < def.perception_radius * SUB_SIGHT_MULT;
+        match def.sub_reaction {
+            species::SubReaction::KeepsAway => {}
+            species::SubReaction::Skittish => {
+                // the part of the sub's velocity heading for the fish
+                let approach_speed = -sub.speed() * sub.bearing().dot(offset) / distance;
+                if sees_sub && approach_speed > SCATTER_SPEED {
+                    boid.sum_threat -= offset / distance.pow(2);
+                }
+            }
+            species::SubReaction::Curious => {
+                let behind = sub.pos() - sub.bearing() * FOLLOW_DIST;
+                if sees_sub && distance > FOLLOW_DIST {
+                    boid.sub_pull = behind - boid.pos;
+                }
+            }
+            species::SubReaction::DrawnToLight => {
+                if distance < LIGHT_SIGHT_DIST && sub.headlight_at(boid.pos) > 0.0 {
+                    // the closest point on the beam, but not right up against the sub
+                    let light_dir = util::safe_normalize(sub.light_dir());
+                    let along = (-offset).dot(light_dir).max(FOLLOW_DIST);
+                    boid.sub_pull = sub.pos() + light_dir * along - boid.pos;
+                }
+            }
         }
+    }
+
+    pub fn settings(&self) -> &BoidSettings { &self.settings }
+    // how far from the sub's chunk the simulation needs collision, see World::update_collision
+    pub fn collision_dist(&self) -> i32 { self.settings.population_dist + COLLISION_MARGIN }
+    pub fn flock_weights_mut(&mut self, species: usize) -> &mut FlockWeights {
+        let name = &self.per_species[species].def.name;
+        self.settings.flock_weights_mut(name)
+    }
+
+    pub fn species_count(&self) -> usize { self.per_species.len() }
+    pub fn species_name(&self, species: usize) -> &str { &self.per_species[species].def.name }
+
+    pub fn verts_buffer_slice(&self, species: usize) -> wgpu::BufferSlice { self.per_species[species].verts_buffer.slice(..) }
+    pub fn inds_buffer_slice(&self, species: usize) -> wgpu::BufferSlice { self.per_species[species].inds_buffer.slice(..) }
+    pub fn inst_buffer_slice(&self, species: usize) -> wgpu::BufferSlice { self.per_species[species].inst_buffer.slice(..) }
+    pub fn diffuse_bind_group(&self, species: usize) -> &wgpu::BindGroup { &self.per_species[species].diffuse_bind_group }
+    // pub fn num_verts(&self, species: usize) -> usize { self.per_species[species].num_verts }
+    pub fn num_inds(&self, species: usize) -> usize { self.per_species[species].num_inds }
+    pub fn num_inst(&self, species: usize) -> usize { self.per_species[species].num_inst }
+    pub fn count(&self) -> usize { self.boids.len() }
+}
 
-        for per_species in self.per_species.iter() {
-            queue.write_buffer(&per_species.inst_buffer, 0, bytemuck::cast_slice(&per_species.insts));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELTA: f32 = 1.0 / 60.0;
+    // ten seconds of chasing
+    const MAX_CATCH_STEPS: usize = 600;
+
+    // The steps BoidManager::update takes in open water, with the real species, until the shark
+    // is close enough for catch_prey. Some if it got there, with whether the prey was fleeing by then
+    fn chase(shark_start: cgmath::Vector3<f32>) -> Option<bool> {
+        let defs: Vec<species::SpeciesDef> = species::load_all().into_iter().map(|(def, _model)| def).collect();
+        let defs = [
+            defs.iter().find(|def| def.name == "shark").unwrap(),
+            defs.iter().find(|def| def.name == "red").unwrap(),
+        ];
+        let settings = BoidSettings::default();
+        let interactions = species::interaction_matrix(&defs);
+        let step_species: Vec<_> = defs.iter().zip(interactions.iter()).map(|(def, interactions)| {
+            let weights = settings.flock_weights(&def.name);
+            boid_step::StepSpecies::new(def, weights.separation, weights.alignment, weights.cohesion, weights.max_steer_force, interactions)
+        }).collect();
+
+        let prey_start = cgmath::Vector3::new(0.0, 0.0, -20.0);
+        let mut boids = [
+            Boid::new(shark_start, util::safe_normalize_to(prey_start - shark_start, defs[0].middle_speed()), 0, 0.0),
+            Boid::new(prey_start, cgmath::Vector3::new(defs[1].middle_speed(), 0.0, 0.0), 1, 0.0),
+        ];
+
+        for _ in 0..MAX_CATCH_STEPS {
+            let inputs: Vec<_> = boids.iter_mut().enumerate().map(|(slot, boid)| {
+                let weights = settings.flock_weights(&defs[boid.species].name);
+                let (accel, max_speed) = boid.steer(defs[boid.species], &weights, DELTA);
+                boid.step_in(accel, max_speed, slot)
+            }).collect();
+            let globals = boid_step::StepGlobals::new(settings.avoidance_radius, DELTA, &step_species, &inputs);
+            let outputs = boid_step::cpu_step(&globals, &step_species, &inputs);
+
+            for (boid, out) in boids.iter_mut().zip(outputs.iter()) {
+                boid.apply_step(out);
+            }
+            let shark_out = &outputs[0];
+            if shark_out.prey == 1 && shark_out.prey_dist < CATCH_DIST + defs[0].scale() {
+                return Some(matches!(boids[1].state, BoidState::Flee { .. }));
+            }
+            boids[0].nearest_prey = (shark_out.prey == 1).then_some((1, boids[1].pos, shark_out.prey_dist));
         }
+        None
     }
 
-    pub fn verts_buffer_slice(&self, species: Species) -> wgpu::BufferSlice { self.per_species[species as usize].verts_buffer.slice(..) }
-    pub fn inds_buffer_slice(&self, species: Species) -> wgpu::BufferSlice { self.per_species[species as usize].inds_buffer.slice(..) }
-    pub fn inst_buffer_slice(&self, species: Species) -> wgpu::BufferSlice { self.per_species[species as usize].inst_buffer.slice(..) }
-    pub fn diffuse_bind_group(&self, species: Species) -> &wgpu::BindGroup { &self.per_species[species as usize].diffuse_bind_group }
-    // pub fn num_verts(&self, species: Species) -> usize { self.per_species[species as usize].num_verts }
-    pub fn num_inds(&self, species: Species) -> usize { self.per_species[species as usize].num_inds }
-    pub fn num_inst(&self, _species: Species) -> usize { NUM_BOIDS }
+    #[test]
+    fn shark_catches_fleeing_prey() {
+        // from behind, and from the side, just inside the shark's sight
+        for shark_start in [cgmath::Vector3::new(-11.0, 0.0, -20.0), cgmath::Vector3::new(0.0, 11.0, -20.0)] {
+            let fleeing = chase(shark_start).unwrap_or_else(|| panic!("No catch from {:?}", shark_start));
+            assert!(fleeing, "the prey should have seen the shark coming");
+        }
+    }
 }
@@ -0,0 +1,438 @@
+use std::sync::mpsc;
+
+use crate::{boid_step, draw};
+
+// boid_compute.wgsl's workgroup size
+const WORKGROUP_SIZE: u32 = 64;
+// in fish, the buffers double as needed
+const INITIAL_CAPACITY: usize = 256;
+// in u32s, see boid_step::grid, doubles as needed
+const INITIAL_GRID_CAPACITY: usize = 4096;
+// A step given up on in the browser keeps its readback buffer until it is mapped,
+// so there's a second one for the next step
+const NUM_READBACKS: usize = 2;
+
+const GLOBALS_SIZE: usize = std::mem::size_of::<boid_step::StepGlobals>();
+const SPECIES_SIZE: usize = std::mem::size_of::<boid_step::StepSpecies>();
+const IN_SIZE: usize = std::mem::size_of::<boid_step::StepIn>();
+const OUT_SIZE: usize = std::mem::size_of::<boid_step::StepOut>();
+const INST_SIZE: usize = std::mem::size_of::<draw::InstanceTime>();
+
+// Compute shaders aren't in WebGL, so the web build (and old GPUs) use boid_step::cpu_step
+pub fn supported(adapter: &wgpu::Adapter, device: &wgpu::Device) -> bool {
+    let limits = device.limits();
+    adapter.get_downlevel_capabilities().flags.contains(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+        && limits.max_storage_buffers_per_shader_stage >= 5
+        && limits.max_compute_invocations_per_workgroup >= WORKGROUP_SIZE
+}
+
+// A finished step, with what it was given so it can be run again on the CPU
+pub struct FinishedStep {
+    pub globals: boid_step::StepGlobals,
+    pub species: Vec<boid_step::StepSpecies>,
+    pub inputs: Vec<boid_step::StepIn>,
+    pub outputs: Vec<boid_step::StepOut>,
+    // false if it wasn't read back in time and the outputs are from boid_step::cpu_step
+    pub from_gpu: bool,
+    // the readback failed, so the GPU step can't be relied on any more
+    pub failed: bool,
+}
+
+// A step that hasn't been read back yet
+struct Pending {
+    globals: boid_step::StepGlobals,
+    species: Vec<boid_step::StepSpecies>,
+    inputs: Vec<boid_step::StepIn>,
+    submission: wgpu::SubmissionIndex,
+    readback: usize,
+}
+
+struct Readback {
+    buffer: wgpu::Buffer,
+    // Some from map_async until it's unmapped
+    mapped: Option<mpsc::Receiver<Result<(), wgpu::BufferAsyncError>>>,
+}
+
+// Runs boid_step on the GPU. The instances go straight into the species' instance buffers,
+// and the fish's new positions are read back for everything else the CPU does with them.
+pub struct BoidCompute {
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+
+    globals_buffer: wgpu::Buffer,
+    species_buffer: wgpu::Buffer,
+    grid_capacity: usize,
+    grid_buffer: wgpu::Buffer,
+
+    // in fish
+    capacity: usize,
+    in_buffer: wgpu::Buffer,
+    out_buffer: wgpu::Buffer,
+    // every species' instances one after another, copied out to each species' buffer
+    inst_buffer: wgpu::Buffer,
+    readbacks: Vec<Readback>,
+    bind_group: wgpu::BindGroup,
+
+    pending: Option<Pending>,
+}
+
+impl BoidCompute {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let shader = device.create_shader_module(wgpu::include_wgsl!("boid_compute.wgsl"));
+
+        let storage_entry = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                storage_entry(1, true),
+                storage_entry(2, true),
+                storage_entry(3, false),
+                storage_entry(4, false),
+                storage_entry(5, true),
+            ],
+            label: Some("boid_step_bind_group_layout"),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Boid Step Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Boid Step Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "cs_main",
+        });
+
+        let globals_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Boid Step Globals Buffer"),
+            size: GLOBALS_SIZE as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let species_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Boid Step Species Buffer"),
+            size: (boid_step::MAX_SPECIES * SPECIES_SIZE) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let grid_buffer = create_grid_buffer(device, INITIAL_GRID_CAPACITY);
+
+        let (in_buffer, out_buffer, inst_buffer, readbacks) = create_fish_buffers(device, INITIAL_CAPACITY);
+        let bind_group = create_bind_group(
+            device,
+            &bind_group_layout,
+            [&globals_buffer, &species_buffer, &in_buffer, &out_buffer, &inst_buffer, &grid_buffer],
+        );
+
+        Self {
+            pipeline,
+            bind_group_layout,
+
+            globals_buffer,
+            species_buffer,
+            grid_capacity: INITIAL_GRID_CAPACITY,
+            grid_buffer,
+
+            capacity: INITIAL_CAPACITY,
+            in_buffer,
+            out_buffer,
+            inst_buffer,
+            readbacks,
+            bind_group,
+
+            pending: None,
+        }
+    }
+
+    // False while every readback buffer is still held by a step that was given up on,
+    // the step has to be run on the CPU then
+    pub fn can_dispatch(&mut self) -> bool {
+        for readback in self.readbacks.iter_mut() {
+            let Some(mapped) = &readback.mapped else { continue; };
+            match mapped.try_recv() {
+                Ok(Ok(())) => readback.buffer.unmap(),
+                Ok(Err(_)) | Err(mpsc::TryRecvError::Disconnected) => {}
+                Err(mpsc::TryRecvError::Empty) => continue,
+            }
+            readback.mapped = None;
+        }
+        self.readbacks.iter().any(|readback| readback.mapped.is_none())
+    }
+
+    // The results are read back by finish, after the frame has been drawn.
+    // inst_copies: (species' instance buffer, first slot, number of fish) for each species
+    pub fn dispatch(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        globals: boid_step::StepGlobals,
+        species: Vec<boid_step::StepSpecies>,
+        inputs: Vec<boid_step::StepIn>,
+        inst_copies: &[(&wgpu::Buffer, usize, usize)],
+    ) {
+        assert!(self.pending.is_none(), "the last boid step hasn't been finished");
+        let count = inputs.len();
+        let grid = boid_step::grid(&globals, &inputs);
+        if count > self.capacity || grid.len() > self.grid_capacity {
+            if count > self.capacity {
+                self.capacity = count.next_power_of_two();
+                (self.in_buffer, self.out_buffer, self.inst_buffer, self.readbacks) = create_fish_buffers(device, self.capacity);
+            }
+            if grid.len() > self.grid_capacity {
+                self.grid_capacity = grid.len().next_power_of_two();
+                self.grid_buffer = create_grid_buffer(device, self.grid_capacity);
+            }
+            self.bind_group = create_bind_group(
+                device,
+                &self.bind_group_layout,
+                [&self.globals_buffer, &self.species_buffer, &self.in_buffer, &self.out_buffer, &self.inst_buffer, &self.grid_buffer],
+            );
+        }
+
+        queue.write_buffer(&self.globals_buffer, 0, bytemuck::bytes_of(&globals));
+        queue.write_buffer(&self.grid_buffer, 0, bytemuck::cast_slice(&grid));
+        queue.write_buffer(&self.species_buffer, 0, bytemuck::cast_slice(&species));
+        queue.write_buffer(&self.in_buffer, 0, bytemuck::cast_slice(&inputs));
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Boid Step Encoder"),
+        });
+        {
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Boid Step Pass"),
+                timestamp_writes: None,
+            });
+            compute_pass.set_pipeline(&self.pipeline);
+            compute_pass.set_bind_group(0, &self.bind_group, &[]);
+            compute_pass.dispatch_workgroups((count as u32).div_ceil(WORKGROUP_SIZE), 1, 1);
+        }
+
+        for (buffer, start, len) in inst_copies.iter() {
+            if *len == 0 { continue; }
+            encoder.copy_buffer_to_buffer(
+                &self.inst_buffer,
+                (start * INST_SIZE) as wgpu::BufferAddress,
+                buffer,
+                0,
+                (len * INST_SIZE) as wgpu::BufferAddress,
+            );
+        }
+
+        let readback_i = self.readbacks.iter()
+            .position(|readback| readback.mapped.is_none())
+            .expect("dispatch needs a free readback buffer, see can_dispatch");
+        let readback = &mut self.readbacks[readback_i];
+        let out_size = (count * OUT_SIZE) as wgpu::BufferAddress;
+        encoder.copy_buffer_to_buffer(&self.out_buffer, 0, &readback.buffer, 0, out_size);
+        let submission = queue.submit(std::iter::once(encoder.finish()));
+
+        let (sender, mapped) = mpsc::channel();
+        readback.buffer.slice(..out_size).map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        readback.mapped = Some(mapped);
+        self.pending = Some(Pending { globals, species, inputs, submission, readback: readback_i });
+    }
+
+    // The last dispatched step, None if there isn't one.
+    // Natively this waits for its submission, which has usually finished while the frame was drawn.
+    // In the browser buffers are only mapped between frames, so a step that isn't back yet is run
+    // again on the CPU instead of holding up the simulation, and its buffer is freed once it's mapped.
+    // A readback that failed is run on the CPU the same way.
+    pub fn finish(&mut self, device: &wgpu::Device) -> Option<FinishedStep> {
+        let pending = self.pending.take()?;
+        device.poll(wgpu::Maintain::WaitForSubmissionIndex(pending.submission));
+
+        let readback = &mut self.readbacks[pending.readback];
+        let mapped = readback.mapped.as_ref().unwrap();
+        let mut failed = false;
+        let outputs = match mapped.try_recv() {
+            Ok(Ok(())) => {
+                let out_size = (pending.inputs.len() * OUT_SIZE) as wgpu::BufferAddress;
+                let outputs = {
+                    let view = readback.buffer.slice(..out_size).get_mapped_range();
+                    bytemuck::cast_slice(&view).to_vec()
+                };
+                readback.buffer.unmap();
+                readback.mapped = None;
+                Some(outputs)
+            }
+            Ok(Err(e)) => {
+                log::error!("Couldn't read back the boid step: {}", e);
+                readback.mapped = None;
+                failed = true;
+                None
+            }
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                log::error!("The boid step's readback was dropped");
+                readback.mapped = None;
+                failed = true;
+                None
+            }
+        };
+
+        let from_gpu = outputs.is_some();
+        // the instances were already written on the GPU, the few that differ are fixed by the next step
+        let outputs = outputs.unwrap_or_else(|| boid_step::cpu_step(&pending.globals, &pending.species, &pending.inputs));
+        Some(FinishedStep {
+            globals: pending.globals,
+            species: pending.species,
+            inputs: pending.inputs,
+            outputs,
+            from_gpu,
+            failed,
+        })
+    }
+}
+
+// in, out, instance and readback buffers for capacity fish
+fn create_fish_buffers(device: &wgpu::Device, capacity: usize) -> (wgpu::Buffer, wgpu::Buffer, wgpu::Buffer, Vec<Readback>) {
+    let buffer = |label, size: usize, usage| device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some(label),
+        size: (capacity * size) as wgpu::BufferAddress,
+        usage,
+        mapped_at_creation: false,
+    });
+
+    (
+        buffer("Boid Step In Buffer", IN_SIZE, wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST),
+        buffer("Boid Step Out Buffer", OUT_SIZE, wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC),
+        buffer("Boid Step Instance Buffer", INST_SIZE, wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC),
+        (0..NUM_READBACKS).map(|_| Readback {
+            buffer: buffer("Boid Step Readback Buffer", OUT_SIZE, wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST),
+            mapped: None,
+        }).collect(),
+    )
+}
+
+fn create_grid_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Boid Step Grid Buffer"),
+        size: (capacity * std::mem::size_of::<u32>()) as wgpu::BufferAddress,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    })
+}
+
+fn create_bind_group(device: &wgpu::Device, layout: &wgpu::BindGroupLayout, buffers: [&wgpu::Buffer; 6]) -> wgpu::BindGroup {
+    let entries: Vec<_> = buffers.iter().enumerate().map(|(binding, buffer)| wgpu::BindGroupEntry {
+        binding: binding as u32,
+        resource: buffer.as_entire_binding(),
+    }).collect();
+
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout,
+        entries: &entries,
+        label: Some("boid_step_bind_group"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    const NUM_FISH: usize = 1000;
+    // fish are spread over a cube this wide, so most see a few others
+    const SPREAD: f32 = 40.0;
+
+    // None (and the test is skipped) without a GPU that can run the step
+    fn device() -> Option<(wgpu::Device, wgpu::Queue)> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))?;
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                features: wgpu::Features::empty(),
+                limits: adapter.limits(),
+                label: None,
+            },
+            None,
+        )).ok()?;
+        supported(&adapter, &device).then_some((device, queue))
+    }
+
+    fn random_vec(rng: &mut impl Rng, half_size: f32) -> [f32; 3] {
+        [(); 3].map(|_| rng.gen_range(-half_size..half_size))
+    }
+
+    #[test]
+    fn matches_cpu_step() {
+        let Some((device, queue)) = device() else {
+            eprintln!("Skipping, no GPU adapter with compute shaders");
+            return;
+        };
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        // two flocking species, the second hunts the first and the first flees it
+        let species = vec![
+            boid_step::StepSpecies {
+                perception_radius: 5.0,
+                min_speed: 1.0,
+                middle_speed: 2.0,
+                separation: 1.5,
+                alignment: 1.0,
+                cohesion: 1.0,
+                max_steer_force: 3.0,
+                hunts: 0,
+                flees: 0b10,
+            },
+            boid_step::StepSpecies {
+                perception_radius: 12.0,
+                min_speed: 2.0,
+                middle_speed: 4.0,
+                separation: 1.0,
+                alignment: 0.5,
+                cohesion: 0.5,
+                max_steer_force: 2.0,
+                hunts: 0b01,
+                flees: 0,
+            },
+        ];
+        let inputs: Vec<_> = (0..NUM_FISH).map(|i| boid_step::StepIn {
+            pos: random_vec(&mut rng, SPREAD / 2.0),
+            species: (i % 10 == 0) as u32,
+            vel: random_vec(&mut rng, 3.0),
+            time: rng.gen_range(0.0..10.0),
+            accel: random_vec(&mut rng, 1.0),
+            max_speed: 5.0,
+            separation: [0.0; 3],
+            slot: i as u32,
+            hungry: 1,
+            _padding: [0; 3],
+        }).collect();
+        let globals = boid_step::StepGlobals::new(2.0, 1.0 / 60.0, &species, &inputs);
+
+        let cpu_outputs = boid_step::cpu_step(&globals, &species, &inputs);
+
+        let mut compute = BoidCompute::new(&device);
+        assert!(compute.can_dispatch());
+        compute.dispatch(&device, &queue, globals, species, inputs, &[]);
+        let step = compute.finish(&device).unwrap();
+        assert!(step.from_gpu, "the step should be read back after waiting for it");
+
+        let diff = boid_step::compare(&step.inputs, &cpu_outputs, &step.outputs);
+        assert!(diff <= boid_step::GPU_STEP_TOLERANCE, "the GPU step is {} away from the CPU one", diff);
+    }
+}
@@ -0,0 +1,189 @@
+// One flocking step for every fish, the same as cpu_step in boid_step.rs
+
+struct Globals {
+    count: u32,
+    avoidance_radius: f32,
+    delta: f32,
+    cell_size: f32,
+    grid_min: vec3<i32>,
+    grid_dims: vec3<u32>,
+};
+
+struct Species {
+    perception_radius: f32,
+    min_speed: f32,
+    middle_speed: f32,
+    separation: f32,
+    alignment: f32,
+    cohesion: f32,
+    max_steer_force: f32,
+    hunts: u32,
+    flees: u32,
+};
+
+struct BoidIn {
+    pos: vec3<f32>,
+    species: u32,
+    vel: vec3<f32>,
+    time: f32,
+    accel: vec3<f32>,
+    max_speed: f32,
+    separation: vec3<f32>,
+    slot: u32,
+    hungry: u32,
+};
+
+struct BoidOut {
+    pos: vec3<f32>,
+    time: f32,
+    vel: vec3<f32>,
+    prey: i32,
+    threat: vec3<f32>,
+    prey_dist: f32,
+};
+
+@group(0) @binding(0)
+var<uniform> globals: Globals;
+@group(0) @binding(1)
+var<storage, read> species: array<Species>;
+@group(0) @binding(2)
+var<storage, read> boids: array<BoidIn>;
+@group(0) @binding(3)
+var<storage, read_write> results: array<BoidOut>;
+// draw::InstanceTime, the model matrix's columns, the time then how far it moved
+@group(0) @binding(4)
+var<storage, read_write> insts: array<f32>;
+// boid_step::grid, each cell's first fish (and the end of the last), the fish sorted by cell, each fish's cell
+@group(0) @binding(5)
+var<storage, read> grid: array<u32>;
+
+const INST_FLOATS: u32 = 20u;
+const FLT_MAX: f32 = 3.40282347e38;
+
+fn safe_normalize_to(v: vec3<f32>, target_len: f32) -> vec3<f32> {
+    let len = length(v);
+    if len == 0.0 {
+        return v;
+    }
+    return v / len * target_len;
+}
+
+fn steer_towards(towards: vec3<f32>, vel: vec3<f32>, max_speed: f32, max_steer_force: f32) -> vec3<f32> {
+    let v = safe_normalize_to(towards, max_speed) - vel;
+    let v_len = min(length(v), max_steer_force);
+    return safe_normalize_to(v, v_len);
+}
+
+//----------------------------------------------------------------------------//
+
+@compute @workgroup_size(64)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i >= globals.count {
+        return;
+    }
+
+    let boid = boids[i];
+    let boid_species = species[boid.species];
+
+    var num_flockmates = 0u;
+    var heading = vec3<f32>(0.0);
+    var center = vec3<f32>(0.0);
+    var separation = boid.separation;
+    var threat = vec3<f32>(0.0);
+    var prey = -1;
+    var prey_dist = FLT_MAX;
+
+    let dims = vec3<i32>(globals.grid_dims);
+    let sorted_start = globals.grid_dims.x * globals.grid_dims.y * globals.grid_dims.z + 1u;
+    let own_index = i32(grid[sorted_start + globals.count + i]);
+    let own = vec3<i32>(own_index % dims.x, own_index / dims.x % dims.y, own_index / (dims.x * dims.y));
+
+    // the cells around its own, in the same order as cpu_step
+    for (var z = own.z - 1; z <= own.z + 1; z++) {
+        for (var y = own.y - 1; y <= own.y + 1; y++) {
+            for (var x = own.x - 1; x <= own.x + 1; x++) {
+                if x < 0 || y < 0 || z < 0 || x >= dims.x || y >= dims.y || z >= dims.z {
+                    continue;
+                }
+                let cell = u32(x + dims.x * (y + dims.y * z));
+                for (var s = grid[cell]; s < grid[cell + 1u]; s++) {
+                    let j = grid[sorted_start + s];
+                    if j == i {
+                        continue;
+                    }
+
+                    let other = boids[j];
+                    let offset = other.pos - boid.pos;
+                    let distance = length(offset);
+                    if distance >= boid_species.perception_radius {
+                        continue;
+                    }
+
+                    let bit = 1u << other.species;
+                    if other.species == boid.species {
+                        num_flockmates += 1u;
+                        heading += other.vel;
+                        center += other.pos;
+                        if distance < globals.avoidance_radius {
+                            separation -= offset / (distance * distance);
+                        }
+                    } else if (boid_species.hunts & bit) != 0u {
+                        if boid.hungry != 0u && distance < prey_dist {
+                            prey = i32(j);
+                            prey_dist = distance;
+                        }
+                    } else if (boid_species.flees & bit) != 0u {
+                        separation -= offset / (distance * distance);
+                        threat -= offset / (distance * distance);
+                    } else {
+                        separation -= offset / (distance * distance);
+                    }
+                }
+            }
+        }
+    }
+
+    var accel = boid.accel;
+    if num_flockmates > 0u {
+        let center_offset = center / f32(num_flockmates) - boid.pos;
+        accel += steer_towards(separation, boid.vel, boid.max_speed, boid_species.max_steer_force) * boid_species.separation;
+        accel += steer_towards(heading, boid.vel, boid.max_speed, boid_species.max_steer_force) * boid_species.alignment;
+        accel += steer_towards(center_offset, boid.vel, boid.max_speed, boid_species.max_steer_force) * boid_species.cohesion;
+    }
+
+    var vel = boid.vel + accel * globals.delta;
+    let speed = clamp(length(vel), boid_species.min_speed, boid.max_speed);
+    vel = safe_normalize_to(vel, speed);
+    let pos = boid.pos + vel * globals.delta;
+    let time = boid.time + globals.delta * speed / boid_species.middle_speed;
+
+    results[i] = BoidOut(pos, time, vel, prey, threat, prey_dist);
+
+    // +x along the velocity without rolling, like vel_to_rot_mat in boid.rs
+    let forward = safe_normalize_to(vel, 1.0);
+    let flat = safe_normalize_to(vec3<f32>(vel.xy, 0.0), 1.0);
+    var left = vec3<f32>(0.0, 1.0, 0.0);
+    if flat.x != 0.0 || flat.y != 0.0 {
+        left = vec3<f32>(-flat.y, flat.x, 0.0);
+    }
+    let up = cross(forward, left);
+
+    var columns = array<vec4<f32>, 4>(
+        vec4<f32>(forward, 0.0),
+        vec4<f32>(left, 0.0),
+        vec4<f32>(up, 0.0),
+        vec4<f32>(pos, 1.0),
+    );
+    let base = boid.slot * INST_FLOATS;
+    for (var c = 0u; c < 4u; c++) {
+        for (var r = 0u; r < 4u; r++) {
+            insts[base + c * 4u + r] = columns[c][r];
+        }
+    }
+    insts[base + 16u] = time;
+    let moved = pos - boid.pos;
+    for (var r = 0u; r < 3u; r++) {
+        insts[base + 17u + r] = moved[r];
+    }
+}
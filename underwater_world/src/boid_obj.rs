@@ -1,3106 +0,0 @@
-pub fn red_obj() -> &'static str {
-r#"
-# Blender v2.79 (sub 0) OBJ File: 'fishred4.blend'
-# www.blender.org
-mtllib fishred.mtl
-o fishV1_Sphere
-v -0.142054 -0.815471 4.214375
-v -0.206751 -1.099525 3.856696
-v -0.284565 -1.433381 3.135250
-v -0.464588 -1.168499 1.878503
-v -0.463179 -1.125787 1.792535
-v -0.334524 -1.344300 0.210740
-v -0.284565 -1.192235 -0.698334
-v -0.218849 -0.965107 -1.212184
-v -0.033647 -0.641234 -2.361747
-v -0.143965 -0.713332 4.082266
-v -0.342432 -0.621299 3.644990
-v -0.460839 -0.694767 3.208851
-v -0.509986 -0.552285 2.810326
-v -0.518651 -0.962018 1.837924
-v -0.541265 -0.776035 0.210740
-v -0.460429 -0.711918 -0.698334
-v -0.354099 -0.686251 -1.212184
-v -0.143965 -0.356174 4.155542
-v -0.339477 -0.184797 3.725826
-v -0.541265 -0.045360 2.226175
-v -0.569119 -0.023266 1.218457
-v -0.541265 -0.045359 0.210740
-v -0.460429 -0.109477 -0.698334
-v -0.354099 -0.198413 -1.212184
-v -0.142054 -0.227333 4.181975
-v -0.271852 0.058550 3.655142
-v -0.329566 0.289958 3.174755
-v -0.358748 0.257157 2.830941
-v -0.351739 0.846458 1.218457
-v -0.334524 0.736170 0.210740
-v -0.284565 0.520916 -0.698334
-v -0.218849 0.256023 -1.212184
-v -0.033647 -0.051603 -2.361747
-v -0.011979 -0.963746 -3.179263
-v -0.011979 0.126773 -3.179263
-v 0.024491 -1.160996 -3.884142
-v -0.024512 -1.160996 -3.884142
-v -0.024512 0.400417 -3.884143
-v 0.024491 0.400417 -3.884143
-v -0.064011 0.985951 1.218457
-v -0.060879 0.853038 2.226175
-v -0.016828 -0.016804 -2.361747
-v -0.051788 0.633768 -0.698334
-v -0.039831 0.342812 -1.212184
-v -0.060879 0.868835 0.210740
-v -0.113376 -0.671567 -1.589591
-v -0.183440 -0.495769 -1.586043
-v -0.183440 -0.204782 -1.586043
-v -0.113376 0.030631 -1.589591
-v -0.020639 0.075591 -1.589591
-v -0.055088 -1.125772 -1.212184
-v -0.071628 -1.433037 -0.698334
-v -0.084201 -1.604915 0.210740
-v -0.113465 -0.478524 4.388983
-v -0.113465 -0.304215 4.249183
-v -0.143965 -0.459595 4.166020
-v -0.142054 -0.333853 4.204590
-v -0.298266 -0.670212 3.856696
-v -0.460429 -0.770077 3.135250
-v -0.505132 -1.010817 1.919188
-v -0.298266 -0.209343 3.856696
-v -0.510000 -0.089587 2.718942
-v -0.144800 -0.032788 3.883621
-v -0.222613 0.421122 3.162175
-v -0.272573 0.720372 2.226175
-v -0.350633 -0.184936 3.629454
-v -0.444671 -0.142798 3.237659
-v -0.488446 -0.107990 2.815712
-v -0.448715 -0.597084 3.211646
-v -0.342624 0.198750 3.184091
-v -0.353021 -0.537708 3.564124
-v -0.488435 -0.481933 2.889567
-v -0.295980 0.011731 3.572329
-v -0.366208 0.172241 2.906227
-v -0.188592 -1.678504 1.805578
-v -0.191021 -1.668048 1.638991
-v -0.541265 -0.757906 2.226175
-v -0.571833 -0.779999 1.218457
-v -0.397010 -1.292628 1.890853
-v -0.421855 -1.240999 1.675355
-v -0.511901 -0.970548 1.987287
-v -0.469980 -0.901407 1.812807
-v -0.463407 -1.306017 1.888047
-v -0.460590 -1.220632 1.716185
-v -0.566004 -0.990794 1.969381
-v -0.593028 -0.893237 1.806924
-v -0.590483 -1.242001 1.378400
-v -0.585425 -1.088711 1.069862
-v -0.774672 -0.676090 1.524417
-v -0.823188 -0.597827 1.232762
-v -0.658055 -1.129811 0.993214
-v -0.652997 -0.692406 0.691985
-v -0.842244 -0.563899 1.139231
-v -0.890760 -0.323998 0.582933
-v -0.000010 1.222664 0.210740
-v -0.000010 0.814706 -2.409037
-v -0.000010 -0.925838 4.191732
-v -0.000010 -1.333300 3.856696
-v -0.000010 -1.728876 3.135250
-v 0.000600 -1.894597 1.805012
-v -0.000010 -1.864367 1.218457
-v -0.000010 -1.737580 0.210740
-v -0.000010 -1.540698 -1.337385
-v -0.000010 -1.273545 -2.175413
-v -0.000010 -0.710831 -2.361747
-v 0.142034 -0.815471 4.214375
-v 0.206731 -1.099525 3.856696
-v 0.284544 -1.433381 3.135250
-v 0.464567 -1.168499 1.878503
-v 0.463158 -1.125787 1.792535
-v 0.334504 -1.344300 0.210740
-v 0.284544 -1.192235 -0.698334
-v 0.218828 -0.965107 -1.212184
-v 0.033626 -0.641234 -2.361747
-v 0.143944 -0.713332 4.082266
-v 0.342411 -0.621299 3.644990
-v 0.460818 -0.694767 3.208851
-v 0.509965 -0.552285 2.810326
-v 0.518630 -0.962018 1.837924
-v 0.541245 -0.776035 0.210740
-v 0.460409 -0.711918 -0.698334
-v 0.354078 -0.686251 -1.212184
-v 0.143944 -0.356174 4.155542
-v 0.339456 -0.184797 3.725826
-v 0.541245 -0.045360 2.226175
-v 0.569099 -0.023266 1.218457
-v 0.541245 -0.045359 0.210740
-v 0.460409 -0.109477 -0.698334
-v 0.354078 -0.198413 -1.212184
-v 0.142034 -0.227333 4.181975
-v 0.271831 0.058550 3.655142
-v 0.329546 0.289958 3.174755
-v 0.358727 0.257157 2.830941
-v 0.351718 0.846458 1.218457
-v 0.334504 0.736170 0.210740
-v 0.284544 0.520916 -0.698334
-v 0.218828 0.256023 -1.212184
-v 0.033626 -0.051603 -2.361747
-v -0.000010 -0.116966 4.204618
-v -0.000010 0.017834 4.049370
-v -0.000010 0.587402 3.135250
-v -0.000010 1.104183 2.226175
-v -0.000010 1.243924 1.218457
-v -0.000010 1.144608 -0.664849
-v -0.000010 0.017994 -2.361747
-v -0.000010 -1.092464 -3.179263
-v 0.011958 -0.963746 -3.179263
-v 0.011958 0.126773 -3.179263
-v -0.000010 0.255491 -3.179263
-v -0.000010 -1.365242 -3.884142
-v -0.024512 -1.160996 -3.884142
-v 0.024491 -1.160996 -3.884142
-v -0.000010 0.604664 -3.884143
-v 0.024491 0.400417 -3.884143
-v -0.024512 0.400417 -3.884143
-v 0.063991 0.985951 1.218457
-v 0.060858 0.853038 2.226175
-v 0.016808 -0.016804 -2.361747
-v 0.051767 0.633768 -0.698334
-v 0.039810 0.342812 -1.212184
-v 0.060858 0.868835 0.210740
-v -0.000010 -0.761487 -1.732544
-v 0.113356 -0.671567 -1.589591
-v 0.183420 -0.495769 -1.586043
-v 0.183420 -0.204782 -1.586043
-v 0.113356 0.030631 -1.589591
-v -0.000010 0.124205 -1.602247
-v 0.020618 0.075591 -1.589591
-v 0.055067 -1.125772 -1.212184
-v 0.071607 -1.433037 -0.698334
-v 0.084181 -1.604915 0.210740
-v -0.000010 -0.439773 4.501843
-v 0.113444 -0.478524 4.388983
-v -0.000010 -0.251298 4.246053
-v 0.113444 -0.304215 4.249183
-v -0.000010 -0.710737 4.083323
-v 0.143944 -0.459595 4.166020
-v 0.142034 -0.333853 4.204590
-v -0.000010 -0.226139 4.237630
-v 0.298245 -0.670212 3.856696
-v 0.460409 -0.770077 3.135250
-v 0.505112 -1.010817 1.919188
-v 0.298245 -0.209343 3.856696
-v 0.509979 -0.089587 2.718942
-v 0.144779 -0.032788 3.883621
-v 0.222593 0.421122 3.162175
-v 0.272552 0.720372 2.226175
-v 0.350612 -0.184936 3.629454
-v 0.444650 -0.142798 3.237659
-v 0.488425 -0.107990 2.815712
-v 0.448695 -0.597084 3.211646
-v 0.342603 0.198750 3.184091
-v 0.353000 -0.537708 3.564124
-v 0.488414 -0.481933 2.889567
-v 0.295959 0.011731 3.572329
-v 0.366187 0.172241 2.906227
-v 0.188572 -1.678504 1.805578
-v 0.191001 -1.668048 1.638991
-v 0.541245 -0.757906 2.226175
-v 0.571813 -0.779999 1.218457
-v 0.396989 -1.274287 1.890853
-v 0.421834 -1.177245 1.689388
-v 0.511880 -0.975857 1.982021
-v 0.469959 -0.910423 1.812807
-v 0.463386 -1.306017 1.888047
-v 0.460569 -1.220632 1.716185
-v 0.565983 -0.990794 1.969381
-v 0.593007 -0.893237 1.806924
-v 0.590462 -1.242001 1.378400
-v 0.585404 -1.088711 1.069862
-v 0.774651 -0.676090 1.524417
-v 0.823168 -0.597827 1.232762
-v 0.658034 -1.129811 0.993214
-v 0.652976 -0.692406 0.691985
-v 0.842223 -0.563899 1.139231
-v 0.890740 -0.323998 0.582933
-v -0.361648 -1.521005 2.226175
-v -0.381577 -1.439302 1.218457
-v -0.000010 -1.885142 2.226175
-v -0.000621 -1.889086 1.639579
-v 0.361628 -1.521005 2.226175
-v 0.381557 -1.439302 1.218457
-v 0.015492 -1.876901 1.791946
-v 0.014464 -1.872261 1.652643
-v 0.173775 -1.694939 1.792423
-v 0.175820 -1.686135 1.652148
-v -0.016564 -1.874462 1.790145
-v -0.017566 -1.869942 1.654444
-v -0.171756 -1.697204 1.790610
-v -0.173748 -1.688628 1.653961
-v 0.208319 -1.944136 0.797246
-v 0.207308 -1.937233 0.772987
-v 0.210209 -1.821899 1.236426
-v 0.212883 -1.811350 1.212414
-v -0.141524 -1.967945 0.793597
-v -0.142727 -1.960200 0.768384
-v -0.253907 -1.780932 1.212114
-v -0.256419 -1.768786 1.187203
-vt 0.275301 0.247877
-vt 0.187291 0.274156
-vt 0.187291 0.312307
-vt 0.275301 0.300994
-vt 0.628274 0.357231
-vt 0.558429 0.312352
-vt 0.558429 0.343442
-vt 0.628274 0.376827
-vt 0.315993 0.251396
-vt 0.316066 0.294976
-vt 0.316066 0.294976
-vt 0.315993 0.251396
-vt 0.119636 0.329816
-vt 0.085011 0.377836
-vt 0.082819 0.392086
-vt 0.117447 0.355411
-vt 0.663944 0.388134
-vt 0.706663 0.332944
-vt 0.658555 0.391820
-vt 0.470419 0.273032
-vt 0.372860 0.256663
-vt 0.372860 0.311543
-vt 0.470419 0.323809
-vt 0.470419 0.290161
-vt 0.470419 0.397177
-vt 0.558429 0.405455
-vt 0.187291 0.397946
-vt 0.275301 0.399518
-vt 0.628274 0.410840
-vt 0.303988 0.769990
-vt 0.297115 0.785122
-vt 0.292730 0.785635
-vt 0.306469 0.755384
-vt 0.095609 0.405273
-vt 0.117447 0.410840
-vt 0.655162 0.431708
-vt 0.372860 0.396665
-vt 0.160662 0.771964
-vt 0.142270 0.692143
-vt 0.136542 0.703300
-vt 0.150781 0.769458
-vt 0.470419 0.491514
-vt 0.558429 0.483236
-vt 0.099385 0.849937
-vt 0.136856 0.836538
-vt 0.129168 0.824975
-vt 0.098432 0.837517
-vt 0.628274 0.470343
-vt 0.275301 0.491514
-vt 0.372860 0.494367
-vt 0.088515 0.451385
-vt 0.117447 0.470343
-vt 0.654169 0.469939
-vt 0.628274 0.525771
-vt 0.652527 0.513573
-vt 0.372860 0.606657
-vt 0.470419 0.592417
-vt 0.558429 0.564626
-vt 0.103546 0.676944
-vt 0.058224 0.694304
-vt 0.066481 0.705687
-vt 0.103261 0.690246
-vt 0.275301 0.590378
-vt 0.085956 0.468020
-vt 0.114840 0.493138
-vt 0.372860 0.624667
-vt 0.275301 0.607506
-vt 0.274805 0.628016
-vt 0.372860 0.657974
-vt 0.083764 0.482269
-vt 0.100779 0.499011
-vt 0.652527 0.519377
-vt 0.628274 0.536357
-vt 0.704496 0.608214
-vt 0.653752 0.525654
-vt 0.470419 0.609546
-vt 0.470813 0.652602
-vt 0.184685 0.551742
-vt 0.187291 0.567431
-vt 0.717482 0.486075
-vt 0.715496 0.422525
-vt 0.558429 0.579196
-vt 0.555187 0.636681
-vt 0.798613 0.498379
-vt 0.798613 0.398810
-vt 0.717482 0.490567
-vt 0.717482 0.495060
-vt 0.798613 0.510131
-vt 0.715496 0.413539
-vt 0.798613 0.387057
-vt 0.869461 0.328838
-vt 0.869461 0.351502
-vt 0.869461 0.524757
-vt 0.869461 0.547420
-vt 0.869461 0.524757
-vt 0.869461 0.351502
-vt 0.620297 0.298452
-vt 0.054989 0.440592
-vt 0.065915 0.435589
-vt 0.079752 0.464926
-vt 0.079449 0.458094
-vt 0.087500 0.438033
-vt 0.083766 0.454267
-vt 0.080568 0.468174
-vt 0.095506 0.405608
-vt 0.137942 0.417155
-vt 0.180166 0.407670
-vt 0.218748 0.426065
-vt 0.130117 0.473512
-vt 0.227595 0.485804
-vt 0.183467 0.534807
-vt 0.136960 0.504930
-vt 0.216752 0.530572
-vt 0.043481 0.763025
-vt 0.100998 0.762091
-vt 0.056819 0.807321
-vt 0.033641 0.763044
-vt 0.048363 0.813696
-vt 0.308757 0.327852
-vt 0.328627 0.337146
-vt 0.317305 0.389630
-vt 0.292462 0.379746
-vt 0.309692 0.777123
-vt 0.321536 0.768802
-vt 0.307857 0.748171
-vt 0.302310 0.792741
-vt 0.283933 0.794645
-vt 0.300797 0.808929
-vt 0.303115 0.800867
-vt 0.317873 0.769644
-vt 0.365155 0.773417
-vt 0.353880 0.819443
-vt 0.336443 0.770590
-vt 0.329545 0.807884
-vt 0.355622 0.820648
-vt 0.365713 0.773538
-vt 0.385435 0.795546
-vt 0.394133 0.835382
-vt 0.275301 0.300994
-vt 0.187291 0.312307
-vt 0.628274 0.357231
-vt 0.628274 0.376827
-vt 0.558429 0.343442
-vt 0.558429 0.312352
-vt 0.332170 0.253142
-vt 0.332097 0.296732
-vt 0.332097 0.296732
-vt 0.332170 0.253142
-vt 0.117447 0.355411
-vt 0.082819 0.392086
-vt 0.658555 0.391820
-vt 0.470419 0.290161
-vt 0.470419 0.323809
-vt 0.372860 0.311543
-vt 0.558429 0.405455
-vt 0.470419 0.397177
-vt 0.275301 0.399518
-vt 0.187291 0.397946
-vt 0.628274 0.410840
-vt 0.303988 0.769990
-vt 0.306469 0.755384
-vt 0.292730 0.785635
-vt 0.297115 0.785122
-vt 0.117447 0.410840
-vt 0.095609 0.405273
-vt 0.655162 0.431708
-vt 0.372860 0.396665
-vt 0.160662 0.771964
-vt 0.150781 0.769458
-vt 0.136542 0.703300
-vt 0.142270 0.692143
-vt 0.558429 0.483236
-vt 0.470419 0.491514
-vt 0.099385 0.849937
-vt 0.098432 0.837517
-vt 0.129168 0.824975
-vt 0.136856 0.836538
-vt 0.628274 0.470343
-vt 0.372860 0.494367
-vt 0.275301 0.491514
-vt 0.117447 0.470343
-vt 0.088515 0.451385
-vt 0.654169 0.469939
-vt 0.652527 0.513573
-vt 0.628274 0.525771
-vt 0.470419 0.592417
-vt 0.372860 0.606657
-vt 0.558429 0.564626
-vt 0.103546 0.676944
-vt 0.103261 0.690246
-vt 0.066481 0.705687
-vt 0.058224 0.694304
-vt 0.275301 0.590378
-vt 0.114840 0.493138
-vt 0.085956 0.468020
-vt 0.372860 0.624667
-vt 0.275301 0.607506
-vt 0.652527 0.519377
-vt 0.628274 0.536357
-vt 0.470419 0.609546
-vt 0.184685 0.551742
-vt 0.715496 0.422525
-vt 0.717482 0.486075
-vt 0.558429 0.579196
-vt 0.798613 0.398810
-vt 0.798613 0.498379
-vt 0.717482 0.490567
-vt 0.869461 0.351502
-vt 0.869461 0.524757
-vt 0.869461 0.524757
-vt 0.869461 0.351502
-vt 0.065915 0.435589
-vt 0.079449 0.458094
-vt 0.083766 0.454267
-vt 0.087500 0.438033
-vt 0.137942 0.417155
-vt 0.180166 0.407670
-vt 0.218748 0.426065
-vt 0.130117 0.473512
-vt 0.227595 0.485804
-vt 0.183467 0.534807
-vt 0.136960 0.504930
-vt 0.216752 0.530572
-vt 0.100998 0.762091
-vt 0.043481 0.763025
-vt 0.056819 0.807321
-vt 0.033641 0.763044
-vt 0.048363 0.813696
-vt 0.308757 0.327852
-vt 0.328627 0.337146
-vt 0.317305 0.389630
-vt 0.292462 0.379746
-vt 0.307857 0.748171
-vt 0.321536 0.768802
-vt 0.309692 0.777123
-vt 0.302310 0.792741
-vt 0.300797 0.808929
-vt 0.283933 0.794645
-vt 0.303115 0.800867
-vt 0.353880 0.819443
-vt 0.365155 0.773417
-vt 0.317873 0.769644
-vt 0.336443 0.770590
-vt 0.365713 0.773538
-vt 0.355622 0.820648
-vt 0.329939 0.808409
-vt 0.385435 0.795546
-vt 0.394133 0.835382
-vt 0.332097 0.296732
-vt 0.316066 0.294976
-vt 0.498099 0.309706
-vt 0.509171 0.287977
-vt 0.669295 0.322029
-vt 0.627297 0.323597
-vt 0.316066 0.294976
-vt 0.315993 0.251396
-vt 0.486065 0.297631
-vt 0.497263 0.275902
-vt 0.663809 0.315299
-vt 0.621712 0.316939
-vt 0.332170 0.253142
-vt 0.332097 0.296732
-vt 0.669576 0.321709
-vt 0.627161 0.324093
-vt 0.663677 0.315785
-vt 0.621988 0.316628
-vt 0.509475 0.287846
-vt 0.486367 0.297508
-vt 0.498109 0.310152
-vt 0.497275 0.276341
-vn -0.0001 -0.9971 0.0753
-vn 0.0000 -0.9494 0.3139
-vn -0.8473 -0.4869 0.2118
-vn -0.8701 -0.4902 0.0517
-vn -0.9281 -0.3003 -0.2200
-vn -0.8504 -0.5042 -0.1503
-vn -0.8220 -0.5219 -0.2278
-vn -0.8711 -0.3671 -0.3262
-vn 0.0025 -0.9996 -0.0267
-vn -0.7337 -0.6788 -0.0279
-vn -0.7638 -0.6449 0.0255
-vn -0.7226 -0.6908 0.0256
-vn 0.0000 -0.8100 0.5865
-vn 0.0000 -0.6229 0.7823
-vn -0.7656 -0.3121 0.5625
-vn -0.7992 -0.4237 0.4261
-vn 0.0000 -0.9240 -0.3823
-vn 0.0000 -0.6334 -0.7738
-vn -0.8996 -0.3334 -0.2820
-vn 0.0000 -0.9881 -0.1537
-vn 0.0001 -0.9954 -0.0959
-vn -0.8794 -0.4750 -0.0320
-vn -0.8559 -0.5068 -0.1029
-vn -0.7962 -0.5963 -0.1020
-vn -0.9835 -0.1668 -0.0702
-vn -0.9706 -0.1783 -0.1618
-vn -0.9754 -0.1668 0.1438
-vn -0.9885 -0.1441 0.0447
-vn -0.9136 -0.2037 -0.3518
-vn -0.0954 -0.5368 0.8383
-vn -0.4060 0.3638 0.8383
-vn -0.2208 0.2787 0.9346
-vn 0.1906 -0.8133 0.5496
-vn -0.8568 -0.0951 0.5067
-vn -0.9362 -0.0912 0.3394
-vn -0.9347 -0.1525 -0.3211
-vn -0.9894 -0.1449 0.0018
-vn -0.9716 0.1806 0.1530
-vn -0.9933 -0.0090 0.1154
-vn -0.9842 0.0575 0.1674
-vn -0.9664 0.1753 0.1881
-vn -0.9892 0.1304 -0.0667
-vn -0.9770 0.1368 -0.1638
-vn -0.8713 0.4239 0.2471
-vn -0.9267 0.3469 0.1445
-vn -0.9539 0.2637 0.1429
-vn -0.9622 0.2280 0.1485
-vn -0.9397 0.1257 -0.3179
-vn -0.9842 0.1633 0.0686
-vn -0.9905 0.1370 0.0086
-vn -0.9637 0.0678 0.2581
-vn -0.8852 0.2052 0.4175
-vn -0.9467 0.1295 -0.2950
-vn -0.7025 0.5600 -0.4392
-vn -0.7646 0.5500 -0.3360
-vn -0.7663 0.6423 0.0152
-vn -0.7613 0.6345 -0.1331
-vn -0.7321 0.6174 -0.2876
-vn -0.9797 0.0016 0.2002
-vn -0.9743 -0.0221 0.2239
-vn -0.9793 0.0279 0.2004
-vn -0.9788 0.0649 0.1942
-vn -0.8003 0.5800 0.1517
-vn -0.6800 0.3744 0.6304
-vn -0.6852 0.5618 0.4635
-vn -0.8068 0.5907 0.0121
-vn -0.7480 0.6432 0.1633
-vn 0.0000 0.9608 0.2770
-vn 0.0000 0.9993 0.0380
-vn 0.0000 0.6231 0.7822
-vn 0.0000 0.8161 0.5778
-vn -0.7743 0.5802 -0.2523
-vn -0.8237 0.4716 -0.3147
-vn 0.0000 0.4615 -0.8871
-vn 0.0000 0.8986 -0.4388
-vn -0.8086 0.5792 -0.1034
-vn 0.0000 0.9919 -0.1273
-vn -0.7092 0.6242 0.3276
-vn 0.0000 0.8999 0.4361
-vn -0.9585 0.2735 -0.0804
-vn -0.9727 -0.2238 -0.0601
-vn -0.8332 0.5159 -0.1988
-vn 0.0000 0.9693 -0.2457
-vn -0.9961 0.0849 0.0233
-vn -0.9951 -0.0922 0.0355
-vn -0.8975 0.4402 -0.0244
-vn 0.0000 0.9999 -0.0109
-vn 0.0000 0.9473 0.3202
-vn 0.0000 -0.9998 0.0211
-vn 0.0000 -0.9310 0.3648
-vn 0.0000 -0.5790 -0.8153
-vn -0.7302 -0.0468 -0.6816
-vn -0.7328 0.0494 -0.6786
-vn 0.0000 0.5682 -0.8229
-vn 0.0000 0.0000 -1.0000
-vn 0.0000 -0.9217 -0.3878
-vn 0.0000 0.1914 0.9815
-vn -0.7971 0.0521 0.6016
-vn 0.0000 0.7370 0.6759
-vn -0.7716 0.4664 0.4325
-vn -0.7027 -0.2155 0.6781
-vn -0.6825 -0.1013 0.7238
-vn 0.0000 0.0786 0.9969
-vn 0.0000 -0.3049 0.9524
-vn -0.9407 0.2012 0.2730
-vn -0.7745 0.5116 0.3719
-vn -0.9741 0.1418 0.1762
-vn -0.9689 0.1632 0.1857
-vn -0.9471 0.2571 0.1922
-vn -0.9656 -0.2596 0.0159
-vn -0.9733 -0.2291 -0.0104
-vn -0.9782 -0.1894 -0.0851
-vn -0.9713 -0.2344 0.0386
-vn -0.1033 0.1506 -0.9832
-vn -0.1876 0.9082 -0.3741
-vn 0.7414 0.6695 0.0451
-vn 0.9690 -0.1190 -0.2163
-vn 0.9605 -0.0701 -0.2694
-vn 0.4678 0.8455 0.2574
-vn -0.1770 -0.9704 -0.1639
-vn -0.5288 0.6858 0.5000
-vn -0.8843 0.4248 0.1936
-vn -0.2122 -0.8398 -0.4996
-vn 0.3942 0.3332 -0.8565
-vn -0.2059 0.8019 -0.5608
-vn 0.8713 -0.4881 0.0508
-vn 0.8473 -0.4869 0.2118
-vn 0.9281 -0.3003 -0.2200
-vn 0.8711 -0.3671 -0.3262
-vn 0.8220 -0.5219 -0.2278
-vn 0.8504 -0.5042 -0.1503
-vn -0.0027 -0.9992 -0.0404
-vn 0.7413 -0.6703 -0.0340
-vn 0.9457 -0.3197 -0.0584
-vn 0.2026 -0.9703 -0.1318
-vn 0.7992 -0.4237 0.4261
-vn 0.7656 -0.3121 0.5625
-vn 0.8996 -0.3334 -0.2820
-vn 0.7962 -0.5963 -0.1020
-vn 0.8559 -0.5068 -0.1029
-vn 0.8796 -0.4747 -0.0307
-vn 0.9706 -0.1783 -0.1618
-vn 0.9835 -0.1668 -0.0702
-vn 0.9887 -0.1429 0.0440
-vn 0.9754 -0.1668 0.1438
-vn 0.9136 -0.2037 -0.3518
-vn 0.0179 -0.6071 0.7944
-vn -0.1906 -0.8133 0.5496
-vn 0.2208 0.2787 0.9346
-vn 0.3688 0.3708 0.8523
-vn 0.9362 -0.0912 0.3394
-vn 0.8568 -0.0951 0.5067
-vn 0.9347 -0.1525 -0.3211
-vn 0.9889 -0.1484 0.0045
-vn 0.9716 0.1806 0.1530
-vn 0.9664 0.1753 0.1881
-vn 0.9842 0.0575 0.1674
-vn 0.9933 -0.0090 0.1154
-vn 0.9770 0.1368 -0.1638
-vn 0.9892 0.1304 -0.0667
-vn 0.8713 0.4238 0.2471
-vn 0.9622 0.2280 0.1485
-vn 0.9539 0.2637 0.1429
-vn 0.9267 0.3469 0.1445
-vn 0.9397 0.1257 -0.3179
-vn 0.9905 0.1370 0.0086
-vn 0.9842 0.1633 0.0686
-vn 0.8852 0.2052 0.4175
-vn 0.9637 0.0678 0.2581
-vn 0.9467 0.1295 -0.2950
-vn 0.7646 0.5500 -0.3360
-vn 0.7025 0.5600 -0.4392
-vn 0.7613 0.6345 -0.1331
-vn 0.7663 0.6423 0.0152
-vn 0.7321 0.6174 -0.2876
-vn 0.9797 0.0016 0.2002
-vn 0.9788 0.0649 0.1942
-vn 0.9793 0.0279 0.2004
-vn 0.9743 -0.0221 0.2239
-vn 0.8003 0.5800 0.1517
-vn 0.6852 0.5618 0.4635
-vn 0.6800 0.3744 0.6304
-vn 0.8068 0.5907 0.0121
-vn 0.7480 0.6432 0.1633
-vn 0.7743 0.5802 -0.2523
-vn 0.8237 0.4716 -0.3147
-vn 0.8086 0.5792 -0.1034
-vn 0.7092 0.6242 0.3276
-vn 0.9727 -0.2238 -0.0601
-vn 0.9585 0.2735 -0.0804
-vn 0.8332 0.5159 -0.1988
-vn 0.9951 -0.0922 0.0355
-vn 0.9961 0.0849 0.0233
-vn 0.8975 0.4402 -0.0244
-vn 0.7328 0.0494 -0.6786
-vn 0.7302 -0.0468 -0.6816
-vn 0.7971 0.0521 0.6016
-vn 0.7716 0.4664 0.4325
-vn 0.6825 -0.1013 0.7238
-vn 0.7027 -0.2155 0.6781
-vn 0.9407 0.2012 0.2730
-vn 0.7745 0.5116 0.3719
-vn 0.9689 0.1632 0.1857
-vn 0.9741 0.1418 0.1762
-vn 0.9471 0.2571 0.1922
-vn 0.9651 -0.2619 0.0000
-vn 0.9704 -0.2364 -0.0501
-vn 0.9792 -0.1777 -0.0980
-vn 0.9715 -0.2325 0.0460
-vn 0.0450 0.0432 -0.9980
-vn 0.1429 0.9203 -0.3642
-vn -0.7414 0.6695 0.0451
-vn -0.4678 0.8455 0.2574
-vn -0.9605 -0.0701 -0.2694
-vn -0.9690 -0.1190 -0.2163
-vn 0.1770 -0.9704 -0.1639
-vn 0.2122 -0.8398 -0.4996
-vn 0.8843 0.4248 0.1936
-vn 0.5288 0.6858 0.5000
-vn -0.3942 0.3332 -0.8565
-vn 0.2059 0.8019 -0.5608
-vn -0.7417 -0.6699 -0.0337
-vn 0.7342 -0.6784 -0.0281
-vn -0.9864 -0.1596 -0.0388
-vn -0.0145 -0.9884 -0.1512
-vn -0.1605 -0.6668 -0.7277
-vn -0.4812 0.7875 -0.3850
-vn 0.7739 -0.6328 0.0239
-vn 0.7278 -0.6854 0.0224
-vn 0.4441 -0.8957 -0.0212
-vn 0.9438 -0.3071 0.1215
-vn 0.4603 -0.8126 -0.3575
-vn 0.4284 0.8636 -0.2658
-vn -0.0964 -0.9911 -0.0914
-vn -0.9675 -0.2266 0.1117
-usemtl FISHV1
-s 1
-f 219/1/1 99/2/2 3/3/3 217/4/4
-f 51/5/5 52/6/6 7/7/7 8/8/8
-f 100/9/9 75/10/10 229/11/11 227/12/12
-f 98/13/13 97/14/14 1/15/15 2/16/16
-f 162/17/17 104/18/18 51/5/5 8/8/8 46/19/19
-f 102/20/20 101/21/21 218/22/22 6/23/23 53/24/24
-f 99/2/2 98/13/13 2/16/16 3/3/3
-f 52/6/6 53/24/24 6/23/23 7/7/7
-f 7/7/7 6/23/23 15/25/25 16/26/26
-f 217/4/4 3/3/3 59/27/27 77/28/28
-f 8/8/8 7/7/7 16/26/26 17/29/29
-f 4/30/30 60/31/31 85/32/32 83/33/33
-f 2/16/16 1/15/15 10/34/34 58/35/35
-f 46/19/19 8/8/8 17/29/29 47/36/36
-f 6/23/23 218/22/22 78/37/37 15/25/25
-f 3/3/3 2/16/16 58/35/35 59/27/27
-f 62/38/38 13/39/39 72/40/40 68/41/41
-f 16/26/26 15/25/25 22/42/42 23/43/43
-f 27/44/44 28/45/45 74/46/46 70/47/47
-f 17/29/29 16/26/26 23/43/43 24/48/48
-f 78/37/37 77/28/28 20/49/49 21/50/50
-f 58/35/35 10/34/34 18/51/51 61/52/52
-f 47/36/36 17/29/29 24/48/48 48/53/53
-f 15/25/25 78/37/37 21/50/50 22/42/42
-f 48/53/53 24/48/48 32/54/54 49/55/55
-f 22/42/42 21/50/50 29/56/56 30/57/57
-f 28/45/45 62/38/38 68/41/41 74/46/46
-f 23/43/43 22/42/42 30/57/57 31/58/58
-f 12/59/59 11/60/60 71/61/61 69/62/62
-f 24/48/48 23/43/43 31/58/58 32/54/54
-f 21/50/50 20/49/49 65/63/63 29/56/56
-f 61/52/52 18/51/51 25/64/64 63/65/65
-f 40/66/66 41/67/67 142/68/68 143/69/69
-f 63/65/65 25/64/64 139/70/70 140/71/71
-f 50/72/72 44/73/73 96/74/74 167/75/75
-f 45/76/76 40/66/66 143/69/69 95/77/77
-f 64/78/78 63/65/65 140/71/71 141/79/79
-f 47/36/36 48/53/53 33/80/80 9/81/81
-f 43/82/82 45/76/76 95/77/77 144/83/83
-f 65/63/63 64/78/78 141/79/79 142/68/68 41/67/67
-f 44/73/73 43/82/82 144/83/83 96/74/74
-f 9/81/81 33/80/80 35/84/84 34/85/85
-f 33/80/80 42/86/86 145/87/87 149/88/88 35/84/84
-f 105/89/89 9/81/81 34/85/85 146/90/90
-f 150/91/91 37/92/92 38/93/93 153/94/94 39/95/95 36/96/95
-f 146/90/90 34/85/85 37/92/92 150/91/91
-f 34/85/85 35/84/84 38/93/93 37/92/92
-f 35/84/84 149/88/88 153/94/94 38/93/93
-f 32/54/54 31/58/58 43/82/82 44/73/73
-f 31/58/58 30/57/57 45/76/76 43/82/82
-f 30/57/57 29/56/56 40/66/66 45/76/76
-f 49/55/55 32/54/54 44/73/73 50/72/72
-f 29/56/56 65/63/63 41/67/67 40/66/66
-f 33/80/80 49/55/55 50/72/72 42/86/86
-f 42/86/86 50/72/72 167/75/75 145/87/87
-f 48/53/53 49/55/55 33/80/80
-f 9/81/81 46/19/19 47/36/36
-f 105/89/89 162/17/17 46/19/19 9/81/81
-f 103/97/96 102/20/20 53/24/24 52/6/6
-f 104/18/18 103/97/96 52/6/6 51/5/5
-f 1/15/15 97/14/14 172/98/97 54/99/98
-f 54/99/98 172/98/97 174/100/99 55/101/100
-f 1/15/15 54/99/98 55/101/100 10/34/34
-f 25/64/64 18/51/51 56/102/101 57/103/102
-f 139/70/70 25/64/64 57/103/102 179/104/103
-f 10/34/34 56/102/101 18/51/51
-f 57/103/102 56/102/101 10/34/34 176/105/104 179/104/103
-f 11/106/60 12/107/59 59/27/27 58/35/35
-f 12/107/59 13/108/39 77/28/28 59/27/27
-f 19/109/105 11/106/60 58/35/35 61/52/52
-f 13/108/39 62/110/38 20/49/49 77/28/28
-f 27/111/44 26/112/106 63/65/65 64/78/78
-f 28/113/45 27/111/44 64/78/78 65/63/63
-f 26/112/106 19/109/105 61/52/52 63/65/65
-f 62/110/38 28/113/45 65/63/63 20/49/49
-f 69/62/62 71/61/61 66/114/107 67/115/108
-f 72/40/40 69/62/62 67/115/108 68/41/41
-f 67/115/108 66/114/107 73/116/109 70/47/47
-f 68/41/41 67/115/108 70/47/47 74/46/46
-f 13/39/39 12/59/59 69/62/62 72/40/40
-f 19/117/105 26/118/106 73/116/109 66/114/107
-f 26/118/106 27/44/44 70/47/47 73/116/109
-f 11/60/60 19/117/105 66/114/107 71/61/61
-f 79/119/110 80/120/111 218/22/22 217/4/4
-f 82/121/112 81/122/113 77/28/28 78/37/37
-f 81/122/113 79/119/110 217/4/4 77/28/28
-f 80/120/111 82/121/112 78/37/37 218/22/22
-f 4/30/30 5/123/114 80/124/111 79/125/110
-f 14/126/115 60/31/31 81/127/113 82/128/112
-f 60/31/31 4/30/30 79/125/110 81/127/113
-f 5/123/114 14/126/115 82/128/112 80/124/111
-f 86/129/116 84/130/117 88/131/118 90/132/119
-f 5/123/114 4/30/30 83/33/33 84/130/117
-f 14/126/115 5/123/114 84/130/117 86/129/116
-f 60/31/31 14/126/115 86/129/116 85/32/32
-f 87/133/120 89/134/121 93/135/122 91/136/123
-f 85/32/32 86/129/116 90/132/119 89/134/121
-f 83/33/33 85/32/32 89/134/121 87/133/120
-f 84/130/117 83/33/33 87/133/120 88/131/118
-f 92/137/124 91/136/123 93/135/122 94/138/125
-f 88/131/118 87/133/120 91/136/123 92/137/124
-f 90/132/119 88/131/118 92/137/124 94/138/125
-f 89/134/121 90/132/119 94/138/125 93/135/122
-f 219/1/1 221/139/126 108/140/127 99/2/2
-f 169/141/128 113/142/129 112/143/130 170/144/131
-f 220/145/132 198/146/133 226/147/134 224/148/135
-f 98/13/13 107/149/136 106/150/137 97/14/14
-f 162/17/17 163/151/138 113/142/129 169/141/128 104/18/18
-f 102/20/20 171/152/139 111/153/140 222/154/141 101/21/21
-f 99/2/2 108/140/127 107/149/136 98/13/13
-f 170/144/131 112/143/130 111/153/140 171/152/139
-f 112/143/130 121/155/142 120/156/143 111/153/140
-f 221/139/126 199/157/144 181/158/145 108/140/127
-f 113/142/129 122/159/146 121/155/142 112/143/130
-f 109/160/147 205/161/148 207/162/149 182/163/150
-f 107/149/136 180/164/151 115/165/152 106/150/137
-f 163/151/138 164/166/153 122/159/146 113/142/129
-f 111/153/140 120/156/143 200/167/154 222/154/141
-f 108/140/127 181/158/145 180/164/151 107/149/136
-f 184/168/155 190/169/156 194/170/157 118/171/158
-f 121/155/142 128/172/159 127/173/160 120/156/143
-f 132/174/161 192/175/162 196/176/163 133/177/164
-f 122/159/146 129/178/165 128/172/159 121/155/142
-f 200/167/154 126/179/166 125/180/167 199/157/144
-f 180/164/151 183/181/168 123/182/169 115/165/152
-f 164/166/153 165/183/170 129/178/165 122/159/146
-f 120/156/143 127/173/160 126/179/166 200/167/154
-f 165/183/170 166/184/171 137/185/172 129/178/165
-f 127/173/160 135/186/173 134/187/174 126/179/166
-f 133/177/164 196/176/163 190/169/156 184/168/155
-f 128/172/159 136/188/175 135/186/173 127/173/160
-f 117/189/176 191/190/177 193/191/178 116/192/179
-f 129/178/165 137/185/172 136/188/175 128/172/159
-f 126/179/166 134/187/174 187/193/180 125/180/167
-f 183/181/168 185/194/181 130/195/182 123/182/169
-f 156/196/183 143/69/69 142/68/68 157/197/184
-f 185/194/181 140/71/71 139/70/70 130/195/182
-f 168/198/185 167/75/75 96/74/74 160/199/186
-f 161/200/187 95/77/77 143/69/69 156/196/183
-f 186/201/188 141/79/79 140/71/71 185/194/181
-f 164/166/153 114/202/189 138/203/190 165/183/170
-f 159/204/191 144/83/83 95/77/77 161/200/187
-f 187/193/180 157/197/184 142/68/68 141/79/79 186/201/188
-f 160/199/186 96/74/74 144/83/83 159/204/191
-f 114/202/189 147/205/192 148/206/193 138/203/190
-f 138/203/190 148/206/193 149/88/88 145/87/87 158/207/194
-f 105/89/89 146/90/90 147/205/192 114/202/189
-f 150/91/91 151/208/95 155/209/95 153/94/94 154/210/195 152/211/196
-f 146/90/90 150/91/91 152/211/196 147/205/192
-f 147/205/192 152/211/196 154/210/195 148/206/193
-f 148/206/193 154/210/195 153/94/94 149/88/88
-f 137/185/172 160/199/186 159/204/191 136/188/175
-f 136/188/175 159/204/191 161/200/187 135/186/173
-f 135/186/173 161/200/187 156/196/183 134/187/174
-f 166/184/171 168/198/185 160/199/186 137/185/172
-f 134/187/174 156/196/183 157/197/184 187/193/180
-f 138/203/190 158/207/194 168/198/185 166/184/171
-f 158/207/194 145/87/87 167/75/75 168/198/185
-f 165/183/170 138/203/190 166/184/171
-f 114/202/189 164/166/153 163/151/138
-f 105/89/89 114/202/189 163/151/138 162/17/17
-f 103/97/96 170/144/131 171/152/139 102/20/20
-f 104/18/18 169/141/128 170/144/131 103/97/96
-f 106/150/137 173/212/197 172/98/97 97/14/14
-f 173/212/197 175/213/198 174/100/99 172/98/97
-f 106/150/137 115/165/152 175/213/198 173/212/197
-f 130/195/182 178/214/199 177/215/200 123/182/169
-f 139/70/70 179/104/103 178/214/199 130/195/182
-f 115/165/152 123/182/169 177/215/200
-f 178/214/199 179/104/103 176/105/104 115/165/152 177/215/200
-f 116/216/179 180/164/151 181/158/145 117/217/176
-f 117/217/176 181/158/145 199/157/144 118/218/158
-f 124/219/201 183/181/168 180/164/151 116/216/179
-f 118/218/158 199/157/144 125/180/167 184/220/155
-f 132/221/161 186/201/188 185/194/181 131/222/202
-f 133/223/164 187/193/180 186/201/188 132/221/161
-f 131/222/202 185/194/181 183/181/168 124/219/201
-f 184/220/155 125/180/167 187/193/180 133/223/164
-f 191/190/177 189/224/203 188/225/204 193/191/178
-f 194/170/157 190/169/156 189/224/203 191/190/177
-f 189/224/203 192/175/162 195/226/205 188/225/204
-f 190/169/156 196/176/163 192/175/162 189/224/203
-f 118/171/158 194/170/157 191/190/177 117/189/176
-f 124/227/201 188/225/204 195/226/205 131/228/202
-f 131/228/202 195/226/205 192/175/162 132/174/161
-f 116/192/179 193/191/178 188/225/204 124/227/201
-f 201/229/206 221/139/126 222/154/141 202/230/207
-f 204/231/208 200/167/154 199/157/144 203/232/209
-f 203/232/209 199/157/144 221/139/126 201/229/206
-f 202/230/207 222/154/141 200/167/154 204/231/208
-f 109/160/147 201/233/206 202/234/207 110/235/210
-f 119/236/211 204/237/208 203/238/209 182/163/150
-f 182/163/150 203/238/209 201/233/206 109/160/147
-f 110/235/210 202/234/207 204/237/208 119/236/211
-f 208/239/212 212/240/213 210/241/214 206/242/215
-f 110/235/210 206/242/215 205/161/148 109/160/147
-f 119/236/211 208/239/212 206/242/215 110/235/210
-f 182/163/150 207/162/149 208/239/212 119/236/211
-f 209/243/216 213/244/217 215/245/218 211/246/219
-f 207/162/149 211/246/219 212/240/213 208/239/212
-f 205/161/148 209/243/216 211/246/219 207/162/149
-f 206/242/215 210/241/214 209/243/216 205/161/148
-f 214/247/220 216/248/221 215/245/218 213/244/217
-f 210/241/214 214/247/220 213/244/217 209/243/216
-f 212/240/213 216/248/221 214/247/220 210/241/214
-f 211/246/219 215/245/218 216/248/221 212/240/213
-f 76/249/222 75/10/10 217/4/4 218/22/22
-f 75/10/10 100/9/9 219/1/1 217/4/4
-f 220/145/132 76/249/222 218/22/22 101/21/21
-f 197/250/223 198/146/133 222/154/141 221/139/126
-f 100/9/9 197/250/223 221/139/126 219/1/1
-f 198/146/133 220/145/132 101/21/21 222/154/141
-f 230/251/224 228/252/225 236/253/226 238/254/227
-f 198/146/133 197/250/223 225/255/228 226/147/134
-f 100/9/9 220/145/132 224/148/135 223/256/229
-f 197/250/223 100/9/9 223/256/229 225/255/228
-f 225/257/228 223/258/229 231/259/230 233/260/231
-f 220/145/132 100/9/9 227/12/12 228/261/225
-f 76/249/222 220/145/132 228/261/225 230/262/224
-f 75/10/10 76/249/222 230/262/224 229/11/11
-f 232/263/232 234/264/233 233/260/231 231/259/230
-f 236/253/226 235/265/234 237/266/235 238/254/227
-f 223/258/229 224/267/135 232/263/232 231/259/230
-f 229/268/11 230/251/224 238/254/227 237/266/235
-f 224/267/135 226/269/134 234/264/233 232/263/232
-f 226/269/134 225/257/228 233/260/231 234/264/233
-f 227/270/12 229/268/11 237/266/235 235/265/234
-f 228/252/225 227/270/12 235/265/234 236/253/226
-"#
-}
-
-pub fn green_obj() -> &'static str {
-r#"
-# Blender v2.79 (sub 0) OBJ File: 'newaaaa3.blend'
-# www.blender.org
-mtllib fish.mtl
-o FISH_Plane
-v 0.637210 0.027893 0.385041
-v 0.630244 0.025130 2.578381
-v 0.706934 -0.859753 0.441920
-v 0.245722 -0.089743 -2.513955
-v 0.386398 0.819295 0.437010
-v 0.396645 0.719626 2.523860
-v 0.656858 -0.785386 2.610010
-v 0.265410 -0.431946 -2.531150
-v 0.172274 0.235627 -2.484186
-v -0.053337 0.531073 -2.458965
-v -0.055472 1.461152 0.496648
-v -0.051699 1.356632 2.474756
-v 0.185793 0.051192 4.843890
-v 0.174016 -0.049116 4.861904
-v 0.177888 -0.157236 4.849121
-v 0.108008 -1.415921 2.492424
-v -0.043365 -1.549272 0.450552
-v -0.047325 -0.731821 -2.551451
-v -0.056079 -0.697001 -4.527766
-v -0.056079 -0.105278 -4.389502
-v -0.056079 0.486445 -4.527766
-v 0.169879 0.179935 4.839464
-v 0.153369 -0.274611 4.851509
-v -0.056079 1.078168 -4.818175
-v -0.056079 -1.288724 -4.818175
-v 0.702925 0.014120 1.617417
-v 0.458215 0.862259 1.585920
-v 0.682739 -0.887081 1.632500
-v -0.055754 1.530250 1.582533
-v -0.048785 -1.518219 1.637078
-v 0.443846 0.033709 -1.509664
-v 0.457538 -0.536087 -1.512073
-v 0.183243 0.558052 -1.421361
-v -0.055077 1.041507 -1.376986
-v -0.040545 -1.348053 -1.421288
-v 0.481740 0.000053 3.623899
-v 0.301909 0.439209 3.574004
-v 0.448746 -0.516257 3.640587
-v 0.121166 0.879929 3.569696
-v 0.196013 -1.119060 3.635880
-v -0.055548 1.250899 -0.439844
-v 0.534328 0.006281 -0.601439
-v 0.552675 -0.677139 -0.575528
-v 0.318129 0.682234 -0.515758
-v -0.037713 -1.453752 -0.453209
-v -0.056079 -2.267688 0.626610
-v -0.056033 -1.925169 1.370098
-v -0.052403 -1.795025 0.014863
-v -0.055310 -1.243719 -2.996632
-v -0.053073 -1.667008 -2.300243
-v -0.046446 -1.778182 -0.962715
-v -0.056079 1.511209 -3.033050
-v -0.056079 2.054451 -0.032900
-v -0.056079 1.925020 1.911451
-v -0.056079 2.123271 1.052596
-v -0.056079 1.501193 -1.842815
-v -0.056079 1.843554 -0.968612
-v -0.047666 -0.074216 -2.804092
-v -0.054938 -0.105166 -3.540679
-v -0.047190 -0.377083 -2.812579
-v -0.055412 -0.553038 -3.540652
-v -0.050667 0.220212 -2.788079
-v -0.055878 0.342190 -3.539401
-v -0.056079 0.789439 -3.539116
-v -0.054796 0.514941 -2.768621
-v -0.050706 -0.723831 -2.800561
-v -0.055983 -1.000863 -3.538546
-v -0.712984 0.021939 0.406196
-v -0.701948 0.026627 2.545476
-v -0.771903 -0.847612 0.455152
-v -0.350543 -0.092066 -2.500460
-v -0.449122 0.797287 0.456436
-v -0.448091 0.708516 2.494225
-v -0.738851 -0.768891 2.578321
-v -0.372032 -0.429254 -2.516105
-v -0.283997 0.230148 -2.476547
-v -0.064815 0.519658 -2.463973
-v -0.062689 1.460950 0.496798
-v -0.065677 1.351923 2.464377
-v -0.293776 0.046687 4.826990
-v -0.283475 -0.050879 4.841642
-v -0.285938 -0.153632 4.829079
-v -0.232315 -1.411279 2.483379
-v -0.073118 -1.551480 0.519560
-v -0.070294 -0.762669 -2.525684
-v -0.062167 -0.697001 -4.527766
-v -0.062167 -0.105278 -4.389503
-v -0.062167 0.486445 -4.527766
-v -0.284450 0.148799 4.829336
-v -0.263804 -0.286305 4.829614
-v -0.062167 1.078168 -4.818175
-v -0.062167 -1.288724 -4.818175
-v -0.761665 0.013049 1.610346
-v -0.497327 0.837082 1.585004
-v -0.749378 -0.870502 1.626574
-v -0.062425 1.529913 1.582579
-v -0.068965 -1.495516 1.648666
-v -0.520754 0.026061 -1.482096
-v -0.540659 -0.538364 -1.489859
-v -0.294022 0.551894 -1.412405
-v -0.063097 1.040981 -1.376443
-v -0.075476 -1.348786 -1.412351
-v -0.578554 -0.001100 3.604079
-v -0.416406 0.438031 3.558766
-v -0.560722 -0.508208 3.624372
-v -0.236275 0.867982 3.556402
-v -0.314789 -1.110981 3.612234
-v -0.062646 1.250598 -0.439511
-v -0.605416 0.002137 -0.573115
-v -0.636632 -0.682388 -0.558766
-v -0.398454 0.670679 -0.495448
-v -0.078074 -1.444666 -0.451725
-v -0.062167 -2.267688 0.626610
-v -0.062217 -1.925218 1.370021
-v -0.065719 -1.788794 -0.004039
-v -0.062818 -1.239315 -2.997689
-v -0.064884 -1.660424 -2.303263
-v -0.070536 -1.758143 -0.965770
-v -0.062167 1.511209 -3.033050
-v -0.062167 2.054451 -0.032900
-v -0.062167 1.925020 1.911451
-v -0.062167 2.123271 1.052596
-v -0.062167 1.501193 -1.842815
-v -0.062167 1.843554 -0.968612
-v -0.069949 -0.074632 -2.798994
-v -0.063264 -0.105174 -3.540549
-v -0.070446 -0.375577 -2.806435
-v -0.062809 -0.552999 -3.540514
-v -0.067194 0.219029 -2.785591
-v -0.062360 0.342186 -3.539387
-v -0.062167 0.789439 -3.539116
-v -0.063372 0.514327 -2.772136
-v -0.067503 -0.719607 -2.776257
-v -0.062264 -1.000987 -3.538653
-v -0.062905 0.591908 -2.564858
-v -0.062400 1.462013 2.542262
-v -0.064931 0.041481 5.061940
-v -0.065204 -0.053551 5.061719
-v -0.065359 -0.148572 5.056064
-v -0.068104 -1.491742 2.553922
-v -0.061093 -1.677462 0.412323
-v -0.058886 -0.824183 -2.687964
-v -0.062302 -0.730150 -4.570870
-v -0.062302 -0.105562 -4.432606
-v -0.062302 0.519026 -4.570870
-v -0.063918 0.186000 5.042500
-v -0.068120 -0.290228 5.032509
-v -0.062302 1.143613 -4.861279
-v -0.062302 -1.354738 -4.861279
-v -0.071154 -1.579084 1.699997
-v -0.063238 1.079870 3.685630
-v -0.067236 -1.198816 3.765310
-v -0.062300 -2.359720 0.609693
-v -0.062300 -2.002168 1.384396
-v -0.060168 -1.865246 -0.039286
-v -0.062907 -1.289451 -3.163004
-v -0.061405 -1.745965 -2.450265
-v -0.066742 -1.900245 -1.052662
-v -0.062300 1.581300 -3.206974
-v -0.062302 2.147848 -0.078111
-v -0.062302 2.012864 1.949657
-v -0.062302 2.219620 1.053955
-v -0.062302 1.570854 -1.965676
-v -0.062302 1.927903 -1.053967
-v -0.062300 0.838848 -3.534988
-v -0.062392 0.560308 -2.718858
-v -0.059615 -0.822842 -2.719636
-v -0.062295 -1.051254 -3.534064
-v 0.372499 -0.019943 4.242424
-v 0.278733 0.281054 4.204535
-v 0.356251 -0.368346 4.244604
-v 0.169007 0.598788 4.199661
-v 0.201556 -0.770173 4.243309
-v -0.474356 -0.021570 4.220061
-v -0.389632 0.277883 4.186603
-v -0.465780 -0.361844 4.224373
-v -0.283923 0.574750 4.186593
-v -0.316211 -0.772189 4.217900
-v -0.068905 -0.823387 4.416515
-v -0.064330 0.727226 4.377632
-v 0.681823 -0.174494 2.015158
-v 0.682229 -0.187965 2.181139
-v 0.643300 -0.652937 2.087316
-v 0.678606 -0.636946 2.151419
-v -0.738473 -0.156972 2.025582
-v -0.747732 -0.214758 2.160238
-v -0.765719 -0.673262 2.000917
-v -0.763302 -0.638924 2.089587
-v 0.678698 -0.160230 1.970646
-v 0.654471 -0.167349 2.215504
-v 0.674112 -0.689511 1.958336
-v 0.665486 -0.647628 2.225219
-v -0.741759 -0.119110 1.922056
-v -0.721854 -0.159501 2.229180
-v -0.745869 -0.738706 1.943823
-v -0.742360 -0.656675 2.167926
-v -0.947884 0.108908 1.069726
-v -0.984275 0.176911 1.043908
-v -0.950049 -0.784809 1.009566
-v -1.004840 -0.874144 0.980422
-v 0.960385 0.039639 1.074327
-v 1.012384 0.067754 1.065536
-v 0.955013 -0.845337 0.965003
-v 1.025137 -0.914933 0.952044
-v 0.670190 -0.401869 2.070609
-v 0.691818 -0.416439 2.209989
-v 0.957699 -0.399897 0.805935
-v 1.018761 -0.420274 0.768748
-v -0.739941 -0.392984 2.037081
-v -0.780649 -0.374199 2.143767
-v -0.961819 -0.389917 0.806692
-v -0.994557 -0.410301 0.739268
-vt 0.350704 0.501343
-vt 0.353560 0.653365
-vt 0.268516 0.627799
-vt 0.263572 0.503316
-vt 0.292616 0.502658
-vt 0.321660 0.502001
-vt 0.111154 0.475219
-vt 0.116420 0.528591
-vt 0.057177 0.495202
-vt 0.054934 0.477481
-vt 0.353867 0.773098
-vt 0.272968 0.741978
-vt 0.118801 0.585354
-vt 0.058363 0.518172
-vt 0.260704 0.358037
-vt 0.290248 0.351961
-vt 0.349337 0.339809
-vt 0.319793 0.345885
-vt 0.271366 0.245019
-vt 0.353917 0.219723
-vt 0.055431 0.457991
-vt 0.108829 0.412939
-vt 0.054684 0.445577
-vt 0.112186 0.381389
-vt 0.634241 0.504854
-vt 0.634460 0.402722
-vt 0.726861 0.421389
-vt 0.725302 0.482726
-vt 0.818396 0.479962
-vt 0.818394 0.399684
-vt 0.895360 0.373880
-vt 0.895360 0.479942
-vt 0.626228 0.257183
-vt 0.727926 0.387558
-vt 0.777187 0.377330
-vt 0.897686 0.290801
-vt 0.722603 0.541046
-vt 0.626235 0.598839
-vt 0.720316 0.594003
-vt 0.622211 0.685494
-vt 0.895360 0.586003
-vt 0.818281 0.560147
-vt 0.895360 0.672146
-vt 0.818255 0.620394
-vt 0.457288 0.344708
-vt 0.451298 0.214685
-vt 0.462446 0.503811
-vt 0.457733 0.645664
-vt 0.452326 0.760712
-vt 0.544122 0.621097
-vt 0.537239 0.723026
-vt 0.551891 0.499938
-vt 0.549542 0.377440
-vt 0.538451 0.238238
-vt 0.162249 0.384740
-vt 0.168493 0.315205
-vt 0.166912 0.476873
-vt 0.177189 0.633572
-vt 0.174107 0.554807
-vt 0.448509 0.218873
-vt 0.346104 0.225515
-vt 0.367039 0.134393
-vt 0.416271 0.058454
-vt 0.572096 0.215980
-vt 0.487847 0.212462
-vt 0.494736 0.181176
-vt 0.615804 0.192333
-vt 0.659834 0.238246
-vt 0.738807 0.231500
-vt 0.725677 0.329224
-vt 0.759120 0.288304
-vt 0.381391 0.802419
-vt 0.479850 0.790034
-vt 0.510177 0.854886
-vt 0.429441 0.908713
-vt 0.649735 0.714815
-vt 0.719541 0.618459
-vt 0.785226 0.743380
-vt 0.684590 0.774104
-vt 0.300492 0.771299
-vt 0.325729 0.857369
-vt 0.564763 0.752347
-vt 0.604094 0.818444
-vt 0.750157 0.538283
-vt 0.748393 0.591111
-vt 0.751609 0.485509
-vt 0.750514 0.388990
-vt 0.752378 0.431222
-vt 0.351345 0.501151
-vt 0.323082 0.501962
-vt 0.294819 0.502773
-vt 0.266556 0.503585
-vt 0.271203 0.625808
-vt 0.353643 0.648852
-vt 0.113167 0.474658
-vt 0.056757 0.476921
-vt 0.058678 0.494192
-vt 0.118023 0.527807
-vt 0.273909 0.741134
-vt 0.353863 0.773037
-vt 0.059089 0.512482
-vt 0.119837 0.580897
-vt 0.292343 0.354923
-vt 0.263578 0.360994
-vt 0.349874 0.342781
-vt 0.352966 0.223407
-vt 0.272186 0.245851
-vt 0.321108 0.348852
-vt 0.110699 0.413857
-vt 0.057266 0.458393
-vt 0.116040 0.371834
-vt 0.056669 0.443481
-vt 0.631742 0.503483
-vt 0.724078 0.482310
-vt 0.725497 0.421871
-vt 0.632445 0.402314
-vt 0.818385 0.479960
-vt 0.895360 0.479942
-vt 0.895360 0.373880
-vt 0.818381 0.399691
-vt 0.725590 0.382028
-vt 0.625418 0.257052
-vt 0.897686 0.290801
-vt 0.777197 0.377307
-vt 0.625423 0.597735
-vt 0.721910 0.540064
-vt 0.622162 0.685400
-vt 0.720770 0.591957
-vt 0.818279 0.560146
-vt 0.895360 0.586003
-vt 0.818255 0.620394
-vt 0.895360 0.672146
-vt 0.456089 0.346884
-vt 0.445634 0.214327
-vt 0.460527 0.502744
-vt 0.455972 0.641719
-vt 0.452312 0.760676
-vt 0.542281 0.619026
-vt 0.537209 0.722972
-vt 0.549323 0.499195
-vt 0.548022 0.376499
-vt 0.538316 0.239866
-vt 0.170637 0.316653
-vt 0.163765 0.385983
-vt 0.168698 0.476427
-vt 0.175479 0.554412
-vt 0.178318 0.631274
-vt 0.443857 0.218578
-vt 0.416271 0.058454
-vt 0.367046 0.134385
-vt 0.345153 0.229198
-vt 0.572020 0.217637
-vt 0.616214 0.195905
-vt 0.496501 0.182053
-vt 0.482139 0.212914
-vt 0.659015 0.238230
-vt 0.739126 0.232648
-vt 0.723125 0.323999
-vt 0.759245 0.289084
-vt 0.381387 0.802359
-vt 0.429441 0.908713
-vt 0.510177 0.854886
-vt 0.479836 0.789997
-vt 0.649686 0.714721
-vt 0.684590 0.774104
-vt 0.785226 0.743380
-vt 0.719995 0.616413
-vt 0.301433 0.770455
-vt 0.325729 0.857369
-vt 0.604094 0.818444
-vt 0.564733 0.752293
-vt 0.748712 0.591001
-vt 0.749932 0.538071
-vt 0.751147 0.485435
-vt 0.751821 0.431492
-vt 0.748310 0.389747
-vt 0.729917 0.604907
-vt 0.743881 0.599243
-vt 0.103808 0.619924
-vt 0.167922 0.670728
-vt 0.037391 0.496098
-vt 0.040033 0.521708
-vt 0.036831 0.479100
-vt 0.036762 0.462038
-vt 0.036722 0.451971
-vt 0.266034 0.234324
-vt 0.156757 0.300909
-vt 0.272430 0.248746
-vt 0.901594 0.278969
-vt 0.776781 0.368297
-vt 0.899269 0.367938
-vt 0.899269 0.479891
-vt 0.899269 0.591843
-vt 0.899269 0.683877
-vt 0.348020 0.220949
-vt 0.266034 0.233841
-vt 0.272430 0.248264
-vt 0.352233 0.234507
-vt 0.266847 0.760867
-vt 0.096481 0.371851
-vt 0.412846 0.056168
-vt 0.365866 0.118040
-vt 0.367046 0.130524
-vt 0.411458 0.071100
-vt 0.451087 0.201788
-vt 0.417412 0.046189
-vt 0.365866 0.121901
-vt 0.340940 0.215640
-vt 0.773990 0.277896
-vt 0.751967 0.215316
-vt 0.623202 0.169160
-vt 0.499211 0.167813
-vt 0.490239 0.191096
-vt 0.737518 0.310799
-vt 0.514277 0.871627
-vt 0.611834 0.833563
-vt 0.322265 0.873114
-vt 0.429318 0.925983
-vt 0.695730 0.786590
-vt 0.800996 0.755943
-vt 0.729142 0.629364
-vt 0.294371 0.790188
-vt 0.817880 0.629250
-vt 0.743176 0.371243
-vt 0.740304 0.371002
-vt 0.784771 0.771217
-vt 0.800540 0.783780
-vt 0.768602 0.229303
-vt 0.783688 0.221106
-vt 0.584649 0.180086
-vt 0.499110 0.166341
-vt 0.504020 0.153755
-vt 0.592804 0.158207
-vt 0.696159 0.219318
-vt 0.709761 0.205166
-vt 0.353184 0.230824
-vt 0.271610 0.247432
-vt 0.271610 0.247914
-vt 0.042671 0.878481
-vt 0.031238 0.920399
-vt 0.115499 0.965937
-vt 0.149369 0.885954
-vt 0.030324 0.876673
-vt 0.021073 0.915797
-vt 0.109429 0.973711
-vt 0.148346 0.886472
-vt 0.298387 0.471601
-vt 0.307158 0.474224
-vt 0.322295 0.511669
-vt 0.290740 0.512384
-vt 0.313439 0.385668
-vt 0.306382 0.384168
-vt 0.288168 0.348660
-vt 0.320266 0.342059
-vt 0.309206 0.429946
-vt 0.299818 0.427109
-vt 0.306983 0.477636
-vt 0.300377 0.475414
-vt 0.293134 0.512508
-vt 0.323840 0.511627
-vt 0.307337 0.383783
-vt 0.314417 0.384847
-vt 0.321696 0.345282
-vt 0.290444 0.351878
-vt 0.312509 0.431676
-vt 0.302048 0.435334
-vt 0.161052 0.889523
-vt 0.142339 0.783019
-vt 0.129193 0.781042
-vt 0.042953 0.819140
-vt 0.027886 0.816874
-vt 0.044887 0.880170
-vt 0.035132 0.920526
-vt 0.119664 0.977154
-vt 0.135888 0.881880
-vt 0.121873 0.780490
-vt 0.135818 0.783454
-vt 0.016320 0.914457
-vt 0.104638 0.961612
-vt 0.027220 0.873812
-vt 0.026432 0.812492
-vt 0.099111 0.816974
-vt 0.125430 0.884312
-vt 0.042415 0.815890
-vt 0.124939 0.944586
-vn 0.9867 0.1613 0.0187
-vn 0.8936 0.4480 0.0253
-vn 0.8938 0.4293 0.1294
-vn 0.9759 0.1828 0.1189
-vn 0.9644 0.1995 0.1733
-vn 0.9873 0.1359 0.0815
-vn 0.9665 0.1253 0.2240
-vn 0.9345 0.3082 0.1781
-vn 0.8462 0.1701 0.5049
-vn 0.8188 0.0351 0.5730
-vn 0.9569 0.2900 0.0151
-vn 0.9137 0.3928 0.1036
-vn 0.8180 0.5105 0.2650
-vn 0.7834 0.3858 0.4872
-vn 0.9258 -0.3595 0.1165
-vn 0.9475 -0.2674 0.1755
-vn 0.9034 -0.4285 0.0174
-vn 0.9537 -0.2965 0.0498
-vn 0.6558 -0.7475 0.1053
-vn 0.8524 -0.5199 0.0552
-vn 0.8293 -0.1242 0.5448
-vn 0.9462 -0.1941 0.2590
-vn 0.7180 -0.3938 0.5739
-vn 0.7163 -0.5731 0.3980
-vn 0.9643 0.2170 -0.1518
-vn 0.9430 -0.2813 -0.1776
-vn 0.8723 -0.2288 -0.4321
-vn 0.8776 0.1512 -0.4549
-vn 1.0000 0.0015 -0.0051
-vn 1.0000 -0.0017 -0.0047
-vn 0.9961 0.0245 -0.0842
-vn 0.9977 0.0001 -0.0674
-vn 0.9567 -0.2785 -0.0844
-vn 0.9247 -0.2993 -0.2353
-vn 0.9985 -0.0535 0.0114
-vn 0.9901 0.0257 -0.1375
-vn 0.8681 0.3531 -0.3488
-vn 0.8756 0.4529 -0.1676
-vn 0.9495 0.2708 -0.1583
-vn 0.9646 0.2499 -0.0839
-vn 0.9962 -0.0245 -0.0838
-vn 1.0000 0.0031 -0.0027
-vn 0.9905 -0.0244 -0.1353
-vn 0.9978 0.0631 0.0176
-vn 0.9225 -0.3785 -0.0754
-vn 0.9142 -0.4031 -0.0426
-vn 0.9819 0.1721 -0.0791
-vn 0.8897 0.4473 -0.0907
-vn 0.9557 0.2909 -0.0448
-vn 0.8883 0.4396 -0.1329
-vn 0.9559 0.2840 -0.0742
-vn 0.9742 0.1924 -0.1176
-vn 0.9423 -0.3082 -0.1306
-vn 0.9333 -0.3519 -0.0717
-vn 0.9512 -0.2420 0.1912
-vn 0.6577 -0.7040 0.2680
-vn 0.9719 0.1686 0.1640
-vn 0.8427 0.5116 0.1677
-vn 0.9110 0.3882 0.1394
-vn 0.9952 -0.0659 0.0716
-vn 0.9961 -0.0559 -0.0682
-vn 0.9938 -0.1064 0.0316
-vn 0.9966 -0.0826 -0.0037
-vn 0.9978 -0.0639 -0.0144
-vn 0.9984 0.0141 -0.0549
-vn 0.9994 0.0333 -0.0042
-vn 0.9993 0.0357 0.0030
-vn 0.9993 0.0215 -0.0307
-vn 0.9996 0.0275 -0.0073
-vn 0.9991 0.0370 0.0198
-vn 0.9995 0.0306 -0.0085
-vn 0.9545 0.1327 -0.2671
-vn 0.9838 0.1593 -0.0814
-vn 0.9265 0.0528 -0.3725
-vn 0.9801 -0.1452 -0.1352
-vn 0.9380 -0.0779 -0.3378
-vn -0.9842 0.1761 0.0173
-vn -0.9882 0.1476 0.0419
-vn -0.9805 0.1621 0.1109
-vn -0.9749 0.2013 0.0947
-vn -0.9072 0.4083 0.1012
-vn -0.9075 0.4193 0.0248
-vn -0.9682 0.1226 0.2180
-vn -0.8501 0.0313 0.5257
-vn -0.8684 0.1526 0.4717
-vn -0.9380 0.2968 0.1791
-vn -0.9385 0.3369 0.0754
-vn -0.9681 0.2501 0.0139
-vn -0.8172 0.3467 0.4603
-vn -0.8418 0.4781 0.2506
-vn -0.9388 -0.3111 0.1473
-vn -0.9386 -0.3316 0.0947
-vn -0.9170 -0.3986 0.0130
-vn -0.8727 -0.4883 0.0031
-vn -0.7128 -0.6962 0.0841
-vn -0.9315 -0.3628 0.0266
-vn -0.9510 -0.1759 0.2542
-vn -0.8562 -0.1078 0.5052
-vn -0.7463 -0.5478 0.3780
-vn -0.7598 -0.3746 0.5313
-vn -0.9702 0.2026 -0.1327
-vn -0.8925 0.1396 -0.4289
-vn -0.8859 -0.2134 -0.4118
-vn -0.9515 -0.2609 -0.1627
-vn -1.0000 0.0014 -0.0047
-vn -1.0000 0.0001 0.0010
-vn -1.0000 -0.0008 0.0016
-vn -1.0000 -0.0015 -0.0044
-vn -0.9362 -0.2784 -0.2141
-vn -0.9610 -0.2649 -0.0791
-vn -1.0000 -0.0011 0.0031
-vn -0.9999 -0.0140 0.0023
-vn -0.8932 0.4232 -0.1518
-vn -0.8843 0.3289 -0.3313
-vn -0.9691 0.2343 -0.0761
-vn -0.9552 0.2560 -0.1483
-vn -1.0000 0.0029 -0.0025
-vn -1.0000 0.0007 0.0017
-vn -1.0000 0.0028 -0.0001
-vn -1.0000 0.0006 0.0031
-vn -0.9302 -0.3609 -0.0669
-vn -0.9276 -0.3721 -0.0323
-vn -0.9811 0.1801 -0.0702
-vn -0.9050 0.4186 -0.0760
-vn -0.9664 0.2542 -0.0369
-vn -0.9044 0.4106 -0.1158
-vn -0.9647 0.2555 -0.0629
-vn -0.9757 0.1908 -0.1079
-vn -0.9482 -0.2927 -0.1230
-vn -0.9387 -0.3380 -0.0668
-vn -0.6936 -0.6751 0.2515
-vn -0.9589 -0.2211 0.1777
-vn -0.9745 0.1718 0.1440
-vn -0.9188 0.3758 0.1203
-vn -0.8701 0.4713 0.1441
-vn -0.9996 -0.0119 -0.0260
-vn -0.9999 -0.0056 -0.0089
-vn -0.9995 -0.0297 -0.0009
-vn -0.9976 -0.0671 0.0185
-vn -0.9998 -0.0160 -0.0071
-vn -0.9995 0.0145 -0.0255
-vn -1.0000 -0.0003 -0.0002
-vn -1.0000 -0.0004 0.0000
-vn -1.0000 0.0006 0.0002
-vn -1.0000 -0.0017 -0.0032
-vn -1.0000 0.0039 0.0018
-vn -1.0000 -0.0002 0.0001
-vn -0.9912 0.0999 -0.0861
-vn -0.9587 0.1253 -0.2553
-vn -0.9326 0.0482 -0.3577
-vn -0.9430 -0.0735 -0.3245
-vn -0.9777 -0.1413 -0.1555
-vn -0.1186 0.6918 -0.7122
-vn -0.0751 0.9968 -0.0252
-vn -0.0475 0.8512 0.5226
-vn -0.0349 0.9398 0.3398
-vn -0.0406 0.0746 0.9964
-vn -0.0355 0.4422 0.8962
-vn -0.0454 -0.0228 0.9987
-vn -0.0447 -0.1165 0.9922
-vn -0.0494 -0.4386 0.8973
-vn -0.0489 -0.9870 0.1532
-vn -0.0338 -0.9189 0.3931
-vn 0.1186 -0.9094 -0.3985
-vn -0.0414 -0.9693 0.2421
-vn -0.1683 0.4024 -0.8998
-vn -0.0923 0.0012 -0.9957
-vn -0.1661 -0.4021 -0.9004
-vn 0.1165 0.9053 -0.4085
-vn -0.1227 -0.9885 0.0882
-vn -0.0306 0.9626 0.2692
-vn -0.0389 -0.8014 0.5969
-vn -0.0419 -0.7881 -0.6141
-vn -0.0643 -0.7917 0.6074
-vn -0.0781 -0.9258 -0.3697
-vn -0.0225 0.1529 -0.9880
-vn -0.0274 -0.9158 -0.4006
-vn -0.0503 -0.9965 -0.0661
-vn -0.0335 -0.9901 0.1366
-vn -0.0235 -0.2826 -0.9589
-vn -0.0305 0.9872 -0.1562
-vn -0.0284 0.9489 -0.3142
-vn -0.0283 0.8762 0.4810
-vn -0.0303 0.9966 0.0762
-vn -0.0470 0.9972 -0.0574
-vn -0.0355 0.0413 -0.9985
-vn -0.0585 0.9617 0.2678
-vn -0.0126 -0.9972 -0.0738
-vn 0.1226 -0.0790 -0.9893
-vn 0.2116 0.9767 0.0367
-vn -0.7042 0.6033 -0.3743
-vn -0.7302 0.1274 -0.6712
-vn -0.9475 -0.0146 0.3193
-vn -0.9552 0.1086 0.2752
-vn -0.4339 0.8999 -0.0422
-vn -0.3117 -0.1502 -0.9382
-vn 0.8898 0.3072 0.3373
-vn 0.7048 -0.6410 -0.3038
-vn 0.9692 0.1172 0.2165
-vn 0.8977 -0.0129 0.4404
-vn -0.4157 0.9072 0.0643
-vn -0.9439 -0.2023 0.2610
-vn -0.3115 -0.9500 -0.0211
-vn -0.0655 0.0873 -0.9940
-vn 0.9188 0.0155 -0.3944
-vn 0.8971 -0.3628 -0.2520
-vn -0.3376 -0.9285 -0.1544
-vn 0.8716 0.4478 -0.1993
-vn 0.5122 -0.0088 -0.8588
-vn 0.4840 -0.8529 -0.1958
-vn -0.7570 -0.4946 -0.4270
-vn 0.5092 0.8177 -0.2684
-usemtl FISH
-s 1
-f 26/1/1 27/2/2 6/3/3 2/4/4 190/5/5 189/6/6
-f 169/7/7 170/8/8 13/9/9 14/10/10
-f 27/2/2 29/11/11 12/12/12 6/3/3
-f 170/8/8 172/13/13 22/14/14 13/9/9
-f 190/5/5 2/4/4 7/15/15 192/16/16
-f 28/17/17 191/18/18 192/16/16 7/15/15 16/19/19 30/20/20
-f 169/7/7 14/10/10 15/21/21 171/22/22
-f 171/22/22 15/21/21 23/23/23 173/24/24
-f 31/25/25 32/26/26 8/27/27 4/28/28
-f 59/29/29 61/30/30 19/31/31 20/32/32
-f 32/26/26 35/33/33 18/34/34 8/27/27
-f 61/30/30 67/35/35 25/36/36 19/31/31
-f 31/25/25 4/28/28 9/37/37 33/38/38
-f 33/38/38 9/37/37 10/39/39 34/40/40
-f 59/29/29 20/32/32 21/41/41 63/42/42
-f 63/42/42 21/41/41 24/43/43 64/44/44
-f 3/45/45 28/17/17 30/20/20 17/46/46
-f 1/47/47 26/1/1 28/17/17 3/45/45
-f 5/48/48 11/49/49 29/11/11 27/2/2
-f 1/47/47 5/48/48 27/2/2 26/1/1
-f 44/50/50 33/38/38 34/40/40 41/51/51
-f 42/52/52 31/25/25 33/38/38 44/50/50
-f 43/53/53 45/54/54 35/33/33 32/26/26
-f 42/52/52 43/53/53 32/26/26 31/25/25
-f 7/15/15 38/55/55 40/56/56 16/19/19
-f 2/4/4 36/57/57 38/55/55 7/15/15
-f 6/3/3 12/12/12 39/58/58 37/59/59
-f 2/4/4 6/3/3 37/59/59 36/57/57
-f 1/47/47 3/45/45 43/53/53 42/52/52
-f 3/45/45 17/46/46 45/54/54 43/53/53
-f 1/47/47 42/52/52 44/50/50 5/48/48
-f 5/48/48 44/50/50 41/51/51 11/49/49
-f 17/60/46 30/61/20 47/62/60 46/63/61
-f 45/64/54 17/65/46 48/66/62 51/67/63
-f 35/68/33 45/64/54 51/67/63 50/69/64
-f 18/70/34 35/68/33 50/69/64 49/71/65
-f 29/72/11 11/73/49 53/74/66 55/75/67
-f 34/76/40 10/77/39 52/78/68 56/79/69
-f 12/80/12 29/72/11 55/75/67 54/81/70
-f 11/73/49 41/82/51 57/83/71 53/74/66
-f 41/82/51 34/76/40 56/79/69 57/83/71
-f 9/37/37 62/84/72 65/85/73 10/39/39
-f 62/84/72 63/42/42 64/44/44 65/85/73
-f 4/28/28 58/86/74 62/84/72 9/37/37
-f 58/86/74 59/29/29 63/42/42 62/84/72
-f 8/27/27 18/34/34 66/87/75 60/88/76
-f 60/88/76 66/87/75 67/35/35 61/30/30
-f 4/28/28 8/27/27 60/88/76 58/86/74
-f 58/86/74 60/88/76 61/30/30 59/29/29
-f 93/89/77 193/90/78 194/91/79 69/92/80 73/93/81 94/94/82
-f 174/95/83 81/96/84 80/97/85 175/98/86
-f 94/94/82 73/93/81 79/99/87 96/100/88
-f 175/98/86 80/97/85 89/101/89 177/102/90
-f 194/91/79 196/103/91 74/104/92 69/92/80
-f 95/105/93 97/106/94 83/107/95 74/104/92 196/103/91 195/108/96
-f 174/95/83 176/109/97 82/110/98 81/96/84
-f 176/109/97 178/111/99 90/112/100 82/110/98
-f 98/113/101 71/114/102 75/115/103 99/116/104
-f 126/117/105 87/118/106 86/119/107 128/120/108
-f 99/116/104 75/115/103 85/121/109 102/122/110
-f 128/120/108 86/119/107 92/123/111 134/124/112
-f 98/113/101 100/125/113 76/126/114 71/114/102
-f 100/125/113 101/127/115 77/128/116 76/126/114
-f 126/117/105 130/129/117 88/130/118 87/118/106
-f 130/129/117 131/131/119 91/132/120 88/130/118
-f 70/133/121 84/134/122 97/106/94 95/105/93
-f 68/135/123 70/133/121 95/105/93 93/89/77
-f 72/136/124 94/94/82 96/100/88 78/137/125
-f 68/135/123 93/89/77 94/94/82 72/136/124
-f 111/138/126 108/139/127 101/127/115 100/125/113
-f 109/140/128 111/138/126 100/125/113 98/113/101
-f 110/141/129 99/116/104 102/122/110 112/142/130
-f 109/140/128 98/113/101 99/116/104 110/141/129
-f 74/104/92 83/107/95 107/143/131 105/144/132
-f 69/92/80 74/104/92 105/144/132 103/145/133
-f 73/93/81 104/146/134 106/147/135 79/99/87
-f 69/92/80 103/145/133 104/146/134 73/93/81
-f 68/135/123 109/140/128 110/141/129 70/133/121
-f 70/133/121 110/141/129 112/142/130 84/134/122
-f 68/135/123 72/136/124 111/138/126 109/140/128
-f 72/136/124 78/137/125 108/139/127 111/138/126
-f 84/148/122 113/149/136 114/150/137 97/151/94
-f 112/152/130 118/153/138 115/154/139 84/155/122
-f 102/156/110 117/157/140 118/153/138 112/152/130
-f 85/158/109 116/159/141 117/157/140 102/156/110
-f 96/160/88 122/161/142 120/162/143 78/163/125
-f 101/164/115 123/165/144 119/166/145 77/167/116
-f 79/168/87 121/169/146 122/161/142 96/160/88
-f 78/163/125 120/162/143 124/170/147 108/171/127
-f 108/171/127 124/170/147 123/165/144 101/164/115
-f 76/126/114 77/128/116 132/172/148 129/173/149
-f 129/173/149 132/172/148 131/131/119 130/129/117
-f 71/114/102 76/126/114 129/173/149 125/174/150
-f 125/174/150 129/173/149 130/129/117 126/117/105
-f 75/115/103 127/175/151 133/176/152 85/121/109
-f 127/175/151 128/120/108 134/124/112 133/176/152
-f 71/114/102 125/174/150 127/175/151 75/115/103
-f 125/174/150 126/117/105 128/120/108 127/175/151
-f 135/177/153 166/178/154 132/172/148 77/128/116
-f 180/179/155 151/180/156 106/147/135 177/102/90
-f 137/181/157 146/182/158 89/101/89 80/97/85
-f 138/183/159 137/181/157 80/97/85 81/96/84
-f 139/184/160 138/183/159 81/96/84 82/110/98
-f 147/185/161 139/184/160 82/110/98 90/112/100
-f 140/186/162 152/187/163 107/143/131 83/188/95
-f 149/189/164 168/190/165 134/124/112 92/123/111
-f 143/191/166 149/189/164 92/123/111 86/119/107
-f 144/192/167 143/191/166 86/119/107 87/118/106
-f 145/193/168 144/192/167 87/118/106 88/130/118
-f 148/194/169 145/193/168 88/130/118 91/132/120
-f 150/195/170 140/196/162 83/197/95 97/198/94
-f 151/180/156 136/199/171 79/99/87 106/147/135
-f 179/200/172 147/185/161 90/112/100 178/111/99
-f 153/201/173 154/202/174 114/203/137 113/204/136
-f 141/205/175 153/206/173 113/149/136 84/148/122
-f 154/207/174 150/208/170 97/151/94 114/150/137
-f 156/209/176 157/210/177 117/157/140 116/159/141
-f 157/210/177 158/211/178 118/153/138 117/157/140
-f 158/211/178 155/212/179 115/154/139 118/153/138
-f 155/212/179 141/213/175 84/155/122 115/154/139
-f 142/214/180 156/209/176 116/159/141 85/158/109
-f 160/215/181 164/216/182 124/170/147 120/162/143
-f 161/217/183 162/218/184 122/161/142 121/169/146
-f 162/218/184 160/215/181 120/162/143 122/161/142
-f 163/219/185 159/220/186 119/166/145 123/165/144
-f 164/216/182 163/219/185 123/165/144 124/170/147
-f 159/220/186 135/221/153 77/167/116 119/166/145
-f 136/222/171 161/217/183 121/169/146 79/168/87
-f 165/223/187 148/194/169 91/132/120 131/131/119
-f 166/178/154 165/223/187 131/131/119 132/172/148
-f 167/224/188 142/225/180 85/121/109 133/176/152
-f 168/190/165 167/224/188 133/176/152 134/124/112
-f 67/35/35 66/87/75 167/224/188 168/190/165
-f 66/87/75 18/34/34 142/225/180 167/224/188
-f 65/85/73 64/44/44 165/223/187 166/178/154
-f 64/44/44 24/43/43 148/194/169 165/223/187
-f 12/80/12 54/81/70 161/217/183 136/222/171
-f 52/226/68 10/39/39 135/177/153 159/227/186
-f 57/83/71 56/79/69 163/219/185 164/216/182
-f 56/79/69 52/78/68 159/220/186 163/219/185
-f 55/75/67 53/74/66 160/215/181 162/218/184
-f 54/81/70 55/75/67 162/218/184 161/217/183
-f 53/74/66 57/83/71 164/216/182 160/215/181
-f 18/34/34 49/228/65 156/229/176 142/225/180
-f 48/66/62 17/65/46 141/213/175 155/212/179
-f 51/230/63 48/231/62 155/232/179 158/233/178
-f 50/234/64 51/230/63 158/233/178 157/235/177
-f 49/228/65 50/234/64 157/235/177 156/229/176
-f 47/62/60 30/61/20 150/208/170 154/207/174
-f 17/60/46 46/63/61 153/206/173 141/205/175
-f 46/63/61 47/62/60 154/207/174 153/206/173
-f 173/24/24 23/23/23 147/185/161 179/200/172
-f 39/58/58 12/12/12 136/199/171 151/180/156
-f 30/236/20 16/237/19 140/196/162 150/195/170
-f 24/43/43 21/41/41 145/193/168 148/194/169
-f 21/41/41 20/32/32 144/192/167 145/193/168
-f 20/32/32 19/31/31 143/191/166 144/192/167
-f 19/31/31 25/36/36 149/189/164 143/191/166
-f 25/36/36 67/35/35 168/190/165 149/189/164
-f 16/238/19 40/56/56 152/187/163 140/186/162
-f 23/23/23 15/21/21 139/184/160 147/185/161
-f 15/21/21 14/10/10 138/183/159 139/184/160
-f 14/10/10 13/9/9 137/181/157 138/183/159
-f 13/9/9 22/14/14 146/182/158 137/181/157
-f 172/13/13 39/58/58 151/180/156 180/179/155
-f 10/39/39 65/85/73 166/178/154 135/177/153
-f 22/14/14 172/13/13 180/179/155 146/182/158
-f 40/56/56 173/24/24 179/200/172 152/187/163
-f 152/187/163 179/200/172 178/111/99 107/143/131
-f 146/182/158 180/179/155 177/102/90 89/101/89
-f 105/144/132 107/143/131 178/111/99 176/109/97
-f 103/145/133 105/144/132 176/109/97 174/95/83
-f 104/146/134 175/98/86 177/102/90 106/147/135
-f 103/145/133 174/95/83 175/98/86 104/146/134
-f 38/55/55 171/22/22 173/24/24 40/56/56
-f 36/57/57 169/7/7 171/22/22 38/55/55
-f 37/59/59 39/58/58 172/13/13 170/8/8
-f 36/57/57 37/59/59 170/8/8 169/7/7
-f 26/1/1 189/6/6 191/18/18 28/17/17
-f 205/239/189 181/240/190 201/241/191 207/242/192
-f 93/89/77 95/105/93 195/108/96 193/90/78
-f 210/243/193 186/244/194 198/245/195 212/246/196
-f 182/247/197 181/248/190 189/249/6 190/250/5
-f 183/251/198 184/252/199 192/253/16 191/254/18
-f 181/248/190 205/255/189 183/251/198 191/254/18 189/249/6
-f 184/252/199 206/256/200 182/247/197 190/250/5 192/253/16
-f 185/257/201 186/258/194 194/259/79 193/260/78
-f 188/261/202 187/262/203 195/263/96 196/264/91
-f 187/262/203 209/265/204 185/257/201 193/260/78 195/263/96
-f 186/258/194 210/266/193 188/261/202 196/264/91 194/259/79
-f 211/267/205 199/268/206 200/269/207 212/246/196
-f 187/270/203 188/271/202 200/269/207 199/268/206
-f 209/272/204 187/270/203 199/268/206 211/267/205
-f 186/244/194 185/273/201 197/274/208 198/245/195
-f 207/242/192 208/275/209 204/276/210 203/277/211
-f 181/240/190 182/278/197 202/279/212 201/241/191
-f 206/280/200 184/281/199 204/282/210 208/283/209
-f 184/281/199 183/284/198 203/277/211 204/276/210
-f 182/278/197 206/280/200 208/283/209 202/285/212
-f 201/241/191 202/279/212 208/275/209 207/242/192
-f 183/284/198 205/239/189 207/242/192 203/277/211
-f 185/273/201 209/272/204 211/267/205 197/274/208
-f 197/274/208 211/267/205 212/246/196 198/245/195
-f 188/271/202 210/243/193 212/246/196 200/269/207
-"#
-}
-
-
-pub fn blue_obj() -> &'static str {
-r#"
-# Blender v2.83.0 OBJ File: 'Bluegill.blend'
-# www.blender.org
-mtllib Bluegill.mtl
-o Bluegill_Bluegill_mesh
-v -58.242001 -4.243034 5.942232
-v -46.533012 -3.920391 14.368069
-v -24.143677 -2.911460 22.537676
-v -6.542586 -2.615699 23.255527
-v 4.762808 -2.397104 19.767395
-v 38.755970 -1.029685 4.771888
-v -58.242001 -5.246799 3.215628
-v -46.533012 -5.532106 10.475485
-v -24.143675 -4.847929 17.336481
-v -6.542586 -4.737486 17.943829
-v 4.762808 -4.333573 15.158276
-v 38.755970 -1.444643 3.015031
-v -58.242001 -5.917493 -0.865020
-v -46.533012 -6.609021 4.649823
-v -24.143675 -6.141836 9.552346
-v -6.542586 -6.155218 9.994316
-v 4.762809 -5.627480 8.260235
-v 38.755970 -1.721909 0.385711
-v -58.242001 -6.153009 -5.678473
-v -46.533012 -6.987182 -2.222013
-v -24.143675 -6.596195 0.370334
-v -6.542585 -6.653060 0.617224
-v 4.762809 -6.081840 0.123446
-v 38.755970 -1.819272 -2.715786
-v -68.005722 -4.000534 -9.857992
-v -58.242001 -5.917493 -10.491927
-v -46.533012 -6.609021 -9.093847
-v -24.143675 -6.141836 -8.811680
-v -6.542585 -6.155217 -8.759867
-v 4.762809 -5.627480 -8.013343
-v 38.755970 -1.721909 -5.817282
-v -65.282310 -5.004297 -9.725983
-v -58.242001 -5.246799 -14.572574
-v -46.533012 -5.532105 -14.919508
-v -24.143675 -4.847928 -16.595814
-v -6.542584 -4.737485 -16.709383
-v 4.762810 -4.333572 -14.911380
-v 38.755970 -1.444643 -8.446603
-v -59.722347 -5.674992 -9.887447
-v -58.242001 -4.243034 -17.299175
-v -46.533012 -3.920389 -18.812090
-v -24.143675 -2.911458 -21.797007
-v -6.542584 -2.615697 -22.021069
-v 4.762810 -2.397102 -19.520500
-v 38.755970 -1.029685 -10.203461
-v -60.901756 -5.535084 -9.357635
-v -64.162720 -5.299569 -8.798972
-v -68.774406 -3.371207 -8.008900
-v -66.927223 -4.628874 -8.325359
-v 25.531326 -1.343305 9.117340
-v 25.531326 -3.052594 6.645022
-v 25.531326 -4.194704 2.944935
-v 25.531326 -4.595759 -1.419614
-v 25.531326 -4.194703 -5.784163
-v 25.531326 -3.052592 -9.484250
-v 25.531326 -1.343303 -11.956568
-v 67.988441 -0.083601 6.361792
-v 67.988441 -0.192144 0.623712
-v 63.873608 -0.230259 -6.144817
-v 66.102478 -0.192144 -12.913343
-v 66.102478 -0.083601 -18.651424
-v 45.806278 -0.789872 -10.851531
-v 45.806278 -0.928037 -7.796405
-v 45.806278 -0.976553 -4.192639
-v 45.806278 -0.928037 -0.588873
-v 45.806274 -0.583094 -12.892900
-v 45.806278 -0.789872 2.466251
-v 45.806274 -0.583095 4.507622
-v 15.575695 -2.263115 15.588421
-v 15.575695 -4.085995 11.778804
-v 15.575696 -5.304003 6.077297
-v 15.575696 -5.731710 -0.648084
-v 15.575696 -5.304001 -7.373465
-v 15.575696 -4.085992 -13.074967
-v 15.575697 -2.263113 -16.884590
-v -18.914406 -0.199232 28.136028
-v 9.992080 -0.199232 28.441412
-v -1.313315 -0.199232 30.264282
-v 32.818016 -0.199232 13.757433
-v 26.755531 -0.199232 24.015427
-v 13.392893 -0.220560 -25.074257
-v 28.358742 -0.220560 -14.083221
-v 21.477669 -0.685409 -20.486738
-v 24.608683 -0.220560 -29.431984
-v 34.073792 -0.220560 -19.869711
-v 34.550846 -0.220560 -30.916704
-v -16.193819 -2.030792 -28.377840
-v -0.988917 -2.030792 -31.611622
-v -16.206102 -1.755515 -28.380573
-v -0.951630 -1.755515 -31.620031
-v -18.263067 -9.000339 -7.365996
-v -16.894537 -7.514262 -16.200851
-v -18.154961 -9.415442 -7.349250
-v -16.786430 -7.929363 -16.184107
-v -7.821937 -11.918623 -3.581934
-v -7.334356 -10.432545 -15.553536
-v -7.712463 -12.306234 -3.567401
-v -7.224881 -10.820155 -15.539005
-v 0.873527 -13.479437 -4.174082
-v -4.164573 -11.201415 -14.906908
-v 0.990270 -13.735089 -4.212448
-v -4.075404 -11.534444 -14.948441
-v -67.339378 -3.238348 -9.916985
-v -64.833672 -4.242112 -9.718560
-v -59.675564 -4.912805 -9.855558
-v -59.731579 -4.454574 -9.615208
-v -61.541279 -4.617993 -9.455956
-v -64.306564 -4.384153 -9.021996
-v -68.216446 -2.712091 -8.400958
-v -66.650581 -3.714888 -8.651530
-v -59.427166 -3.750152 -9.559778
-v -61.786236 -3.987980 -8.257978
-v -63.974606 -3.754137 -8.313269
-v -65.829262 -3.084872 -8.357790
-v -46.729893 0.005247 16.490984
-v -24.143677 0.000000 24.364094
-v 4.762808 0.000000 21.385904
-v -58.242001 4.277765 5.942232
-v -46.533012 3.930885 14.368069
-v -24.143677 2.911460 22.537676
-v -6.542586 2.615699 23.255527
-v 4.762808 2.397104 19.767395
-v 38.755970 1.029685 4.771888
-v -58.242001 5.281530 3.215628
-v -46.533012 5.542600 10.475485
-v -24.143675 4.847929 17.336481
-v -6.542586 4.737486 17.943829
-v 4.762808 4.333573 15.158276
-v 38.755970 1.444643 3.015031
-v -58.242001 5.952224 -0.865020
-v -46.533012 6.619514 4.649823
-v -24.143675 6.141836 9.552346
-v -6.542586 6.155218 9.994316
-v 4.762809 5.627480 8.260235
-v 38.755970 1.721909 0.385711
-v -68.962036 -0.358056 -9.904351
-v -58.242001 6.187740 -5.678473
-v -46.533012 6.997675 -2.222013
-v -24.143675 6.596195 0.370334
-v -6.542585 6.653060 0.617224
-v 4.762809 6.081840 0.123446
-v 38.755970 1.819272 -2.715786
-v -68.005722 3.284423 -9.857992
-v -58.242001 5.952224 -10.491927
-v -46.533012 6.619514 -9.093847
-v -24.143675 6.141836 -8.811680
-v -6.542585 6.155217 -8.759867
-v 4.762809 5.627480 -8.013343
-v 38.755970 1.721909 -5.817282
-v -65.282310 4.288187 -9.725983
-v -58.242001 5.281530 -14.572574
-v -46.533012 5.542598 -14.919508
-v -24.143675 4.847928 -16.595814
-v -6.542584 4.737485 -16.709383
-v 4.762810 4.333572 -14.911380
-v 38.755970 1.444643 -8.446603
-v -59.722347 4.958882 -9.887447
-v -58.242001 4.277764 -17.299175
-v -46.533012 3.930883 -18.812090
-v -24.143675 2.911458 -21.797007
-v -6.542584 2.615697 -22.021069
-v 4.762810 2.397102 -19.520500
-v 38.755970 1.029685 -10.203461
-v -69.900322 0.017365 -7.113093
-v -58.242001 0.017365 -18.256630
-v -46.533012 0.005247 -20.178982
-v -24.143675 0.000000 -23.709167
-v -6.542584 0.000000 -23.972034
-v 4.762810 0.000000 -21.139006
-v 38.755970 0.000000 -10.820385
-v -60.901756 5.569814 -9.357635
-v -64.162720 5.334300 -8.798972
-v -68.774406 3.405938 -8.008900
-v -58.643791 0.017365 7.627040
-v -6.542586 0.000000 25.120739
-v 38.755970 0.000000 5.388812
-v -66.927223 4.663605 -8.325359
-v 25.531326 1.343305 9.117340
-v 25.531326 3.052594 6.645022
-v 25.531326 4.194704 2.944935
-v 25.531326 4.595759 -1.419614
-v 25.531326 4.194703 -5.784163
-v 25.531326 3.052592 -9.484250
-v 25.531326 1.343303 -11.956568
-v 25.531326 0.000000 -12.824726
-v 25.531326 0.000000 9.985501
-v 66.959732 0.000000 9.252872
-v 67.988441 0.083601 6.361792
-v 67.988441 0.192144 0.623712
-v 63.873608 0.230259 -6.144817
-v 66.102478 0.192144 -12.913343
-v 66.102478 0.083601 -18.651424
-v 63.873608 0.000000 -20.170898
-v 61.301838 0.000000 -20.745705
-v 45.806278 0.789872 -10.851531
-v 45.806278 0.928037 -7.796405
-v 45.806278 0.976553 -4.192639
-v 45.806278 0.928037 -0.588873
-v 45.806274 0.583094 -12.892900
-v 45.806278 0.000000 5.224454
-v 45.806278 0.789872 2.466251
-v 45.806274 0.583095 4.507622
-v 45.806278 0.000000 -13.609732
-v 68.552002 0.000000 8.082334
-v 68.383492 0.000000 0.757398
-v 64.184975 0.000000 -6.148803
-v 66.459175 0.000000 -13.055001
-v 66.459175 0.000000 -18.909796
-v 64.184975 0.000000 -20.460175
-v 15.575695 2.263115 15.588421
-v 15.575695 4.085995 11.778804
-v 15.575696 5.304003 6.077297
-v 15.575696 5.731710 -0.648084
-v 15.575696 5.304001 -7.373465
-v 15.575696 4.085992 -13.074967
-v 15.575697 2.263113 -16.884590
-v 15.575697 0.000000 -18.222347
-v 15.575695 0.000000 16.926184
-v -18.914406 0.199232 28.136028
-v 9.992080 0.199232 28.441412
-v -1.313315 0.199232 30.264282
-v 32.818016 0.199232 13.757433
-v 26.755531 0.199232 24.015427
-v 13.392893 0.220560 -25.074257
-v 28.358742 0.220560 -14.083221
-v 21.477669 0.685409 -20.486738
-v 24.608683 0.220560 -29.431984
-v 34.073792 0.220560 -19.869711
-v 34.550846 0.220560 -30.916704
-v -16.193819 2.030792 -28.377840
-v -0.988917 2.030792 -31.611622
-v -16.206102 1.755515 -28.380573
-v -0.951630 1.755515 -31.620031
-v -18.263067 9.000339 -7.365996
-v -16.894537 7.514262 -16.200851
-v -18.154961 9.415442 -7.349250
-v -16.786430 7.929363 -16.184107
-v -7.821937 11.918623 -3.581934
-v -7.334356 10.432545 -15.553536
-v -7.712463 12.306234 -3.567401
-v -7.224881 10.820155 -15.539005
-v 0.873527 13.479437 -4.174082
-v -4.164573 11.201415 -14.906908
-v 0.990270 13.735089 -4.212448
-v -4.075404 11.534444 -14.948441
-v -19.020382 0.000000 28.651112
-v -1.220534 0.000000 30.874363
-v 10.206341 0.000000 28.925694
-v 27.152817 0.000000 24.424145
-v 34.437908 0.000000 13.710882
-v 28.494879 0.000000 -13.862982
-v 34.438484 0.000000 -19.880882
-v 34.934620 0.000000 -31.369667
-v 24.594847 0.000000 -29.825569
-v 12.930516 0.000000 -25.293570
-v -67.878410 -0.358056 -10.089920
-v -67.339378 2.522237 -9.916985
-v -64.833672 3.526001 -9.718560
-v -59.675564 4.196694 -9.855558
-v -67.406670 -0.358056 -10.139132
-v -64.900963 -0.358056 -9.940708
-v -59.507854 -0.358056 -10.016035
-v -59.731579 4.489304 -9.615208
-v -68.766281 0.017365 -8.312651
-v -61.541279 4.652724 -9.455956
-v -64.306564 4.418884 -9.021996
-v -68.216446 2.746822 -8.400958
-v -66.650581 3.749619 -8.651530
-v -59.427166 3.784882 -9.559778
-v -61.786236 4.022710 -8.257978
-v -63.974606 3.788868 -8.313269
-v -65.829262 3.119603 -8.357790
-v -59.143261 0.017365 -9.115671
-v -61.502327 0.017365 -7.813870
-v -63.690685 0.017365 -7.869162
-v -65.545364 0.017365 -7.913683
-v -55.493790 -5.560600 3.433312
-v -51.015415 -5.732549 5.894245
-v -56.070770 -5.954949 -0.006194
-v -48.704243 -6.481259 3.595147
-v -54.538174 -6.106756 -2.649181
-v -49.189991 -6.609495 -0.728561
-v -55.477135 5.576054 3.416058
-v -50.997749 5.742819 5.908218
-v -56.045956 6.004477 0.023925
-v -48.701973 6.430726 3.600847
-v -54.556190 6.154920 -2.644493
-v -49.162441 6.672287 -0.707456
-vt 0.533231 0.765028
-vt 0.459354 0.811078
-vt 0.459354 0.788080
-vt 0.533231 0.745073
-vt 0.343399 0.809413
-vt 0.196742 0.704676
-vt 0.195899 0.685492
-vt 0.344336 0.779229
-vt 0.121514 0.321674
-vt 0.121514 0.371987
-vt 0.093618 0.365902
-vt 0.059006 0.357523
-vt 0.603890 0.710042
-vt 0.603890 0.693548
-vt 0.119260 0.593515
-vt 0.120266 0.585220
-vt 0.533231 0.688244
-vt 0.603890 0.646577
-vt 0.344336 0.715101
-vt 0.459354 0.722589
-vt 0.120648 0.574096
-vt 0.195608 0.661617
-vt 0.533231 0.603194
-vt 0.603890 0.576279
-vt 0.344336 0.619125
-vt 0.459354 0.624575
-vt 0.121514 0.519575
-vt 0.129599 0.528678
-vt 0.132798 0.574039
-vt 0.198585 0.586981
-vt 0.121514 0.276251
-vt 0.121514 0.288056
-vt 0.042189 0.337057
-vt 0.039620 0.325797
-vt 0.459354 0.508959
-vt 0.533231 0.502871
-vt 0.198585 0.502254
-vt 0.344336 0.505915
-vt 0.603890 0.493358
-vt 0.121514 0.460227
-vt 0.140992 0.502405
-vt 0.459354 0.393343
-vt 0.533231 0.402547
-vt 0.198029 0.389225
-vt 0.344336 0.392704
-vt 0.603890 0.410437
-vt 0.533231 0.317497
-vt 0.603890 0.340139
-vt 0.344336 0.296728
-vt 0.459354 0.295328
-vt 0.198029 0.317397
-vt 0.344336 0.232600
-vt 0.459354 0.229837
-vt 0.198029 0.269403
-vt 0.535419 0.260668
-vt 0.603890 0.293168
-vt 0.459354 0.205782
-vt 0.534572 0.241144
-vt 0.198029 0.252549
-vt 0.344336 0.209024
-vt 0.055979 0.501537
-vt 0.062496 0.481973
-vt 0.603890 0.276674
-vt 0.052352 0.509459
-vt 0.079476 0.414007
-vt 0.071068 0.447494
-vt 0.755366 0.375544
-vt 0.666328 0.371262
-vt 0.664731 0.354924
-vt 0.755366 0.367937
-vt 0.755366 0.397205
-vt 0.667072 0.386770
-vt 0.755366 0.429624
-vt 0.668947 0.430032
-vt 0.755366 0.467864
-vt 0.668947 0.483845
-vt 0.755366 0.506104
-vt 0.668947 0.537658
-vt 0.755366 0.538523
-vt 0.668947 0.583279
-vt 0.754870 0.557376
-vt 0.668451 0.610954
-vt 0.754870 0.564983
-vt 0.668451 0.621658
-vt 0.801438 0.405222
-vt 0.801438 0.367553
-vt 0.934068 0.271384
-vt 0.934068 0.342132
-vt 0.801438 0.494088
-vt 0.801438 0.449655
-vt 0.919503 0.425585
-vt 0.946392 0.509039
-vt 0.801438 0.342384
-vt 0.919503 0.252649
-vt 0.950074 0.601000
-vt 0.939669 0.615433
-vt 0.946392 0.579787
-vt 0.801438 0.531756
-vt 0.802063 0.561047
-vt 0.802063 0.552209
-vt 0.801438 0.333546
-vt 0.902697 0.245562
-vt 0.948973 0.510687
-vt 0.921537 0.425536
-vt 0.936398 0.340385
-vt 0.936398 0.268198
-vt 0.921537 0.249082
-vt 0.727588 0.712574
-vt 0.676947 0.797449
-vt 0.565828 0.839391
-vt 0.490770 0.861124
-vt 0.378508 0.848255
-vt 0.589626 0.192192
-vt 0.642458 0.248755
-vt 0.674652 0.324424
-vt 0.662918 0.138463
-vt 0.727887 0.120157
-vt 0.724770 0.256363
-vt 0.349232 0.136465
-vt 0.257502 0.139481
-vt 0.298350 0.084140
-vt 0.363633 0.030524
-vt 0.363859 0.030428
-vt 0.298275 0.084109
-vt 0.045114 0.143095
-vt 0.084562 0.173060
-vt 0.093713 0.061546
-vt 0.054489 0.065159
-vt 0.094386 0.061743
-vt 0.085236 0.173256
-vt 0.154265 0.068147
-vt 0.150290 0.212993
-vt 0.149608 0.212822
-vt 0.153583 0.067976
-vt 0.181699 0.082754
-vt 0.207007 0.200104
-vt 0.206279 0.200555
-vt 0.181143 0.083242
-vt 0.491376 0.868646
-vt 0.377815 0.854606
-vt 0.567228 0.845362
-vt 0.679543 0.802489
-vt 0.738174 0.712000
-vt 0.727153 0.256225
-vt 0.675542 0.327139
-vt 0.730395 0.114573
-vt 0.662828 0.133611
-vt 0.586605 0.189488
-vt 0.093924 0.366295
-vt 0.061779 0.359318
-vt 0.046450 0.338902
-vt 0.046729 0.327746
-vt 0.060847 0.922851
-vt 0.058932 0.931495
-vt 0.040659 0.930983
-vt 0.040489 0.921181
-vt 0.057904 0.913640
-vt 0.040340 0.911637
-vt 0.040317 0.910433
-vt 0.056505 0.493188
-vt 0.062115 0.476749
-vt 0.069355 0.446980
-vt 0.076208 0.419069
-vt 0.054536 0.498962
-vt 0.091274 0.365456
-vt 0.037880 0.928786
-vt 0.020129 0.929620
-vt 0.016642 0.930641
-vt 0.021237 0.935361
-vt 0.038582 0.940212
-vt 0.059430 0.931751
-vt 0.061360 0.930690
-vt 0.058083 0.938187
-vt 0.057286 0.941606
-vt 0.057199 0.933264
-vt 0.054170 0.934166
-vt 0.060719 0.929562
-vt 0.056206 0.934627
-vt 0.056365 0.928708
-vt 0.038862 0.943189
-vt 0.039126 0.946015
-vt 0.039273 0.938126
-vt 0.533231 0.745073
-vt 0.459354 0.788080
-vt 0.344336 0.779229
-vt 0.195899 0.685492
-vt 0.121514 0.321674
-vt 0.059006 0.357523
-vt 0.093618 0.365902
-vt 0.121514 0.371987
-vt 0.603890 0.693548
-vt 0.120266 0.585220
-vt 0.603890 0.646577
-vt 0.533231 0.688244
-vt 0.459354 0.722589
-vt 0.344336 0.715101
-vt 0.195608 0.661617
-vt 0.120648 0.574096
-vt 0.603890 0.576279
-vt 0.533231 0.603194
-vt 0.459354 0.624575
-vt 0.344336 0.619125
-vt 0.198585 0.502254
-vt 0.121514 0.460227
-vt 0.140875 0.502463
-vt 0.180512 0.526258
-vt 0.198585 0.586981
-vt 0.042189 0.337057
-vt 0.121514 0.288056
-vt 0.533231 0.502871
-vt 0.459354 0.508959
-vt 0.344336 0.505915
-vt 0.603890 0.493358
-vt 0.166762 0.613376
-vt 0.132553 0.574047
-vt 0.533231 0.402547
-vt 0.459354 0.393343
-vt 0.344336 0.392704
-vt 0.198029 0.389225
-vt 0.603890 0.410437
-vt 0.603890 0.340139
-vt 0.533231 0.317497
-vt 0.459354 0.295328
-vt 0.344336 0.296728
-vt 0.198029 0.317397
-vt 0.459354 0.229837
-vt 0.344336 0.232600
-vt 0.198029 0.269403
-vt 0.535419 0.260668
-vt 0.603890 0.293168
-vt 0.062496 0.481973
-vt 0.055979 0.501537
-vt 0.079476 0.414007
-vt 0.071068 0.447494
-vt 0.121514 0.519575
-vt 0.755366 0.375544
-vt 0.666328 0.371262
-vt 0.755366 0.397205
-vt 0.667072 0.386770
-vt 0.755366 0.429624
-vt 0.668947 0.430032
-vt 0.755366 0.467864
-vt 0.668947 0.483845
-vt 0.755366 0.506104
-vt 0.668947 0.537658
-vt 0.755366 0.538523
-vt 0.668947 0.583279
-vt 0.754870 0.557376
-vt 0.668451 0.610954
-vt 0.801438 0.405222
-vt 0.934068 0.342132
-vt 0.934068 0.271384
-vt 0.801438 0.367553
-vt 0.801438 0.494088
-vt 0.946392 0.509039
-vt 0.919503 0.425585
-vt 0.801438 0.449655
-vt 0.801438 0.342384
-vt 0.946392 0.579787
-vt 0.801438 0.531756
-vt 0.802063 0.552209
-vt 0.676947 0.797449
-vt 0.727588 0.712574
-vt 0.490770 0.861124
-vt 0.565828 0.839391
-vt 0.378508 0.848255
-vt 0.642458 0.248755
-vt 0.589626 0.192192
-vt 0.674652 0.324424
-vt 0.727887 0.120157
-vt 0.662918 0.138463
-vt 0.724770 0.256363
-vt 0.363633 0.030524
-vt 0.298350 0.084140
-vt 0.298275 0.084109
-vt 0.363859 0.030428
-vt 0.045114 0.143095
-vt 0.054489 0.065159
-vt 0.093713 0.061546
-vt 0.084562 0.173060
-vt 0.085236 0.173256
-vt 0.094386 0.061743
-vt 0.150290 0.212993
-vt 0.154265 0.068147
-vt 0.153583 0.067976
-vt 0.149608 0.212822
-vt 0.207007 0.200104
-vt 0.181699 0.082754
-vt 0.181143 0.083242
-vt 0.206279 0.200555
-vt 0.061779 0.359318
-vt 0.093924 0.366295
-vt 0.046450 0.338902
-vt 0.020754 0.924021
-vt 0.022865 0.932330
-vt 0.023262 0.914877
-vt 0.062115 0.476749
-vt 0.056505 0.493188
-vt 0.069355 0.446980
-vt 0.076208 0.419069
-vt 0.091274 0.365456
-vt 0.019188 0.932675
-vt 0.021310 0.942184
-vt 0.020022 0.938890
-vt 0.016753 0.931746
-vt 0.021634 0.934045
-vt 0.024586 0.934798
-vt 0.166531 0.613348
-vt 0.184396 0.573977
-vt 0.180331 0.525997
-vt 0.184411 0.574047
-vt 0.129761 0.529049
-vn 0.3411 0.0000 0.9400
-vn 0.1393 0.0000 0.9902
-vn 0.0961 -0.7625 0.6398
-vn 0.2286 -0.7486 0.6224
-vn -0.1835 0.0000 0.9830
-vn -0.4481 -0.0000 0.8939
-vn -0.3145 -0.7120 0.6277
-vn -0.1144 -0.7510 0.6503
-vn -0.1359 -0.9502 -0.2803
-vn -0.0967 -0.9891 -0.1110
-vn -0.2268 -0.9712 -0.0725
-vn -0.2391 -0.7809 0.5770
-vn 0.4630 0.0000 0.8863
-vn 0.3471 -0.6982 0.6261
-vn -0.6893 0.0001 0.7245
-vn -0.5256 -0.6572 0.5402
-vn 0.1120 -0.9497 0.2924
-vn 0.2034 -0.9204 0.3338
-vn -0.0311 -0.9608 0.2753
-vn 0.0522 -0.9583 0.2810
-vn -0.2949 -0.9217 0.2518
-vn -0.1261 -0.9531 0.2752
-vn 0.0620 -0.9904 0.1234
-vn 0.1132 -0.9828 0.1456
-vn -0.0047 -0.9933 0.1150
-vn 0.0317 -0.9926 0.1173
-vn -0.1439 -0.9851 0.0938
-vn -0.0852 -0.9921 0.0919
-vn -0.1252 -0.9795 0.1575
-vn -0.0644 -0.9909 0.1183
-vn -0.3797 0.0018 -0.9251
-vn -0.3014 -0.6150 -0.7286
-vn -0.7775 -0.5394 0.3232
-vn -0.9442 -0.0063 0.3294
-vn 0.0246 -0.9997 0.0010
-vn 0.0433 -0.9991 0.0006
-vn -0.0340 -0.9994 0.0108
-vn 0.0069 -1.0000 0.0016
-vn 0.0778 -0.9969 0.0004
-vn -0.1010 -0.9948 -0.0067
-vn -0.0848 -0.9937 0.0730
-vn 0.0278 -0.9930 -0.1151
-vn 0.0473 -0.9914 -0.1223
-vn -0.0117 -0.9938 -0.1104
-vn -0.0035 -0.9945 -0.1046
-vn 0.0910 -0.9852 -0.1453
-vn 0.0774 -0.9533 -0.2918
-vn 0.1546 -0.9285 -0.3375
-vn -0.0026 -0.9607 -0.2775
-vn 0.0428 -0.9597 -0.2778
-vn -0.0106 -0.9632 -0.2686
-vn -0.0349 -0.7525 -0.6576
-vn 0.0778 -0.7736 -0.6289
-vn -0.0782 -0.7025 -0.7074
-vn 0.1588 -0.7610 -0.6290
-vn 0.2649 -0.7130 -0.6492
-vn 0.1283 0.0000 -0.9917
-vn 0.2299 0.0000 -0.9732
-vn -0.1373 -0.0000 -0.9905
-vn -0.0764 0.0000 -0.9971
-vn -0.8175 -0.5191 -0.2493
-vn -0.4577 -0.7832 -0.4208
-vn 0.3293 0.0000 -0.9442
-vn -0.9999 0.0000 0.0100
-vn -0.2409 -0.7334 -0.6357
-vn -0.2649 -0.7992 -0.5395
-vn 0.0095 -0.8334 -0.5526
-vn 0.2439 -0.7187 -0.6511
-vn 0.3459 0.0000 -0.9383
-vn -0.0985 0.0000 -0.9951
-vn 0.0966 -0.9706 -0.2202
-vn 0.1768 -0.9245 -0.3377
-vn 0.1363 -0.9863 -0.0925
-vn 0.1566 -0.9769 -0.1451
-vn 0.1564 -0.9877 -0.0006
-vn 0.1590 -0.9873 0.0001
-vn 0.1581 -0.9832 0.0908
-vn 0.1791 -0.9731 0.1450
-vn 0.1491 -0.9647 0.2170
-vn 0.2302 -0.9136 0.3350
-vn 0.1609 -0.8078 0.5671
-vn 0.3458 -0.6939 0.6315
-vn 0.2083 0.0000 0.9781
-vn 0.4780 0.0000 0.8783
-vn 0.0629 -0.9975 -0.0317
-vn 0.0298 -0.9957 -0.0878
-vn 0.1459 -0.9880 -0.0507
-vn 0.2884 -0.9559 0.0551
-vn 0.0740 -0.9969 0.0276
-vn 0.0758 -0.9971 -0.0031
-vn 0.2405 -0.9703 -0.0234
-vn 0.2799 -0.9527 -0.1184
-vn -0.1012 -0.9310 -0.3507
-vn 0.0084 0.9215 -0.3881
-vn 0.8720 0.0000 0.4894
-vn 0.2571 0.0000 0.9664
-vn 0.1350 -0.9905 0.0265
-vn 0.0613 -0.9950 0.0792
-vn -0.0589 0.0000 0.9983
-vn 0.0122 -0.9114 0.4114
-vn -0.3409 0.0000 -0.9401
-vn -0.3399 0.0902 -0.9361
-vn 0.9664 0.0000 -0.2570
-vn 0.9892 0.0000 -0.1462
-vn 0.9918 0.0000 0.1279
-vn 0.9536 0.0000 -0.3009
-vn 0.1794 0.9124 -0.3678
-vn 0.1034 -0.9916 -0.0770
-vn 0.0782 -0.9864 0.1444
-vn 0.0342 -0.9855 0.1663
-vn -0.0001 -0.9857 0.1681
-vn -0.0906 -0.9819 0.1660
-vn -0.1199 -0.9647 -0.2344
-vn -0.0206 -0.9993 0.0296
-vn 0.2020 -0.9176 0.3424
-vn -0.0817 -0.9533 -0.2907
-vn 0.1924 -0.9619 -0.1943
-vn 0.3299 -0.9247 0.1897
-vn -0.2760 -0.8014 -0.5306
-vn 0.6388 -0.6554 -0.4029
-vn 0.7602 0.4235 -0.4925
-vn -0.2741 0.5295 -0.8028
-vn 0.0011 0.6136 0.7895
-vn 0.2716 0.7043 -0.6558
-vn -0.1773 -0.6328 -0.7538
-vn -0.4533 -0.7307 0.5105
-vn -0.0323 -0.6253 -0.7797
-vn -0.2069 -0.7080 0.6752
-vn -0.0188 0.5855 0.8104
-vn 0.2445 0.7120 -0.6582
-vn 0.4918 -0.5175 -0.7002
-vn 0.7971 -0.5322 0.2850
-vn 0.6212 0.5597 0.5484
-vn 0.5000 0.6897 -0.5237
-vn 0.0327 0.0000 0.9995
-vn -0.3799 0.0000 0.9250
-vn 0.2178 0.0000 0.9760
-vn 0.5368 0.0000 0.8437
-vn 0.9986 0.0000 -0.0532
-vn 0.9414 0.0000 0.3371
-vn 0.5340 0.0000 0.8455
-vn 0.6628 0.0000 -0.7488
-vn -0.2442 0.0000 -0.9697
-vn -0.4261 0.0000 -0.9047
-vn -0.7736 0.0612 0.6307
-vn -0.0074 0.0279 0.9996
-vn 0.0103 0.0624 0.9980
-vn 0.1089 0.0000 0.9940
-vn -0.5377 0.0079 0.8431
-vn -0.0221 0.0000 0.9997
-vn 0.0200 0.0000 0.9998
-vn -0.3112 -0.0504 -0.9490
-vn -0.1127 -0.0153 -0.9935
-vn -0.0829 0.2242 -0.9710
-vn -0.2076 0.3043 -0.9297
-vn -0.3678 0.0000 -0.9299
-vn -0.8119 0.3486 -0.4682
-vn -0.3112 0.0504 -0.9490
-vn -0.1127 0.0153 -0.9935
-vn 0.0844 -0.2753 -0.9576
-vn 0.0640 0.0000 -0.9979
-vn 0.0590 0.4653 -0.8831
-vn -0.1742 0.4819 -0.8587
-vn -0.9171 0.2764 -0.2871
-vn 0.0844 0.2753 -0.9576
-vn 0.0385 0.0000 -0.9992
-vn -0.2439 0.0000 -0.9698
-vn -0.9402 -0.0066 -0.3405
-vn 0.2286 0.7486 0.6224
-vn 0.0961 0.7625 0.6398
-vn -0.1143 0.7510 0.6503
-vn -0.3139 0.7121 0.6280
-vn -0.1654 0.9554 -0.2448
-vn -0.2776 0.7515 0.5984
-vn -0.3843 0.9124 -0.1406
-vn -0.1340 0.9856 -0.1030
-vn 0.3471 0.6982 0.6261
-vn -0.5248 0.6576 0.5404
-vn 0.2034 0.9204 0.3338
-vn 0.1120 0.9497 0.2924
-vn 0.0522 0.9583 0.2810
-vn -0.0309 0.9608 0.2753
-vn -0.1254 0.9535 0.2740
-vn -0.2941 0.9218 0.2526
-vn 0.1132 0.9828 0.1456
-vn 0.0620 0.9904 0.1234
-vn 0.0317 0.9926 0.1173
-vn -0.0045 0.9933 0.1150
-vn -0.0329 0.9994 0.0106
-vn -0.1067 0.9940 -0.0226
-vn -0.0833 0.9940 0.0701
-vn -0.1010 0.9924 0.0698
-vn -0.0650 0.9909 0.1175
-vn -0.7982 0.5125 0.3164
-vn -0.3098 0.6240 -0.7173
-vn 0.0433 0.9991 0.0006
-vn 0.0246 0.9997 0.0010
-vn 0.0071 1.0000 0.0016
-vn 0.0778 0.9969 0.0004
-vn -0.1287 0.9787 0.1600
-vn -0.1194 0.9806 0.1552
-vn 0.0473 0.9914 -0.1223
-vn 0.0278 0.9930 -0.1151
-vn -0.0033 0.9945 -0.1046
-vn -0.0104 0.9938 -0.1104
-vn 0.0910 0.9852 -0.1453
-vn 0.1546 0.9285 -0.3375
-vn 0.0774 0.9533 -0.2918
-vn 0.0428 0.9597 -0.2778
-vn -0.0023 0.9607 -0.2775
-vn -0.0094 0.9632 -0.2687
-vn 0.0778 0.7736 -0.6289
-vn -0.0347 0.7525 -0.6577
-vn -0.0774 0.7024 -0.7075
-vn 0.1588 0.7610 -0.6290
-vn 0.2649 0.7130 -0.6492
-vn -0.4577 0.7832 -0.4208
-vn -0.8175 0.5191 -0.2493
-vn -0.2460 0.7658 -0.5941
-vn -0.2649 0.7992 -0.5395
-vn -0.1451 0.9849 0.0943
-vn 0.0095 0.8334 -0.5526
-vn 0.2439 0.7187 -0.6511
-vn 0.0966 0.9706 -0.2202
-vn 0.1768 0.9245 -0.3377
-vn 0.1363 0.9863 -0.0925
-vn 0.1566 0.9769 -0.1451
-vn 0.1564 0.9877 -0.0006
-vn 0.1590 0.9873 0.0001
-vn 0.1581 0.9832 0.0908
-vn 0.1791 0.9731 0.1450
-vn 0.1491 0.9647 0.2170
-vn 0.2302 0.9136 0.3350
-vn 0.1609 0.8078 0.5671
-vn 0.3458 0.6939 0.6315
-vn 0.0629 0.9975 -0.0317
-vn 0.2884 0.9559 0.0551
-vn 0.1459 0.9880 -0.0507
-vn 0.0298 0.9957 -0.0878
-vn 0.0740 0.9969 0.0276
-vn 0.2799 0.9527 -0.1184
-vn 0.2405 0.9703 -0.0234
-vn 0.0758 0.9971 -0.0031
-vn -0.1012 0.9310 -0.3507
-vn 0.1350 0.9905 0.0265
-vn 0.0613 0.9950 0.0792
-vn 0.0122 0.9114 0.4114
-vn 0.0782 0.9864 0.1444
-vn 0.1034 0.9916 -0.0770
-vn -0.0001 0.9857 0.1681
-vn 0.0342 0.9855 0.1663
-vn -0.0906 0.9819 0.1660
-vn -0.0206 0.9993 0.0296
-vn -0.1199 0.9647 -0.2344
-vn 0.2020 0.9176 0.3424
-vn 0.1924 0.9619 -0.1943
-vn -0.0817 0.9533 -0.2907
-vn 0.3299 0.9247 0.1897
-vn 0.6388 0.6554 -0.4029
-vn -0.2760 0.8014 -0.5306
-vn -0.2741 -0.5295 -0.8028
-vn 0.7602 -0.4235 -0.4925
-vn 0.2716 -0.7043 -0.6558
-vn 0.0011 -0.6136 0.7895
-vn -0.4533 0.7307 0.5105
-vn -0.1773 0.6328 -0.7538
-vn -0.2069 0.7080 0.6752
-vn -0.0323 0.6253 -0.7797
-vn 0.2445 -0.7120 -0.6582
-vn -0.0188 -0.5855 0.8104
-vn 0.7971 0.5322 0.2850
-vn 0.4918 0.5175 -0.7002
-vn 0.5000 -0.6897 -0.5237
-vn 0.6212 -0.5597 0.5484
-vn -0.0074 -0.0279 0.9996
-vn -0.5703 -0.0342 0.8207
-vn 0.0103 -0.0624 0.9980
-vn -0.0829 -0.2242 -0.9710
-vn -0.2083 -0.3051 -0.9293
-vn -0.7392 -0.3195 -0.5928
-vn -0.1742 -0.4819 -0.8587
-vn 0.0590 -0.4653 -0.8831
-vn -0.9263 -0.2829 -0.2489
-vn -0.1333 -0.9771 0.1655
-vn -0.1184 -0.9873 0.1057
-vn -0.1028 -0.9924 0.0673
-vn -0.1177 0.9870 0.1092
-vn -0.0852 0.9916 0.0972
-usemtl Bluegill
-s 1
-f 117/1/1 175/2/2 4/3/3 5/4/4
-f 116/5/5 115/6/6 2/7/7 3/8/8
-f 33/9/9 26/10/10 39/11/11 32/12/12
-f 218/13/13 117/1/1 5/4/4 69/14/14
-f 175/2/2 116/5/5 3/8/8 4/3/3
-f 115/6/6 174/15/15 1/16/16 2/7/7
-f 69/14/14 5/4/4 11/17/17 70/18/18
-f 4/3/3 3/8/8 9/19/19 10/20/20
-f 2/7/7 1/16/16 7/21/21 8/22/22
-f 5/4/4 4/3/3 10/20/20 11/17/17
-f 3/8/8 2/7/7 8/22/22 9/19/19
-f 70/18/18 11/17/17 17/23/23 71/24/24
-f 10/20/20 9/19/19 15/25/25 16/26/26
-f 7/21/21 13/27/27 279/28/28 277/29/29
-f 11/17/17 10/20/20 16/26/26 17/23/23
-f 9/19/19 8/22/22 14/30/30 15/25/25
-f 165/31/31 40/32/32 25/33/33 136/34/34
-f 17/23/23 16/26/26 22/35/35 23/36/36
-f 15/25/25 14/30/30 20/37/37 21/38/38
-f 71/24/24 17/23/23 23/36/36 72/39/39
-f 16/26/26 15/25/25 21/38/38 22/35/35
-f 13/27/27 19/40/40 281/41/41 279/28/28
-f 23/36/36 22/35/35 29/42/42 30/43/43
-f 21/38/38 20/37/37 27/44/44 28/45/45
-f 40/32/32 33/9/9 32/12/12 25/33/33
-f 72/39/39 23/36/36 30/43/43 73/46/46
-f 22/35/35 21/38/38 28/45/45 29/42/42
-f 20/37/37 19/40/40 26/10/10 27/44/44
-f 73/46/46 30/43/43 37/47/47 74/48/48
-f 29/42/42 28/45/45 35/49/49 36/50/50
-f 27/44/44 26/10/10 33/9/9 34/51/51
-f 30/43/43 29/42/42 36/50/50 37/47/47
-f 28/45/45 27/44/44 34/51/51 35/49/49
-f 36/50/50 35/49/49 42/52/52 43/53/53
-f 34/51/51 33/9/9 40/32/32 41/54/54
-f 37/47/47 36/50/50 43/53/53 44/55/55
-f 35/49/49 34/51/51 41/54/54 42/52/52
-f 74/48/48 37/47/47 44/55/55 75/56/56
-f 44/55/55 43/53/53 168/57/57 169/58/58
-f 42/52/52 41/54/54 166/59/59 167/60/60
-f 7/21/21 1/16/16 48/61/61 49/62/62
-f 75/56/56 44/55/55 169/58/58 217/63/63
-f 43/53/53 42/52/52 167/60/60 168/57/57
-f 41/54/54 40/32/32 165/31/31 166/59/59
-f 1/16/16 174/15/15 164/64/64 48/61/61
-f 26/10/10 19/40/40 46/65/65 39/11/11
-f 19/40/40 13/27/27 47/66/66 46/65/65
-f 13/27/27 7/21/21 49/62/62 47/66/66
-f 45/67/67 56/68/68 185/69/69 170/70/70
-f 38/71/71 55/72/72 56/68/68 45/67/67
-f 31/73/73 54/74/74 55/72/72 38/71/71
-f 24/75/75 53/76/76 54/74/74 31/73/73
-f 18/77/77 52/78/78 53/76/76 24/75/75
-f 12/79/79 51/80/80 52/78/78 18/77/77
-f 6/81/81 50/82/82 51/80/80 12/79/79
-f 176/83/83 186/84/84 50/82/82 6/81/81
-f 63/85/85 62/86/86 61/87/87 60/88/88
-f 65/89/89 64/90/90 59/91/91 58/92/92
-f 62/86/86 66/93/93 193/94/94 61/87/87
-f 204/95/95 187/96/96 57/97/97
-f 64/90/90 63/85/85 60/88/88 59/91/91
-f 67/98/98 65/89/89 58/92/92 57/97/97
-f 200/99/99 68/100/100 187/96/96
-f 66/93/93 203/101/101 194/102/102 193/94/94
-f 45/67/67 170/70/70 203/101/101 66/93/93
-f 176/83/83 6/81/81 68/100/100 200/99/99
-f 12/79/79 18/77/77 65/89/89 67/98/98
-f 24/75/75 31/73/73 63/85/85 64/90/90
-f 6/81/81 12/79/79 67/98/98 68/100/100
-f 38/71/71 45/67/67 66/93/93 62/86/86
-f 18/77/77 24/75/75 64/90/90 65/89/89
-f 31/73/73 38/71/71 62/86/86 63/85/85
-f 57/97/97 58/92/92 205/103/103 204/95/95
-f 58/92/92 59/91/91 206/104/104 205/103/103
-f 59/91/91 60/88/88 207/105/105 206/104/104
-f 60/88/88 61/87/87 208/106/106 207/105/105
-f 61/87/87 193/94/94 209/107/107 208/106/106
-f 56/68/68 75/56/56 217/63/63 185/69/69
-f 55/72/72 74/48/48 75/56/56 56/68/68
-f 54/74/74 73/46/46 74/48/48 55/72/72
-f 53/76/76 72/39/39 73/46/46 54/74/74
-f 52/78/78 71/24/24 72/39/39 53/76/76
-f 51/80/80 70/18/18 71/24/24 52/78/78
-f 50/82/82 69/14/14 70/18/18 51/80/80
-f 186/84/84 218/13/13 69/14/14 50/82/82
-f 218/13/13 186/84/84 79/108/108 80/109/109
-f 175/2/2 117/1/1 77/110/110 78/111/111
-f 117/1/1 218/13/13 80/109/109 77/110/110
-f 116/5/5 175/2/2 78/111/111 76/112/112
-f 217/63/63 169/58/58 81/113/113 83/114/114
-f 185/69/69 217/63/63 83/114/114 82/115/115
-f 83/114/114 81/113/113 84/116/116 86/117/117
-f 82/115/115 83/114/114 86/117/117 85/118/118
-f 168/119/57 167/120/60 87/121/119 88/122/120
-f 167/120/60 168/119/57 90/123/121 89/124/122
-f 168/119/57 88/122/120 90/123/121
-f 88/122/120 87/121/119 89/124/122 90/123/121
-f 167/120/60 89/124/122 87/121/119
-f 28/125/45 91/126/123 92/127/124 35/128/49
-f 35/128/49 94/129/125 93/130/126 28/125/45
-f 94/129/125 98/131/127 97/132/128 93/130/126
-f 91/126/123 95/133/129 96/134/130 92/127/124
-f 98/131/127 102/135/131 101/136/132 97/132/128
-f 95/133/129 99/137/133 100/138/134 96/134/130
-f 28/125/45 93/130/126 91/126/123
-f 93/130/126 97/132/128 95/133/129 91/126/123
-f 97/132/128 101/136/132 99/137/133 95/133/129
-f 100/138/134 99/137/133 101/136/132 102/135/131
-f 102/135/131 98/131/127 96/134/130 100/138/134
-f 98/131/127 94/129/125 92/127/124 96/134/130
-f 35/128/49 92/127/124 94/129/125
-f 76/112/112 78/111/111 247/139/135 246/140/136
-f 78/111/111 77/110/110 248/141/137 247/139/135
-f 77/110/110 80/109/109 249/142/138 248/141/137
-f 80/109/109 79/108/108 250/143/139 249/142/138
-f 79/108/108 186/84/84 250/143/139
-f 76/112/112 246/140/136 116/5/5
-f 82/115/115 85/118/118 252/144/140 251/145/141
-f 85/118/118 86/117/117 253/146/142 252/144/140
-f 86/117/117 84/116/116 254/147/143 253/146/142
-f 84/116/116 81/113/113 255/148/144 254/147/143
-f 81/113/113 169/58/58 255/148/144
-f 82/115/115 251/145/141 185/69/69
-f 32/12/12 39/11/11 105/149/145 104/150/146
-f 25/33/33 32/12/12 104/150/146 103/151/147
-f 136/34/34 25/33/33 103/151/147 256/152/148
-f 104/153/146 105/154/145 262/155/149 261/156/150
-f 103/157/147 104/153/146 261/156/150 260/158/151
-f 103/157/147 260/158/151 256/159/148
-f 49/62/62 48/61/61 109/160/152 110/161/153
-f 47/66/66 49/62/62 110/161/153 108/162/154
-f 46/65/65 47/66/66 108/162/154 107/163/155
-f 48/61/61 164/64/64 264/164/156 109/160/152
-f 39/11/11 46/65/65 107/163/155 106/165/157
-f 264/166/156 267/167/158 268/168/159 272/169/160 276/170/161
-f 107/171/155 108/172/154 113/173/162 112/174/163
-f 106/175/157 107/171/155 112/174/163 111/176/164
-f 108/172/154 110/177/153 114/178/165 113/173/162
-f 109/179/152 264/166/156 276/170/161 114/178/165 110/177/153
-f 112/174/163 113/173/162 275/180/166 274/181/167
-f 111/176/164 112/174/163 274/181/167 273/182/168
-f 113/173/162 114/178/165 276/170/161 275/180/166
-f 39/11/11 106/165/157 105/149/145
-f 105/154/145 106/175/157 111/176/164 273/182/168 262/155/149
-f 57/97/97 187/96/96 68/100/100 67/98/98
-f 117/1/1 122/183/169 121/184/170 175/2/2
-f 116/5/5 120/185/171 119/186/172 115/6/6
-f 151/187/173 150/188/174 157/189/175 144/190/176
-f 218/13/13 210/191/177 122/183/169 117/1/1
-f 175/2/2 121/184/170 120/185/171 116/5/5
-f 115/6/6 119/186/172 118/192/178 174/15/15
-f 210/191/177 211/193/179 128/194/180 122/183/169
-f 121/184/170 127/195/181 126/196/182 120/185/171
-f 119/186/172 125/197/183 124/198/184 118/192/178
-f 122/183/169 128/194/180 127/195/181 121/184/170
-f 120/185/171 126/196/182 125/197/183 119/186/172
-f 211/193/179 212/199/185 134/200/186 128/194/180
-f 127/195/181 133/201/187 132/202/188 126/196/182
-f 138/203/189 137/204/190 287/205/191 288/206/192
-f 128/194/180 134/200/186 133/201/187 127/195/181
-f 126/196/182 132/202/188 131/207/193 125/197/183
-f 165/31/31 136/34/34 143/208/194 158/209/195
-f 134/200/186 141/210/196 140/211/197 133/201/187
-f 132/202/188 139/212/198 138/203/189 131/207/193
-f 212/199/185 213/213/199 141/210/196 134/200/186
-f 133/201/187 140/211/197 139/212/198 132/202/188
-f 124/198/184 125/197/183 284/214/200 283/215/201
-f 141/210/196 148/216/202 147/217/203 140/211/197
-f 139/212/198 146/218/204 145/219/205 138/203/189
-f 158/209/195 143/208/194 150/188/174 151/187/173
-f 213/213/199 214/220/206 148/216/202 141/210/196
-f 140/211/197 147/217/203 146/218/204 139/212/198
-f 138/203/189 145/219/205 144/190/176 137/204/190
-f 214/220/206 215/221/207 155/222/208 148/216/202
-f 147/217/203 154/223/209 153/224/210 146/218/204
-f 145/219/205 152/225/211 151/187/173 144/190/176
-f 148/216/202 155/222/208 154/223/209 147/217/203
-f 146/218/204 153/224/210 152/225/211 145/219/205
-f 154/223/209 161/226/212 160/227/213 153/224/210
-f 152/225/211 159/228/214 158/209/195 151/187/173
-f 155/222/208 162/229/215 161/226/212 154/223/209
-f 153/224/210 160/227/213 159/228/214 152/225/211
-f 215/221/207 216/230/216 162/229/215 155/222/208
-f 162/229/215 169/58/58 168/57/57 161/226/212
-f 160/227/213 167/60/60 166/59/59 159/228/214
-f 124/198/184 177/231/217 173/232/218 118/192/178
-f 216/230/216 217/63/63 169/58/58 162/229/215
-f 161/226/212 168/57/57 167/60/60 160/227/213
-f 159/228/214 166/59/59 165/31/31 158/209/195
-f 118/192/178 173/232/218 164/64/64 174/15/15
-f 144/190/176 157/189/175 171/233/219 137/204/190
-f 137/204/190 171/233/219 172/234/220 130/235/221
-f 130/235/221 172/234/220 177/231/217 124/198/184
-f 163/236/222 170/70/70 185/69/69 184/237/223
-f 156/238/224 163/236/222 184/237/223 183/239/225
-f 149/240/226 156/238/224 183/239/225 182/241/227
-f 142/242/228 149/240/226 182/241/227 181/243/229
-f 135/244/230 142/242/228 181/243/229 180/245/231
-f 129/246/232 135/244/230 180/245/231 179/247/233
-f 123/248/234 129/246/232 179/247/233 178/249/235
-f 176/83/83 123/248/234 178/249/235 186/84/84
-f 196/250/236 191/251/237 192/252/238 195/253/239
-f 198/254/240 189/255/241 190/256/242 197/257/243
-f 195/253/239 192/252/238 193/94/94 199/258/244
-f 204/95/95 188/259/245 187/96/96
-f 197/257/243 190/256/242 191/251/237 196/250/236
-f 201/260/246 188/259/245 189/255/241 198/254/240
-f 200/99/99 187/96/96 202/261/247
-f 199/258/244 193/94/94 194/102/102 203/101/101
-f 163/236/222 199/258/244 203/101/101 170/70/70
-f 176/83/83 200/99/99 202/261/247 123/248/234
-f 129/246/232 201/260/246 198/254/240 135/244/230
-f 142/242/228 197/257/243 196/250/236 149/240/226
-f 123/248/234 202/261/247 201/260/246 129/246/232
-f 156/238/224 195/253/239 199/258/244 163/236/222
-f 135/244/230 198/254/240 197/257/243 142/242/228
-f 149/240/226 196/250/236 195/253/239 156/238/224
-f 188/259/245 204/95/95 205/103/103 189/255/241
-f 189/255/241 205/103/103 206/104/104 190/256/242
-f 190/256/242 206/104/104 207/105/105 191/251/237
-f 191/251/237 207/105/105 208/106/106 192/252/238
-f 192/252/238 208/106/106 209/107/107 193/94/94
-f 193/94/94 209/107/107 194/102/102
-f 184/237/223 185/69/69 217/63/63 216/230/216
-f 183/239/225 184/237/223 216/230/216 215/221/207
-f 182/241/227 183/239/225 215/221/207 214/220/206
-f 181/243/229 182/241/227 214/220/206 213/213/199
-f 180/245/231 181/243/229 213/213/199 212/199/185
-f 179/247/233 180/245/231 212/199/185 211/193/179
-f 178/249/235 179/247/233 211/193/179 210/191/177
-f 186/84/84 178/249/235 210/191/177 218/13/13
-f 218/13/13 223/262/248 222/263/249 186/84/84
-f 175/2/2 221/264/250 220/265/251 117/1/1
-f 117/1/1 220/265/251 223/262/248 218/13/13
-f 116/5/5 219/266/252 221/264/250 175/2/2
-f 217/63/63 226/267/253 224/268/254 169/58/58
-f 185/69/69 225/269/255 226/267/253 217/63/63
-f 226/267/253 229/270/256 227/271/257 224/268/254
-f 225/269/255 228/272/258 229/270/256 226/267/253
-f 168/119/57 231/273/259 230/274/260 167/120/60
-f 167/120/60 232/275/261 233/276/262 168/119/57
-f 168/119/57 233/276/262 231/273/259
-f 231/273/259 233/276/262 232/275/261 230/274/260
-f 167/120/60 230/274/260 232/275/261
-f 146/277/204 153/278/210 235/279/263 234/280/264
-f 153/278/210 146/277/204 236/281/265 237/282/266
-f 237/282/266 236/281/265 240/283/267 241/284/268
-f 234/280/264 235/279/263 239/285/269 238/286/270
-f 241/284/268 240/283/267 244/287/271 245/288/272
-f 238/286/270 239/285/269 243/289/273 242/290/274
-f 146/277/204 234/280/264 236/281/265
-f 236/281/265 234/280/264 238/286/270 240/283/267
-f 240/283/267 238/286/270 242/290/274 244/287/271
-f 243/289/273 245/288/272 244/287/271 242/290/274
-f 245/288/272 243/289/273 239/285/269 241/284/268
-f 241/284/268 239/285/269 235/279/263 237/282/266
-f 153/278/210 237/282/266 235/279/263
-f 219/266/252 246/140/136 247/139/135 221/264/250
-f 221/264/250 247/139/135 248/141/137 220/265/251
-f 220/265/251 248/141/137 249/142/138 223/262/248
-f 223/262/248 249/142/138 250/143/139 222/263/249
-f 222/263/249 250/143/139 186/84/84
-f 219/266/252 116/5/5 246/140/136
-f 225/269/255 251/145/141 252/144/140 228/272/258
-f 228/272/258 252/144/140 253/146/142 229/270/256
-f 229/270/256 253/146/142 254/147/143 227/271/257
-f 227/271/257 254/147/143 255/148/144 224/268/254
-f 224/268/254 255/148/144 169/58/58
-f 225/269/255 185/69/69 251/145/141
-f 150/188/174 258/291/275 259/292/276 157/189/175
-f 143/208/194 257/293/277 258/291/275 150/188/174
-f 136/34/34 256/152/148 257/293/277 143/208/194
-f 258/294/275 261/156/150 262/155/149 259/295/276
-f 257/296/277 260/158/151 261/156/150 258/294/275
-f 257/296/277 256/159/148 260/158/151
-f 177/231/217 268/297/159 267/298/158 173/232/218
-f 172/234/220 266/299/278 268/297/159 177/231/217
-f 171/233/219 265/300/279 266/299/278 172/234/220
-f 173/232/218 267/298/158 264/164/156 164/64/64
-f 157/189/175 263/301/280 265/300/279 171/233/219
-f 265/302/279 270/303/281 271/304/282 266/305/278
-f 263/306/280 269/307/283 270/303/281 265/302/279
-f 266/305/278 271/304/282 272/169/160 268/168/159
-f 270/303/281 274/181/167 275/180/166 271/304/282
-f 269/307/283 273/182/168 274/181/167 270/303/281
-f 271/304/282 275/180/166 276/170/161 272/169/160
-f 157/189/175 259/292/276 263/301/280
-f 259/295/276 262/155/149 273/182/168 269/307/283 263/306/280
-f 188/259/245 201/260/246 202/261/247 187/96/96
-f 278/308/284 277/29/29 279/28/28 280/309/285
-f 280/309/285 279/28/28 281/41/41 282/310/286
-f 8/22/22 7/21/21 277/29/29 278/308/284
-f 19/40/40 20/37/37 282/310/286 281/41/41
-f 20/37/37 14/30/30 280/309/285 282/310/286
-f 14/30/30 8/22/22 278/308/284 280/309/285
-f 284/214/200 286/311/287 285/312/288 283/215/201
-f 286/311/287 288/206/192 287/205/191 285/312/288
-f 131/207/193 138/203/189 288/206/192 286/311/287
-f 125/197/183 131/207/193 286/311/287 284/214/200
-f 137/204/190 130/235/221 285/312/288 287/205/191
-f 130/235/221 124/198/184 283/215/201 285/312/288
-"#
-}
\ No newline at end of file
@@ -0,0 +1,307 @@
+use crate::{species, util};
+use cgmath::{InnerSpace, Zero, num_traits::Pow};
+
+// One flocking step for every fish: finding its neighbours, the flocking forces and moving it.
+// It runs on the CPU (cpu_step) or on the GPU (boid_compute.wgsl), both take and give the
+// same data, so the two can be checked against each other (see compare).
+// Everything else (walls, depth, predators and the sub) is worked out on the CPU first
+// and comes in as StepIn::accel.
+
+// The hunts and flees masks have a bit per species
+pub const MAX_SPECIES: usize = 32;
+// how far apart (in distance moved and velocity) the CPU and GPU steps can be, see compare
+pub const GPU_STEP_TOLERANCE: f32 = 1e-3;
+// the cells grow past the perception radius when the fish are spread out further than this many would cover
+const MAX_GRID_CELLS: usize = 1 << 16;
+
+// The layouts match the structs in boid_compute.wgsl
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct StepGlobals {
+    pub count: u32,
+    pub avoidance_radius: f32,
+    pub delta: f32,
+    // the neighbour grid over every fish, see grid
+    pub cell_size: f32,
+    pub grid_min: [i32; 3],
+    pub _padding: u32,
+    pub grid_dims: [u32; 3],
+    pub _padding2: u32,
+}
+
+impl StepGlobals {
+    // The cells are at least as big as the furthest any species sees, so a fish's neighbours
+    // are all in its own cell and the ones around it
+    pub fn new(avoidance_radius: f32, delta: f32, species: &[StepSpecies], boids: &[StepIn]) -> Self {
+        let mut cell_size = species.iter().map(|species| species.perception_radius).fold(0.0, f32::max);
+        let (min, max) = boids.iter().fold(([f32::MAX; 3], [f32::MIN; 3]), |(min, max), boid| (
+            [0, 1, 2].map(|a| min[a].min(boid.pos[a])),
+            [0, 1, 2].map(|a| max[a].max(boid.pos[a])),
+        ));
+
+        loop {
+            let (grid_min, grid_dims) = if boids.is_empty() {
+                ([0; 3], [1; 3])
+            } else {
+                let grid_min = min.map(|v| (v / cell_size).floor() as i32);
+                let grid_max = max.map(|v| (v / cell_size).floor() as i32);
+                (grid_min, [0, 1, 2].map(|a| (grid_max[a] - grid_min[a] + 1) as u32))
+            };
+            if grid_dims.iter().map(|dim| *dim as usize).product::<usize>() <= MAX_GRID_CELLS {
+                return Self {
+                    count: boids.len() as u32,
+                    avoidance_radius,
+                    delta,
+                    cell_size,
+                    grid_min,
+                    _padding: 0,
+                    grid_dims,
+                    _padding2: 0,
+                };
+            }
+            cell_size *= 2.0;
+        }
+    }
+
+    fn num_cells(&self) -> usize {
+        self.grid_dims.iter().map(|dim| *dim as usize).product()
+    }
+
+    fn cell_index(&self, pos: [f32; 3]) -> u32 {
+        let cell = [0, 1, 2].map(|a| {
+            let cell = (pos[a] / self.cell_size).floor() as i32 - self.grid_min[a];
+            cell.clamp(0, self.grid_dims[a] as i32 - 1) as u32
+        });
+        cell[0] + self.grid_dims[0] * (cell[1] + self.grid_dims[1] * cell[2])
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct StepSpecies {
+    pub perception_radius: f32,
+    pub min_speed: f32,
+    pub middle_speed: f32,
+    pub separation: f32,
+    pub alignment: f32,
+    pub cohesion: f32,
+    pub max_steer_force: f32,
+    // bit per species
+    pub hunts: u32,
+    pub flees: u32,
+}
+
+impl StepSpecies {
+    // interactions is the species' row of species::interaction_matrix
+    pub fn new(def: &species::SpeciesDef, separation: f32, alignment: f32, cohesion: f32, max_steer_force: f32, interactions: &[species::Interaction]) -> Self {
+        let mask = |kind: species::Interaction| interactions.iter()
+            .enumerate()
+            .filter(|(_b, interaction)| **interaction == kind)
+            .fold(0, |mask, (b, _interaction)| mask | 1 << b);
+
+        Self {
+            perception_radius: def.perception_radius,
+            min_speed: def.min_speed(),
+            middle_speed: def.middle_speed(),
+            separation,
+            alignment,
+            cohesion,
+            max_steer_force,
+            hunts: mask(species::Interaction::Hunts),
+            flees: mask(species::Interaction::Flees),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct StepIn {
+    pub pos: [f32; 3],
+    pub species: u32,
+    pub vel: [f32; 3],
+    pub time: f32,
+    // every force that isn't from the other fish
+    pub accel: [f32; 3],
+    // higher while fleeing
+    pub max_speed: f32,
+    // the sub's part of the separation
+    pub separation: [f32; 3],
+    // where its instance goes, the species are one after another
+    pub slot: u32,
+    // 0 while full after a catch
+    pub hungry: u32,
+    pub _padding: [u32; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct StepOut {
+    pub pos: [f32; 3],
+    pub time: f32,
+    pub vel: [f32; 3],
+    // index of the nearest prey it saw, -1 if none
+    pub prey: i32,
+    // away from every predator it saw, zero if none
+    pub threat: [f32; 3],
+    pub prey_dist: f32,
+}
+
+// What a fish saw of the others
+struct Sums {
+    num_flockmates: u32,
+    heading: cgmath::Vector3<f32>,
+    center: cgmath::Vector3<f32>,
+    separation: cgmath::Vector3<f32>,
+    threat: cgmath::Vector3<f32>,
+    prey: i32,
+    prey_dist: f32,
+}
+
+impl Sums {
+    fn new(boid: &StepIn) -> Self {
+        Self {
+            num_flockmates: 0,
+            heading: cgmath::Vector3::zero(),
+            center: cgmath::Vector3::zero(),
+            separation: boid.separation.into(),
+            threat: cgmath::Vector3::zero(),
+            prey: -1,
+            prey_dist: f32::MAX,
+        }
+    }
+
+    fn see(&mut self, boid: &StepIn, species: &StepSpecies, other: &StepIn, other_i: usize, avoidance_radius: f32) {
+        let offset = cgmath::Vector3::from(other.pos) - cgmath::Vector3::from(boid.pos);
+        let distance = offset.magnitude();
+        if distance >= species.perception_radius { return; }
+
+        let bit = 1 << other.species;
+        if other.species == boid.species {
+            self.num_flockmates += 1;
+            self.heading += cgmath::Vector3::from(other.vel);
+            self.center += cgmath::Vector3::from(other.pos);
+            if distance < avoidance_radius {
+                self.separation -= offset / distance.pow(2);
+            }
+        } else if species.hunts & bit != 0 {
+            if boid.hungry != 0 && distance < self.prey_dist {
+                self.prey = other_i as i32;
+                self.prey_dist = distance;
+            }
+        } else if species.flees & bit != 0 {
+            self.separation -= offset / distance.pow(2);
+            self.threat -= offset / distance.pow(2);
+        } else {
+            self.separation -= offset / distance.pow(2);
+        }
+    }
+}
+
+fn steer_towards(target: cgmath::Vector3<f32>, vel: cgmath::Vector3<f32>, max_speed: f32, max_steer_force: f32) -> cgmath::Vector3<f32> {
+    let v = util::safe_normalize_to(target, max_speed) - vel;
+    let v_mag = v.magnitude().min(max_steer_force);
+    util::safe_normalize_to(v, v_mag)
+}
+
+fn move_boid(boid: &StepIn, species: &StepSpecies, sums: &Sums, delta: f32) -> StepOut {
+    let pos = cgmath::Vector3::from(boid.pos);
+    let vel = cgmath::Vector3::from(boid.vel);
+    let mut accel = cgmath::Vector3::from(boid.accel);
+
+    if sums.num_flockmates > 0 {
+        let center_offset = sums.center / sums.num_flockmates as f32 - pos;
+        let steer = |target| steer_towards(target, vel, boid.max_speed, species.max_steer_force);
+
+        accel += steer(sums.separation) * species.separation;
+        accel += steer(sums.heading) * species.alignment;
+        accel += steer(center_offset) * species.cohesion;
+    }
+
+    let vel = vel + accel * delta;
+    let speed = vel.magnitude().clamp(species.min_speed, boid.max_speed);
+    let vel = util::safe_normalize_to(vel, speed);
+    let pos = pos + vel * delta;
+
+    // the swimming animation speeds up with the fish
+    let time = boid.time + delta * speed / species.middle_speed;
+
+    StepOut {
+        pos: pos.into(),
+        time,
+        vel: vel.into(),
+        prey: sums.prey,
+        threat: sums.threat.into(),
+        prey_dist: sums.prey_dist,
+    }
+}
+
+// The fish sorted into the globals' grid, as boid_compute.wgsl reads it: where each cell's fish start
+// (and where the last cell's end), then the fish's indices cell by cell, then each fish's cell
+pub fn grid(globals: &StepGlobals, boids: &[StepIn]) -> Vec<u32> {
+    let num_cells = globals.num_cells();
+    let cells: Vec<u32> = boids.iter().map(|boid| globals.cell_index(boid.pos)).collect();
+
+    let mut grid = vec![0; num_cells + 1 + boids.len() * 2];
+    for cell in cells.iter() {
+        grid[*cell as usize + 1] += 1;
+    }
+    for c in 0..num_cells {
+        grid[c + 1] += grid[c];
+    }
+
+    // in order within each cell, so the CPU and GPU add up the neighbours in the same order
+    let mut next: Vec<u32> = grid[..num_cells].to_vec();
+    let (_starts, rest) = grid.split_at_mut(num_cells + 1);
+    let (sorted, fish_cells) = rest.split_at_mut(boids.len());
+    for (i, cell) in cells.iter().enumerate() {
+        sorted[next[*cell as usize] as usize] = i as u32;
+        next[*cell as usize] += 1;
+    }
+    fish_cells.copy_from_slice(&cells);
+    grid
+}
+
+// The same neighbour search as boid_compute.wgsl, through the cells around each fish's own
+pub fn cpu_step(globals: &StepGlobals, species: &[StepSpecies], boids: &[StepIn]) -> Vec<StepOut> {
+    let grid = grid(globals, boids);
+    let (starts, rest) = grid.split_at(globals.num_cells() + 1);
+    let (sorted, fish_cells) = rest.split_at(boids.len());
+    let dims = globals.grid_dims.map(|dim| dim as i32);
+
+    boids.iter().enumerate().map(|(i, boid)| {
+        let boid_species = &species[boid.species as usize];
+        let own = fish_cells[i] as i32;
+        let own = [own % dims[0], own / dims[0] % dims[1], own / (dims[0] * dims[1])];
+
+        let mut sums = Sums::new(boid);
+        for z in own[2] - 1..=own[2] + 1 {
+            for y in own[1] - 1..=own[1] + 1 {
+                for x in own[0] - 1..=own[0] + 1 {
+                    if x < 0 || y < 0 || z < 0 || x >= dims[0] || y >= dims[1] || z >= dims[2] {
+                        continue;
+                    }
+                    let cell = (x + dims[0] * (y + dims[1] * z)) as usize;
+                    for &j in &sorted[starts[cell] as usize..starts[cell + 1] as usize] {
+                        if i != j as usize {
+                            sums.see(boid, boid_species, &boids[j as usize], j as usize, globals.avoidance_radius);
+                        }
+                    }
+                }
+            }
+        }
+
+        move_boid(boid, boid_species, &sums, globals.delta)
+    }).collect()
+}
+
+// The biggest difference in where the fish moved and how fast it goes, for comparing the CPU and GPU steps.
+// The positions are compared by how far they moved, so fish far from the origin don't lose precision.
+pub fn compare(boids: &[StepIn], a: &[StepOut], b: &[StepOut]) -> f32 {
+    boids.iter().zip(a.iter().zip(b.iter())).map(|(boid, (a, b))| {
+        let start = cgmath::Vector3::from(boid.pos);
+        let moved_a = cgmath::Vector3::from(a.pos) - start;
+        let moved_b = cgmath::Vector3::from(b.pos) - start;
+        let vel_diff = (cgmath::Vector3::from(a.vel) - cgmath::Vector3::from(b.vel)).magnitude();
+        (moved_a - moved_b).magnitude().max(vel_diff)
+    }).fold(0.0, f32::max)
+}
@@ -1,10 +1,14 @@
-use crate::{chunk, world};
+use crate::chunk;
 use cgmath::SquareMatrix;
 
 const Z_NEAR: f32 = 2.0;
-const Z_FAR: f32 = chunk::CHUNK_SIZE as f32 * (world::VIEW_DIST + 1) as f32;
 const FOVY: f32 = 45.0;
 
+// Fully dark past FOG_NEAR of the far plane without the headlight, and past FOG_FAR with it
+// (were 20 and 45 back when the view distance was 4 chunks)
+const FOG_NEAR: f32 = 0.25;
+const FOG_FAR: f32 = 0.56;
+
 #[rustfmt::skip]
 pub const OPENGL_TO_WGPU_MATRIX: cgmath::Matrix4<f32> = cgmath::Matrix4::new(
     1.0, 0.0, 0.0, 0.0,
@@ -19,18 +23,22 @@ pub struct Camera {
     pub target: cgmath::Point3<f32>,
     pub up: cgmath::Vector3<f32>,
     pub aspect: f32,
+    z_far: f32,
 
     uniform: CameraUniform,
 }
 impl Camera {
-    pub fn new(config: &wgpu::SurfaceConfiguration) -> Self {
+    // view_dist is in chunks
+    pub fn new(config: &wgpu::SurfaceConfiguration, view_dist: i32) -> Self {
+        let z_far = chunk::CHUNK_SIZE as f32 * (view_dist + 1) as f32;
         Self {
             eye: cgmath::Point3::new(0.0, 0.0, 0.0),
             target: cgmath::Point3::new(1.0, 0.0, 0.0),
             up: cgmath::Vector3::unit_z(),
             aspect: config.width as f32 / config.height as f32,
+            z_far,
 
-            uniform: CameraUniform::new(),
+            uniform: CameraUniform::new(z_far),
         }
     }
 
@@ -48,21 +56,27 @@ impl Camera {
     pub fn set_fog_color(&mut self, color: [f32; 3]) { self.uniform.fog_color = color; }
     pub fn set_sub_pos(&mut self, pos: [f32; 3]) { self.uniform.sub_pos = pos; }
     pub fn set_sub_dir(&mut self, dir: [f32; 3]) { self.uniform.sub_dir = dir; }
+    pub fn set_step_alpha(&mut self, alpha: f32) { self.uniform.step_alpha = alpha; }
 
-    fn build_view_projection_matrix(&self) -> cgmath::Matrix4<f32> {
-        let view = cgmath::Matrix4::look_at_rh(self.eye, self.target, self.up);
-        let proj = cgmath::perspective(cgmath::Deg(FOVY), self.aspect, Z_NEAR, Z_FAR);
+    fn build_view_projection_matrix(&self, eye: cgmath::Point3<f32>, target: cgmath::Point3<f32>, up: cgmath::Vector3<f32>) -> cgmath::Matrix4<f32> {
+        let view = cgmath::Matrix4::look_at_rh(eye, target, up);
+        let proj = cgmath::perspective(cgmath::Deg(FOVY), self.aspect, Z_NEAR, self.z_far);
         OPENGL_TO_WGPU_MATRIX * (proj * view)
     }
 
     pub fn chunk_generation_frustum_matrix(&self, fovy: f32) -> cgmath::Matrix4<f32> {
         let view = cgmath::Matrix4::look_at_rh(self.eye, self.target, self.up);
-        let proj = cgmath::perspective(cgmath::Deg(fovy), self.aspect, Z_NEAR, Z_FAR);
+        let proj = cgmath::perspective(cgmath::Deg(fovy), self.aspect, Z_NEAR, self.z_far);
         OPENGL_TO_WGPU_MATRIX * (proj * view)
     }
 
     pub fn update_uniform(&mut self) {
-        self.uniform.view_proj = self.build_view_projection_matrix().into();
+        self.uniform.view_proj = self.build_view_projection_matrix(self.eye, self.target, self.up).into();
+    }
+
+    // Renders from another view (the debug camera) without moving eye/target/up
+    pub fn update_uniform_from(&mut self, eye: cgmath::Point3<f32>, target: cgmath::Point3<f32>, up: cgmath::Vector3<f32>) {
+        self.uniform.view_proj = self.build_view_projection_matrix(eye, target, up).into();
     }
 }
 
@@ -71,22 +85,23 @@ impl Camera {
 pub struct CameraUniform {
     view_proj: [[f32; 4]; 4],
     fog_color: [f32; 3],
-    _padding1: f32,
+    fog_near: f32,
     sub_pos: [f32; 3],
-    _padding2: f32,
+    fog_far: f32,
     sub_dir: [f32; 3],
-    _padding3: f32,
+    // how far between the last two simulation steps the frame is
+    step_alpha: f32,
 }
 impl CameraUniform {
-    fn new() -> Self {
+    fn new(z_far: f32) -> Self {
         Self {
             view_proj: cgmath::Matrix4::identity().into(),
             fog_color: [0.0, 0.0, 0.0],
-            _padding1: 0.0,
+            fog_near: z_far * FOG_NEAR,
             sub_pos: [0.0, 0.0, 0.0],
-            _padding2: 0.0,
+            fog_far: z_far * FOG_FAR,
             sub_dir: [0.0, 0.0, 0.0],
-            _padding3: 0.0,
+            step_alpha: 0.0,
         }
     }
 }
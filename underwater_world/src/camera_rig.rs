@@ -0,0 +1,270 @@
+use crate::{camera, sub};
+use cgmath::{EuclideanSpace, InnerSpace};
+
+// Chase: behind and above the sub, lagging a little behind it
+const TARGET_DOWN: f32 = 0.6;
+const HORIZONTAL_OFFSET: f32 = 7.0;
+const VERTICAL_OFFSET: f32 = 6.0;
+const CAMERA_FOLLOW_SPEED: f32 = 10.0;
+
+// Cockpit: at the front of the hull, Z_NEAR clips the rest of the sub out of view
+const COCKPIT_FORWARD: f32 = 1.6;
+const COCKPIT_UP: f32 = 0.3;
+
+// Orbit: drag with the left mouse button to rotate, scroll to zoom
+const ORBIT_SENSITIVITY: f32 = 0.006;
+const ORBIT_ZOOM_STEP: f32 = 1.5;
+const ORBIT_MIN_DIST: f32 = 4.0;
+const ORBIT_MAX_DIST: f32 = 40.0;
+const ORBIT_START_DIST: f32 = 12.0;
+const ORBIT_MAX_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.05;
+
+// Cinematic: a loop around the sub (in world axes, so it doesn't turn with the sub)
+#[rustfmt::skip]
+const CINEMATIC_PATH: [[f32; 3]; 6] = [
+    [ 14.0,   0.0, 5.0],
+    [  6.0,  12.0, 1.0],
+    [ -8.0,  11.0, 8.0],
+    [-15.0,  -2.0, 3.0],
+    [ -5.0, -13.0, 9.0],
+    [  9.0, -10.0, 2.0],
+];
+// path points per second
+const CINEMATIC_SPEED: f32 = 0.08;
+// looks slightly ahead of the sub
+const CINEMATIC_LEAD: f32 = 3.0;
+
+// Seconds to blend from the old rig to the new one
+const BLEND_TIME: f32 = 1.0;
+
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RigKind {
+    Chase,
+    Cockpit,
+    Orbit,
+    Cinematic,
+}
+impl RigKind {
+    fn next(self) -> Self {
+        match self {
+            RigKind::Chase => RigKind::Cockpit,
+            RigKind::Cockpit => RigKind::Orbit,
+            RigKind::Orbit => RigKind::Cinematic,
+            RigKind::Cinematic => RigKind::Chase,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            RigKind::Chase => "CHASE",
+            RigKind::Cockpit => "COCKPIT",
+            RigKind::Orbit => "ORBIT",
+            RigKind::Cinematic => "CINEMATIC",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Pose {
+    eye: cgmath::Point3<f32>,
+    target: cgmath::Point3<f32>,
+    up: cgmath::Vector3<f32>,
+}
+impl Pose {
+    fn from_camera(camera: &camera::Camera) -> Self {
+        Self {
+            eye: camera.eye,
+            target: camera.target,
+            up: camera.up,
+        }
+    }
+
+    fn lerp(&self, other: &Pose, t: f32) -> Self {
+        Self {
+            eye: self.eye + (other.eye - self.eye) * t,
+            target: self.target + (other.target - self.target) * t,
+            up: (self.up + (other.up - self.up) * t).normalize(),
+        }
+    }
+}
+
+// The camera rigs and which one drives the camera
+pub struct CameraRigs {
+    kind: RigKind,
+
+    // smoothed every frame, even when another rig is selected, so switching back doesn't swoop in
+    chase: Pose,
+
+    orbit_yaw: f32,
+    orbit_pitch: f32,
+    orbit_dist: f32,
+    orbit_dragging: bool,
+    last_cursor: Option<(f64, f64)>,
+
+    // position along CINEMATIC_PATH, in path points
+    cinematic_t: f32,
+
+    // the pose when the rig was switched, and how far the blend away from it is (0 to 1)
+    blend_from: Pose,
+    blend: f32,
+}
+
+impl CameraRigs {
+    pub fn new(camera: &camera::Camera) -> Self {
+        Self {
+            kind: RigKind::Chase,
+            chase: Pose::from_camera(camera),
+            orbit_yaw: 0.0,
+            orbit_pitch: 0.0,
+            orbit_dist: ORBIT_START_DIST,
+            orbit_dragging: false,
+            last_cursor: None,
+            cinematic_t: 0.0,
+            blend_from: Pose::from_camera(camera),
+            blend: 1.0,
+        }
+    }
+
+    pub fn kind(&self) -> RigKind { self.kind }
+
+    // Cycles to the next rig, blending over from wherever the camera is now
+    pub fn next_rig(&mut self, camera: &camera::Camera, sub: &sub::SubPose) {
+        self.kind = self.kind.next();
+        self.blend_from = Pose::from_camera(camera);
+        self.blend = 0.0;
+
+        if self.kind == RigKind::Orbit {
+            // start orbiting from the current view direction so the blend is short
+            let offset = camera.eye.to_vec() - sub.pos();
+            if offset.magnitude2() > 0.0 {
+                self.orbit_yaw = offset.y.atan2(offset.x);
+                self.orbit_pitch = (offset.z / offset.magnitude()).asin().clamp(-ORBIT_MAX_PITCH, ORBIT_MAX_PITCH);
+                self.orbit_dist = offset.magnitude().clamp(ORBIT_MIN_DIST, ORBIT_MAX_DIST);
+            }
+        }
+    }
+
+    pub fn process_events(&mut self, event: &winit::event::WindowEvent) -> bool {
+        if self.kind != RigKind::Orbit { return false; }
+
+        match event {
+            winit::event::WindowEvent::MouseInput {
+                state,
+                button: winit::event::MouseButton::Left,
+                ..
+            } => {
+                self.orbit_dragging = *state == winit::event::ElementState::Pressed;
+                true
+            }
+            winit::event::WindowEvent::CursorMoved { position, .. } => {
+                if let (true, Some(last)) = (self.orbit_dragging, self.last_cursor) {
+                    self.orbit_yaw -= (position.x - last.0) as f32 * ORBIT_SENSITIVITY;
+                    self.orbit_pitch += (position.y - last.1) as f32 * ORBIT_SENSITIVITY;
+                    self.orbit_pitch = self.orbit_pitch.clamp(-ORBIT_MAX_PITCH, ORBIT_MAX_PITCH);
+                }
+                self.last_cursor = Some((position.x, position.y));
+                true
+            }
+            winit::event::WindowEvent::CursorLeft { .. } => {
+                self.last_cursor = None;
+                true
+            }
+            winit::event::WindowEvent::MouseWheel { delta, .. } => {
+                let steps = match delta {
+                    winit::event::MouseScrollDelta::LineDelta(_, y) => *y,
+                    // roughly one line per 50 pixels
+                    winit::event::MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 50.0,
+                };
+                self.orbit_dist = (self.orbit_dist - steps * ORBIT_ZOOM_STEP).clamp(ORBIT_MIN_DIST, ORBIT_MAX_DIST);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn update(&mut self, sub: &sub::SubPose, camera: &mut camera::Camera, delta: f32) {
+        self.update_chase(sub, delta);
+        if self.kind == RigKind::Cinematic {
+            self.cinematic_t = (self.cinematic_t + CINEMATIC_SPEED * delta) % CINEMATIC_PATH.len() as f32;
+        }
+
+        let goal = match self.kind {
+            RigKind::Chase => self.chase,
+            RigKind::Cockpit => cockpit_pose(sub),
+            RigKind::Orbit => self.orbit_pose(sub),
+            RigKind::Cinematic => self.cinematic_pose(sub),
+        };
+
+        self.blend = (self.blend + delta / BLEND_TIME).min(1.0);
+        // smoothstep so the blend eases in and out
+        let t = self.blend * self.blend * (3.0 - 2.0 * self.blend);
+        let pose = self.blend_from.lerp(&goal, t);
+
+        camera.eye = pose.eye;
+        camera.target = pose.target;
+        camera.up = pose.up;
+    }
+
+    fn update_chase(&mut self, sub: &sub::SubPose, delta: f32) {
+        let eye_goal = sub.pos() - sub.bearing() * HORIZONTAL_OFFSET + sub.up() * VERTICAL_OFFSET;
+        let eye_diff = eye_goal - self.chase.eye.to_vec();
+        self.chase.eye += eye_diff * delta * CAMERA_FOLLOW_SPEED;
+
+        let target_goal = eye_goal + sub.bearing() - sub.up() * TARGET_DOWN;
+        let target_diff = target_goal - self.chase.target.to_vec();
+        self.chase.target += target_diff * delta * CAMERA_FOLLOW_SPEED;
+
+        let up_diff = sub.up() - self.chase.up;
+        self.chase.up += up_diff * delta * CAMERA_FOLLOW_SPEED;
+    }
+
+    fn orbit_pose(&self, sub: &sub::SubPose) -> Pose {
+        let offset = cgmath::Vector3::new(
+            self.orbit_pitch.cos() * self.orbit_yaw.cos(),
+            self.orbit_pitch.cos() * self.orbit_yaw.sin(),
+            self.orbit_pitch.sin(),
+        ) * self.orbit_dist;
+
+        Pose {
+            eye: cgmath::Point3::from_vec(sub.pos() + offset),
+            target: cgmath::Point3::from_vec(sub.pos()),
+            up: cgmath::Vector3::unit_z(),
+        }
+    }
+
+    fn cinematic_pose(&self, sub: &sub::SubPose) -> Pose {
+        let offset = catmull_rom_loop(&CINEMATIC_PATH, self.cinematic_t);
+
+        Pose {
+            eye: cgmath::Point3::from_vec(sub.pos() + offset),
+            target: cgmath::Point3::from_vec(sub.pos() + sub.bearing() * CINEMATIC_LEAD),
+            up: cgmath::Vector3::unit_z(),
+        }
+    }
+}
+
+fn cockpit_pose(sub: &sub::SubPose) -> Pose {
+    let eye = sub.pos() + sub.bearing() * COCKPIT_FORWARD + sub.up() * COCKPIT_UP;
+    Pose {
+        eye: cgmath::Point3::from_vec(eye),
+        target: cgmath::Point3::from_vec(eye + sub.bearing()),
+        up: sub.up(),
+    }
+}
+
+// Closed Catmull-Rom spline through the points, t goes from 0 to points.len()
+fn catmull_rom_loop(points: &[[f32; 3]], t: f32) -> cgmath::Vector3<f32> {
+    let len = points.len();
+    let i = t.floor() as usize;
+    let f = t - t.floor();
+    let p = |offset: usize| cgmath::Vector3::from(points[(i + offset) % len]);
+
+    let (p0, p1, p2, p3) = (p(len - 1), p(0), p(1), p(2));
+    let f2 = f * f;
+    let f3 = f2 * f;
+    (p1 * 2.0
+        + (p2 - p0) * f
+        + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * f2
+        + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * f3) * 0.5
+}
@@ -1,10 +1,9 @@
-use crate::{draw, marching_table, perlin_util, util, world};
+use crate::{density, draw, marching_table, util, world};
 use std::collections::HashMap;
 use wgpu::util::DeviceExt;
 
 pub const CHUNK_SIZE: usize = 16;
 pub const INTERNAL_SIZE: usize = 12;
-const SIZE_SCALE: f32 = CHUNK_SIZE as f32 / INTERNAL_SIZE as f32;
 
 pub const PERLIN_OCTAVES: u32 = 3;
 pub const ISO_LEVEL: f32 = -0.1;
@@ -14,12 +13,49 @@ pub const ADJ_Z_MOD: f32 = 0.25;
 pub const MIN_HUE: f32 = -150.0;
 pub const MAX_HUE: f32 = 60.0;
 pub const SATURATION: f32 = 0.6;
-pub const BASE_VALUE: f32 = 0.4;
+pub const BASE_VALUE: f32 = 0.5;
 
 const X_GENERATION_STEP_ISO: i32 = 13;
-const X_GENERATION_STEP_MESH: i32 = 4;
+const X_GENERATION_STEP_MESH: i32 = 6;
+
+// Coarse chunks hang a skirt this many of their cells down into the wall along
+// every edge on a chunk face, covering the cracks next to finer neighbours
+const SKIRT_DEPTH_CELLS: f32 = 1.0;
+const FACE_EPSILON: f32 = 0.001;
+
+// How the normals are worked out on the chunk's faces, `world.borders` in the settings file
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Borders {
+    // Samples one extra iso layer past each face so normals at the borders use the
+    // same central differences as the neighbouring chunk, hiding the seams
+    #[default]
+    Seamless = 0,
+    // Only the chunk's own samples, one sided differences on the faces (cheaper, but the seams show)
+    Local = 1,
+}
+impl Borders {
+    // iso layers sampled past each face
+    fn iso_border(&self) -> usize {
+        match self {
+            Borders::Seamless => 1,
+            Borders::Local => 0,
+        }
+    }
+}
 
-const ISO_LEN: usize = INTERNAL_SIZE + 1;
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Lod {
+    Full = 0,
+    Half = 1,
+    Quarter = 2,
+}
+impl Lod {
+    // cells per side: 12, 6 or 3
+    pub fn cells(&self) -> usize { INTERNAL_SIZE >> (*self as usize) }
+    fn size_scale(&self) -> f32 { CHUNK_SIZE as f32 / self.cells() as f32 }
+    fn iso_len(&self, borders: Borders) -> usize { self.cells() + 1 + 2 * borders.iso_border() }
+}
 
 enum BuildState {
     Done,
@@ -32,34 +68,40 @@ impl BuildState {
 
 struct Build {
     chunk_offset: [i32; 3],
+    lod: Lod,
+    borders: Borders,
     
-    verts: Vec<draw::VertColor>,
-    vert_pairs: Vec<([usize; 3], [usize; 3])>,
+    verts: Vec<draw::VertColorNormal>,
+    // vert index for each cube edge, see edge_idx
+    edge_verts: Vec<Option<u16>>,
 
     inds: Vec<u16>,
-    num_inds: usize,
 
     tris: HashMap<(usize, usize, usize), Vec<util::Tri>>,
     isos: Vec<f32>,
     x: i32,
 }
 impl Build {
-    fn new(chunk_offset: [i32; 3]) -> Self {
+    fn new(chunk_offset: [i32; 3], lod: Lod, borders: Borders) -> Self {
+        let iso_len = lod.iso_len(borders);
         Self {
             chunk_offset,
+            lod,
+            borders,
             verts: Vec::new(),
-            vert_pairs: Vec::new(),
+            edge_verts: Vec::new(),
 
             inds: Vec::new(),
-            num_inds: 0,
 
             tris: HashMap::new(),
-            isos: Vec::with_capacity(ISO_LEN * ISO_LEN * ISO_LEN),
+            isos: Vec::with_capacity(iso_len * iso_len * iso_len),
             x: 0,
         }
     }
     
     fn start_mesh(&mut self) {
+        let corners = self.lod.cells() + 1;
+        self.edge_verts = vec![None; corners * corners * corners * 3];
         self.x = 0;
     }
 
@@ -67,79 +109,87 @@ impl Build {
         self.isos.clear();
         self.isos.shrink_to(0);
 
-        self.verts.clear();
-        self.verts.shrink_to(0);
-
-        self.inds.clear();
-        self.inds.shrink_to(0);
+        self.edge_verts.clear();
+        self.edge_verts.shrink_to(0);
 
         self.x = -1;
     }
 }
 
-pub struct Chunk {
-	verts_buffer: Option<wgpu::Buffer>,
-    inds_buffer: Option<wgpu::Buffer>, 
-   
+// CPU side result of meshing a chunk, no GPU resources needed
+pub struct ChunkMesh {
+    pub verts: Vec<draw::VertColorNormal>,
+    pub inds: Vec<u16>,
+    pub tris: HashMap<(usize, usize, usize), Vec<util::Tri>>,
+    pub lod: Lod,
+    pub borders: Borders,
+}
+impl ChunkMesh {
+    pub fn is_blank(&self) -> bool { self.inds.is_empty() }
+
+    // the inds without the skirts, which are added after every surface triangle
+    pub fn surface_inds(&self) -> &[u16] {
+        let num_tris: usize = self.tris.values().map(|tris| tris.len()).sum();
+        &self.inds[..num_tris * 3]
+    }
+}
+
+pub struct ChunkMesher {
     build: Build,
     build_state: BuildState,
 }
 
-impl Chunk {
-	pub fn new(pos: (i32, i32, i32)) -> Self {
-        let chunk_offset = [
-			pos.0 * CHUNK_SIZE as i32,
-			pos.1 * CHUNK_SIZE as i32,
-			pos.2 * CHUNK_SIZE as i32,
-		];
-
+impl ChunkMesher {
+	pub fn new(pos: (i32, i32, i32), lod: Lod, borders: Borders) -> Self {
         Self {
-            verts_buffer: None,
-            inds_buffer: None,
-            
-            build: Build::new(chunk_offset),
+            build: Build::new(chunk_offset(pos), lod, borders),
             build_state: BuildState::new(),
         }
     }
 
-    fn build_iso(&mut self, perlin: &noise::Perlin) -> bool {
+    fn build_iso(&mut self, density: &dyn density::Density) -> bool {
+        let cells = self.build.lod.cells();
+        let iso_len = self.build.lod.iso_len(self.build.borders);
+        let iso_border = self.build.borders.iso_border() as i32;
+
         for _ in 0..X_GENERATION_STEP_ISO {
-            let local_perlin_x = self.build.x as f64 * SIZE_SCALE as f64;
-            let perlin_x = (local_perlin_x + self.build.chunk_offset[0] as f64) / CHUNK_SIZE as f64;
+            let perlin_x = iso_sample_coord(self.build.x - iso_border, self.build.chunk_offset[0], cells);
 
-            for y in 0..(INTERNAL_SIZE + 1) {
-                let local_perlin_y = y as f64 * SIZE_SCALE as f64;
-                let perlin_y = (local_perlin_y + self.build.chunk_offset[1] as f64) / CHUNK_SIZE as f64;
+            for y in 0..iso_len {
+                let perlin_y = iso_sample_coord(y as i32 - iso_border, self.build.chunk_offset[1], cells);
 
-                for z in 0..(INTERNAL_SIZE + 1) {
-                    let local_perlin_z = z as f64 * SIZE_SCALE as f64;
-                    let perlin_z = (local_perlin_z + self.build.chunk_offset[2] as f64) / CHUNK_SIZE as f64;
+                for z in 0..iso_len {
+                    let perlin_z = iso_sample_coord(z as i32 - iso_border, self.build.chunk_offset[2], cells);
 
-                    let iso = perlin_util::iso_at(perlin, perlin_x, perlin_y, perlin_z);
+                    let iso = density.iso_at(perlin_x, perlin_y, perlin_z);
                     self.build.isos.push(iso);
                 }
             }
     
             self.build.x += 1;
 
-            if self.build.x == ISO_LEN as i32 { break; }
+            if self.build.x == iso_len as i32 { break; }
         }
 
-        self.build.x == ISO_LEN as i32
+        self.build.x == iso_len as i32
     }
 
     fn early_blank_check(&self) -> bool {
         self.build.isos.iter().all(|iso| *iso > ISO_LEVEL)
     }
 
-    fn build_mesh(&mut self) -> bool {
+    fn build_mesh(&mut self, density: &dyn density::Density) -> bool {
         let chunk_offset = self.build.chunk_offset;
+        let cells = self.build.lod.cells();
+        let iso_len = self.build.lod.iso_len(self.build.borders);
+        let iso_border = self.build.borders.iso_border();
+        let size_scale = self.build.lod.size_scale();
 
         for _ in 0..X_GENERATION_STEP_MESH {
             let x = self.build.x as usize;
 
-            for y in 0..INTERNAL_SIZE {
-                for z in 0..INTERNAL_SIZE {
+            for y in 0..cells {
+                for z in 0..cells {
 
                     let cube_corners = [
                         [x, y, z],
@@ -154,7 +204,7 @@ impl Chunk {
 
                     let mut triangulation_idx = 0;
                     for (i, cube_corner) in cube_corners.iter().enumerate() {
-                        let iso_idx = corner_to_iso_idx(*cube_corner);
+                        let iso_idx = corner_to_iso_idx(*cube_corner, iso_border, iso_len);
                         let iso = self.build.isos[iso_idx];
                         if iso < ISO_LEVEL {
                             triangulation_idx |= 1 << i;
@@ -183,18 +233,18 @@ impl Chunk {
                         let corner_b = cube_corners[corner_b_idx];
 
                         let scaled_corner_a = [
-                            corner_a[0] as f32 * SIZE_SCALE,
-                            corner_a[1] as f32 * SIZE_SCALE,
-                            corner_a[2] as f32 * SIZE_SCALE,
+                            corner_a[0] as f32 * size_scale,
+                            corner_a[1] as f32 * size_scale,
+                            corner_a[2] as f32 * size_scale,
                         ];
                         let scaled_corner_b = [
-                            corner_b[0] as f32 * SIZE_SCALE,
-                            corner_b[1] as f32 * SIZE_SCALE,
-                            corner_b[2] as f32 * SIZE_SCALE,
+                            corner_b[0] as f32 * size_scale,
+                            corner_b[1] as f32 * size_scale,
+                            corner_b[2] as f32 * size_scale,
                         ];
 
-                        let iso_idx_a = corner_to_iso_idx(corner_a);
-                        let iso_idx_b = corner_to_iso_idx(corner_b);
+                        let iso_idx_a = corner_to_iso_idx(corner_a, iso_border, iso_len);
+                        let iso_idx_b = corner_to_iso_idx(corner_b, iso_border, iso_len);
 
                         let iso_a = self.build.isos[iso_idx_a];
                         let iso_b = self.build.isos[iso_idx_b];
@@ -212,37 +262,40 @@ impl Chunk {
                             scaled_corner_a[2] + t * corner_diff[2],
                         ];
 
+                        let world_x = middle[0] + chunk_offset[0] as f32;
+                        let world_y = middle[1] + chunk_offset[1] as f32;
                         let world_z = middle[2] + chunk_offset[2] as f32;
                         let world_z_ratio = world_z / CHUNK_SIZE as f32;
                         let mix_ratio = util::create_mix_ratio(world::MIN_Z as f32, world::MAX_Z as f32, world_z_ratio);
 
-                        let value_intensity = (corner_b_idx % 3) as f32 / 9.0;
-                        let hue = MIN_HUE + (MAX_HUE - MIN_HUE) * mix_ratio;
-                        let rgb_color = util::hsv_to_rgb(hue as f32, SATURATION, BASE_VALUE + value_intensity);
+                        let (min_hue, max_hue) = density
+                            .biome_at(world_x as f64 / CHUNK_SIZE as f64, world_y as f64 / CHUNK_SIZE as f64)
+                            .hue_range();
+
+                        let hue = min_hue + (max_hue - min_hue) * mix_ratio;
+                        let rgb_color = util::hsv_to_rgb(hue, SATURATION, BASE_VALUE);
                         let srgb_color = util::to_srgb(rgb_color);
 
-                        let vert = draw::VertColor::new(
-                            [
-                                middle[0] + chunk_offset[0] as f32,
-                                middle[1] + chunk_offset[1] as f32,
-                                world_z,
-                            ],
-                            srgb_color,
-                        );
-
-                        let maybe_ind = self.build.vert_pairs.iter().position(|(a_, b_)| *a_ == corner_a && *b_ == corner_b);
-                        let ind = match maybe_ind {
+                        let pos = [world_x, world_y, world_z];
+
+                        let edge_idx = edge_idx(corner_a, corner_b, cells);
+                        let ind = match self.build.edge_verts[edge_idx] {
                             Some(ind) => ind,
                             None => {
-                                let ind = self.build.vert_pairs.len();
-                                self.build.vert_pairs.push((corner_a, corner_b));
-                                self.build.verts.push(vert);
+                                // iso increases towards open water, so the gradient points out of the wall
+                                let gradient_a = self.iso_gradient(corner_a);
+                                let gradient_b = self.iso_gradient(corner_b);
+                                let normal = util::safe_normalize(gradient_a + (gradient_b - gradient_a) * t);
+
+                                let ind = self.build.verts.len() as u16;
+                                self.build.edge_verts[edge_idx] = Some(ind);
+                                self.build.verts.push(draw::VertColorNormal::new(pos, srgb_color, normal.into()));
                                 ind
                             }
                         };
-                        self.build.inds.push(ind as u16);
+                        self.build.inds.push(ind);
 
-                        current_tri.push(vert.pos);
+                        current_tri.push(pos);
                         if i % 3 == 2 {
                             let tri = util::Tri::new([current_tri[0], current_tri[1], current_tri[2]]);
                             pos_tris.push(tri);
@@ -255,23 +308,90 @@ impl Chunk {
 
             self.build.x += 1;
 
-            if self.build.x == INTERNAL_SIZE as i32 { break; }
+            if self.build.x == cells as i32 { break; }
         }
 
-        self.build.num_inds = self.build.inds.len();
+        self.build.x == cells as i32
+    }
+
+    // central differences, only one sided on the faces if there is no border layer
+    fn iso_gradient(&self, corner: [usize; 3]) -> cgmath::Vector3<f32> {
+        let mut gradient = [0.0; 3];
+        let iso_len = self.build.lod.iso_len(self.build.borders);
+        let iso_border = self.build.borders.iso_border();
+
+        let padded = [corner[0] + iso_border, corner[1] + iso_border, corner[2] + iso_border];
+
+        for (axis, g) in gradient.iter_mut().enumerate() {
+            let mut low = padded;
+            let mut high = padded;
+            if padded[axis] > 0 { low[axis] -= 1; }
+            if padded[axis] < iso_len - 1 { high[axis] += 1; }
+
+            let iso_low = self.build.isos[padded_to_iso_idx(low, iso_len)];
+            let iso_high = self.build.isos[padded_to_iso_idx(high, iso_len)];
+            *g = (iso_high - iso_low) / (high[axis] - low[axis]) as f32;
+        }
+
+        cgmath::Vector3::from(gradient)
+    }
+
+    // Every triangle edge lying in one of the chunk's faces is on the border with a
+    // neighbour, extrude it against the normal so there is no gap to see through
+    fn build_skirts(&mut self) {
+        let chunk_size = CHUNK_SIZE as f32;
+        let skirt_depth = SKIRT_DEPTH_CELLS * self.build.lod.size_scale();
+        let offset = self.build.chunk_offset;
+
+        let num_inds = self.build.inds.len();
+        for tri_start in (0..num_inds).step_by(3) {
+            for edge in 0..3 {
+                let ind_a = self.build.inds[tri_start + edge];
+                let ind_b = self.build.inds[tri_start + (edge + 1) % 3];
+                let vert_a = self.build.verts[ind_a as usize];
+                let vert_b = self.build.verts[ind_b as usize];
+
+                let on_face = (0..3).any(|axis| {
+                    let local_a = vert_a.pos[axis] - offset[axis] as f32;
+                    let local_b = vert_b.pos[axis] - offset[axis] as f32;
+                    let on_low = local_a.abs() < FACE_EPSILON && local_b.abs() < FACE_EPSILON;
+                    let on_high = (local_a - chunk_size).abs() < FACE_EPSILON && (local_b - chunk_size).abs() < FACE_EPSILON;
+                    on_low || on_high
+                });
+                if !on_face { continue; }
+
+                let mut skirt_a = vert_a;
+                let mut skirt_b = vert_b;
+                for axis in 0..3 {
+                    skirt_a.pos[axis] -= vert_a.normal[axis] * skirt_depth;
+                    skirt_b.pos[axis] -= vert_b.normal[axis] * skirt_depth;
+                }
 
-        self.build.x == INTERNAL_SIZE as i32
+                let ind_skirt_a = self.build.verts.len() as u16;
+                let ind_skirt_b = ind_skirt_a + 1;
+                self.build.verts.push(skirt_a);
+                self.build.verts.push(skirt_b);
+
+                // both windings so it covers the crack from either side
+                self.build.inds.extend_from_slice(&[
+                    ind_a, ind_skirt_a, ind_skirt_b,
+                    ind_a, ind_skirt_b, ind_b,
+                    ind_a, ind_skirt_b, ind_skirt_a,
+                    ind_a, ind_b, ind_skirt_b,
+                ]);
+            }
+        }
     }
 
-    pub fn build_full(&mut self, perlin: &noise::Perlin, device: &wgpu::Device) {
-        while !self.build_partial(perlin, device) {}
+    pub fn build_full(&mut self, density: &dyn density::Density) {
+        while !self.build_partial(density) {}
     }
 
-    pub fn build_partial(&mut self, perlin: &noise::Perlin, device: &wgpu::Device) -> bool {
+    pub fn build_partial(&mut self, density: &dyn density::Density) -> bool {
         match self.build_state {
             BuildState::Done => true,
             BuildState::Iso => {
-                let finished = self.build_iso(perlin);
+                let finished = self.build_iso(density);
                 if finished {
                     let blank_check = self.early_blank_check();
                     if blank_check {
@@ -286,24 +406,11 @@ impl Chunk {
                 false
             },
             BuildState::Mesh => {
-                let finished = self.build_mesh();
+                let finished = self.build_mesh(density);
                 if finished {
-                    if self.build.num_inds > 0 {
-                        let verts_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                            label: Some(&format!("{:?} Chunk Vertex Buffer", self.build.chunk_offset)),
-                            contents: bytemuck::cast_slice(&self.build.verts),
-                            usage: wgpu::BufferUsages::VERTEX,
-                        });
-                        self.verts_buffer = Some(verts_buffer);
-
-                        let inds_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                            label: Some(&format!("{:?} Chunk Index Buffer", self.build.chunk_offset)),
-                            contents: bytemuck::cast_slice(&self.build.inds),
-                            usage: wgpu::BufferUsages::INDEX,
-                        });
-                        self.inds_buffer = Some(inds_buffer);
+                    if self.build.lod != Lod::Full {
+                        self.build_skirts();
                     }
-
                     self.build_state = BuildState::Done;
                     self.build.finish();
                 }
@@ -312,42 +419,272 @@ impl Chunk {
         }
     }
 
-    pub fn tris_around(&self, local_pos_percent: (f32, f32, f32), range: i32) -> Vec<util::Tri> {
-        let middle_x = (local_pos_percent.0 * INTERNAL_SIZE as f32).floor() as i32;
-        let middle_y = (local_pos_percent.1 * INTERNAL_SIZE as f32).floor() as i32;
-        let middle_z = (local_pos_percent.2 * INTERNAL_SIZE as f32).floor() as i32;
-
-        let start_x = (middle_x - range).max(0) as usize;
-        let start_y = (middle_y - range).max(0) as usize;
-        let start_z = (middle_z - range).max(0) as usize;
-
-        let end_x = (middle_x + range).min(INTERNAL_SIZE as i32) as usize;
-        let end_y = (middle_y + range).min(INTERNAL_SIZE as i32) as usize;
-        let end_z = (middle_z + range).min(INTERNAL_SIZE as i32) as usize;
-
-        let mut tris = Vec::new();
-
-        for x in start_x..=end_x {
-            for y in start_y..=end_y {
-                for z in start_z..=end_z {
-                    let key = (x, y, z);
-                    if let Some(chunk_tris) = self.build.tris.get(&key) {
-                        tris.extend_from_slice(chunk_tris);
-                    }
-                }
-            }
+    // only call once build_partial has returned true
+    pub fn into_mesh(self) -> ChunkMesh {
+        ChunkMesh {
+            verts: self.build.verts,
+            inds: self.build.inds,
+            tris: self.build.tris,
+            lod: self.build.lod,
+            borders: self.build.borders,
         }
+    }
+}
 
-        tris
+pub fn mesh_chunk(pos: (i32, i32, i32), lod: Lod, borders: Borders, density: &dyn density::Density) -> ChunkMesh {
+    let mut mesher = ChunkMesher::new(pos, lod, borders);
+    mesher.build_full(density);
+    mesher.into_mesh()
+}
+
+pub struct Chunk {
+	verts_buffer: Option<wgpu::Buffer>,
+    inds_buffer: Option<wgpu::Buffer>,
+
+    // kept on the CPU for wall checks and exporting
+    mesh: ChunkMesh,
+    // World's edit version when it was meshed, see World::is_stale
+    edit_version: u64,
+}
+
+impl Chunk {
+    pub fn new(pos: (i32, i32, i32), mesh: ChunkMesh, edit_version: u64, device: &wgpu::Device) -> Self {
+        let mut verts_buffer = None;
+        let mut inds_buffer = None;
+
+        if !mesh.is_blank() {
+            let chunk_offset = chunk_offset(pos);
+
+            verts_buffer = Some(device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some(&format!("{:?} Chunk Vertex Buffer", chunk_offset)),
+                contents: bytemuck::cast_slice(&mesh.verts),
+                usage: wgpu::BufferUsages::VERTEX,
+            }));
+
+            inds_buffer = Some(device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some(&format!("{:?} Chunk Index Buffer", chunk_offset)),
+                contents: bytemuck::cast_slice(&mesh.inds),
+                usage: wgpu::BufferUsages::INDEX,
+            }));
+        }
+
+        Self {
+            verts_buffer,
+            inds_buffer,
+
+            mesh,
+            edit_version,
+        }
+    }
+
+    pub fn tris_around(&self, local_pos_percent: (f32, f32, f32), range: i32) -> Vec<util::Tri> {
+        tris_around(&self.mesh.tris, self.mesh.lod, local_pos_percent, range)
     }
 
     pub fn not_blank(&self) -> bool { self.verts_buffer.is_some() }
     // only call if self is not blank
     pub fn verts_buffer_slice(&self) -> wgpu::BufferSlice { self.verts_buffer.as_ref().unwrap().slice(..) }
     pub fn inds_buffer_slice(&self) -> wgpu::BufferSlice { self.inds_buffer.as_ref().unwrap().slice(..) }
-    pub fn num_inds(&self) -> usize { self.build.num_inds }
+    pub fn num_inds(&self) -> usize { self.mesh.inds.len() }
+    pub fn mesh(&self) -> &ChunkMesh { &self.mesh }
+    pub fn lod(&self) -> Lod { self.mesh.lod }
+    pub fn edit_version(&self) -> u64 { self.edit_version }
 }
 
-fn corner_to_iso_idx(corner: [usize; 3]) -> usize {
-    corner[0] * ISO_LEN * ISO_LEN + corner[1] * ISO_LEN + corner[2]
-}
\ No newline at end of file
+fn chunk_offset(pos: (i32, i32, i32)) -> [i32; 3] {
+    [
+        pos.0 * CHUNK_SIZE as i32,
+        pos.1 * CHUNK_SIZE as i32,
+        pos.2 * CHUNK_SIZE as i32,
+    ]
+}
+
+// Integer math first so both chunks sharing a face sample the exact same points
+fn iso_sample_coord(local: i32, chunk_offset: i32, cells: usize) -> f64 {
+    let scaled_local = (local * CHUNK_SIZE as i32) as f64 / cells as f64;
+    (scaled_local + chunk_offset as f64) / CHUNK_SIZE as f64
+}
+
+// corner is in mesh cells, from 0 to Lod::cells
+fn corner_to_iso_idx(corner: [usize; 3], iso_border: usize, iso_len: usize) -> usize {
+    padded_to_iso_idx([corner[0] + iso_border, corner[1] + iso_border, corner[2] + iso_border], iso_len)
+}
+
+// Cube edges always join two corners that differ on one axis, so the lower corner
+// and that axis name the edge no matter which way round it was visited
+fn edge_idx(corner_a: [usize; 3], corner_b: [usize; 3], cells: usize) -> usize {
+    let low = [
+        corner_a[0].min(corner_b[0]),
+        corner_a[1].min(corner_b[1]),
+        corner_a[2].min(corner_b[2]),
+    ];
+    let axis = (0..3).find(|axis| corner_a[*axis] != corner_b[*axis]).unwrap();
+
+    let corners = cells + 1;
+    ((low[0] * corners + low[1]) * corners + low[2]) * 3 + axis
+}
+
+// padded includes the border layer, from 0 to iso_len - 1
+fn padded_to_iso_idx(padded: [usize; 3], iso_len: usize) -> usize {
+    padded[0] * iso_len * iso_len + padded[1] * iso_len + padded[2]
+}
+// Just the triangles of a chunk at full detail, which is what the sub and the fish collide with
+pub struct CollisionChunk {
+    tris: HashMap<(usize, usize, usize), Vec<util::Tri>>,
+}
+
+impl CollisionChunk {
+    pub fn new(mesh: ChunkMesh) -> Self {
+        assert_eq!(mesh.lod, Lod::Full, "collision is always at full detail");
+        Self { tris: mesh.tris }
+    }
+
+    // the same as meshing it again, as long as the terrain hasn't been edited since
+    pub fn from_chunk(chunk: &Chunk) -> Self {
+        assert_eq!(chunk.lod(), Lod::Full, "collision is always at full detail");
+        Self { tris: chunk.mesh.tris.clone() }
+    }
+
+    pub fn tris_around(&self, local_pos_percent: (f32, f32, f32), range: i32) -> Vec<util::Tri> {
+        tris_around(&self.tris, Lod::Full, local_pos_percent, range)
+    }
+}
+
+// range is in cells
+fn tris_around(
+    chunk_tris: &HashMap<(usize, usize, usize), Vec<util::Tri>>,
+    lod: Lod,
+    local_pos_percent: (f32, f32, f32),
+    range: i32,
+) -> Vec<util::Tri> {
+    let cells = lod.cells() as i32;
+
+    let middle_x = (local_pos_percent.0 * cells as f32).floor() as i32;
+    let middle_y = (local_pos_percent.1 * cells as f32).floor() as i32;
+    let middle_z = (local_pos_percent.2 * cells as f32).floor() as i32;
+
+    let start_x = (middle_x - range).max(0) as usize;
+    let start_y = (middle_y - range).max(0) as usize;
+    let start_z = (middle_z - range).max(0) as usize;
+
+    let end_x = (middle_x + range).min(cells) as usize;
+    let end_y = (middle_y + range).min(cells) as usize;
+    let end_z = (middle_z + range).min(cells) as usize;
+
+    let mut tris = Vec::new();
+
+    for x in start_x..=end_x {
+        for y in start_y..=end_y {
+            for z in start_z..=end_z {
+                let key = (x, y, z);
+                if let Some(cell_tris) = chunk_tris.get(&key) {
+                    tris.extend_from_slice(cell_tris);
+                }
+            }
+        }
+    }
+
+    tris
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // solid below a flat floor at height (in chunk units), open water above
+    struct Floor(f64);
+    impl density::Density for Floor {
+        fn iso_at(&self, _x: f64, _y: f64, z: f64) -> f32 { (z - self.0) as f32 + ISO_LEVEL }
+    }
+
+    struct Constant(f32);
+    impl density::Density for Constant {
+        fn iso_at(&self, _x: f64, _y: f64, _z: f64) -> f32 { self.0 }
+    }
+
+    fn tri_count(mesh: &ChunkMesh) -> usize {
+        mesh.tris.values().map(|tris| tris.len()).sum()
+    }
+
+    #[test]
+    fn flat_floor() {
+        // between two layers of corners so every column of cells crosses it once
+        let floor = 0.45;
+        let mesh = mesh_chunk((0, 0, 0), Lod::Full, Borders::Seamless, &Floor(floor));
+
+        let cells = Lod::Full.cells();
+        assert_eq!(tri_count(&mesh), cells * cells * 2);
+        assert_eq!(mesh.inds.len(), cells * cells * 2 * 3);
+        // shared along the cube edges, one per column of corners
+        assert_eq!(mesh.verts.len(), (cells + 1) * (cells + 1));
+
+        let floor_z = (floor * CHUNK_SIZE as f64) as f32;
+        for vert in &mesh.verts {
+            assert!((vert.pos[2] - floor_z).abs() < 1e-4, "{:?}", vert.pos);
+            assert!((vert.normal[2] - 1.0).abs() < 1e-4, "{:?}", vert.normal);
+        }
+        // wound to face the open water, for backface culling
+        for tri in mesh.tris.values().flatten() {
+            assert!(tri.normal.z > 1.0 - 1e-4, "{:?}", tri.normal);
+        }
+    }
+
+    #[test]
+    fn empty_and_solid() {
+        for iso in [ISO_LEVEL + 1.0, ISO_LEVEL - 1.0] {
+            for lod in [Lod::Full, Lod::Half, Lod::Quarter] {
+                for borders in [Borders::Seamless, Borders::Local] {
+                    let mesh = mesh_chunk((0, 0, 0), lod, borders, &Constant(iso));
+                    assert!(mesh.is_blank());
+                    assert!(mesh.verts.is_empty());
+                    assert_eq!(tri_count(&mesh), 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn partial_matches_full() {
+        let density = density::PerlinDensity::new(1);
+        for lod in [Lod::Full, Lod::Half, Lod::Quarter] {
+            let full = mesh_chunk((2, -1, -1), lod, Borders::Seamless, &density);
+
+            let mut mesher = ChunkMesher::new((2, -1, -1), lod, Borders::Seamless);
+            while !mesher.build_partial(&density) {}
+            let partial = mesher.into_mesh();
+
+            assert_eq!(full.inds, partial.inds);
+            assert_eq!(full.verts.len(), partial.verts.len());
+        }
+    }
+    #[test]
+    fn skirts_after_surface() {
+        let density = density::PerlinDensity::new(1);
+        for lod in [Lod::Full, Lod::Half, Lod::Quarter] {
+            let mesh = mesh_chunk((2, -1, -1), lod, Borders::Seamless, &density);
+            let surface_inds = mesh.surface_inds();
+
+            let surface_tris = mesh.tris.values().flatten().collect::<Vec<_>>();
+            assert_eq!(surface_inds.len(), surface_tris.len() * 3);
+            if lod == Lod::Full {
+                assert_eq!(surface_inds.len(), mesh.inds.len());
+            } else {
+                assert!(surface_inds.len() < mesh.inds.len());
+            }
+        }
+    }
+
+    // the border layer only changes the normals (and so the skirts), not where the surface is
+    #[test]
+    fn borders_keep_the_surface() {
+        let density = density::PerlinDensity::new(1);
+        let seamless = mesh_chunk((2, -1, -1), Lod::Full, Borders::Seamless, &density);
+        let local = mesh_chunk((2, -1, -1), Lod::Full, Borders::Local, &density);
+
+        assert!(!seamless.is_blank());
+        assert_eq!(seamless.inds, local.inds);
+        for (a, b) in seamless.verts.iter().zip(&local.verts) {
+            assert_eq!(a.pos, b.pos);
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{chunk, draw, util};
+use std::collections::HashMap;
+
+// Bump when the mesh format or the terrain generation changes, old entries are then ignored
+const CACHE_VERSION: u32 = 1;
+const MAGIC: &[u8; 4] = b"UWCC";
+
+#[cfg(not(target_arch = "wasm32"))]
+const CACHE_ARG: &str = "--chunk-cache";
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_CACHE_DIR: &str = "chunk_cache";
+#[cfg(not(target_arch = "wasm32"))]
+static TMP_COUNTER: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+#[cfg(target_arch = "wasm32")]
+const CACHE_QUERY_PARAM: &str = "chunk_cache";
+#[cfg(target_arch = "wasm32")]
+const DB_NAME: &str = "underwater_world_chunks";
+#[cfg(target_arch = "wasm32")]
+const DB_VERSION: u32 = 1;
+#[cfg(target_arch = "wasm32")]
+const STORE_NAME: &str = "chunks";
+
+
+// Built chunk meshes by density, seed, chunk position, LOD and borders, so coming back to an area skips meshing.
+// Native: one file per chunk in a directory, web: the same bytes in IndexedDB.
+// Only valid for the density the entries were built with, so it is opt in.
+pub struct ChunkCache {
+    #[cfg(not(target_arch = "wasm32"))]
+    dir: std::path::PathBuf,
+
+    // density id and seed, the start of every key
+    #[cfg(target_arch = "wasm32")]
+    key_prefix: String,
+    #[cfg(target_arch = "wasm32")]
+    open_request: web_sys::IdbOpenDbRequest,
+    #[cfg(target_arch = "wasm32")]
+    db: std::cell::RefCell<DbState>,
+    // creates the store the first time, kept alive as long as the request might call it
+    #[cfg(target_arch = "wasm32")]
+    _on_upgrade: wasm_bindgen::closure::Closure<dyn FnMut(web_sys::Event)>,
+    #[cfg(target_arch = "wasm32")]
+    warned: std::cell::Cell<bool>,
+}
+
+#[cfg(target_arch = "wasm32")]
+enum DbState {
+    Opening,
+    Open(web_sys::IdbDatabase),
+    Failed,
+}
+
+// IndexedDB only answers between frames, so on the web a chunk is looked up and then polled
+#[cfg(target_arch = "wasm32")]
+pub struct Lookup {
+    key: String,
+    lod: chunk::Lod,
+    borders: chunk::Borders,
+    // None until the database is open
+    request: std::cell::RefCell<Option<web_sys::IdbRequest>>,
+}
+
+#[cfg(target_arch = "wasm32")]
+pub enum Loaded {
+    Waiting,
+    Hit(chunk::ChunkMesh),
+    Miss,
+}
+
+impl ChunkCache {
+    // native: `--chunk-cache` on its own or with a dir, web: `?chunk_cache`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_env(density_id: Option<&str>, seed: u32) -> Option<Self> {
+        let dir = util::arg_value(CACHE_ARG).or_else(|| {
+            std::env::args().skip(1).any(|arg| arg == CACHE_ARG).then(|| DEFAULT_CACHE_DIR.to_string())
+        })?;
+        let Some(density_id) = density_id else {
+            log::warn!("This terrain has no cache id, so its chunks aren't cached");
+            return None;
+        };
+
+        let dir = std::path::Path::new(&dir).join(density_id).join(seed.to_string());
+        if let Err(e) = std::fs::create_dir_all(&dir) {
+            log::error!("Couldn't create chunk cache {}: {}", dir.display(), e);
+            return None;
+        }
+        log::info!("Caching chunks in {}", dir.display());
+
+        Some(Self { dir })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_env(density_id: Option<&str>, seed: u32) -> Option<Self> {
+        use wasm_bindgen::JsCast;
+
+        let window = web_sys::window()?;
+        let search = window.location().search().ok()?;
+        let params = web_sys::UrlSearchParams::new_with_str(&search).ok()?;
+        if !params.has(CACHE_QUERY_PARAM) { return None; }
+        let Some(density_id) = density_id else {
+            log::warn!("This terrain has no cache id, so its chunks aren't cached");
+            return None;
+        };
+
+        let factory = window.indexed_db().ok()??;
+        let open_request = factory.open_with_u32(DB_NAME, DB_VERSION).ok()?;
+
+        let upgrading = open_request.clone();
+        let on_upgrade = wasm_bindgen::closure::Closure::<dyn FnMut(web_sys::Event)>::new(move |_event: web_sys::Event| {
+            let Some(db) = upgrading.result().ok().and_then(|db| db.dyn_into::<web_sys::IdbDatabase>().ok()) else { return; };
+            if !db.object_store_names().contains(STORE_NAME) && db.create_object_store(STORE_NAME).is_err() {
+                log::error!("Couldn't create the chunk cache store");
+            }
+        });
+        open_request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+        log::info!("Caching chunks in IndexedDB");
+
+        Some(Self {
+            key_prefix: format!("{}_{}", density_id, seed),
+            open_request,
+            db: std::cell::RefCell::new(DbState::Opening),
+            _on_upgrade: on_upgrade,
+            warned: std::cell::Cell::new(false),
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(&self, pos: (i32, i32, i32), lod: chunk::Lod, borders: chunk::Borders) -> Option<chunk::ChunkMesh> {
+        let bytes = std::fs::read(self.path(pos, lod, borders)).ok()?;
+        decode(&bytes, lod, borders)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn store(&self, pos: (i32, i32, i32), lod: chunk::Lod, mesh: &chunk::ChunkMesh) {
+        // written to the side and renamed so a reader never sees half a file
+        let path = self.path(pos, lod, mesh.borders);
+        let tmp_id = TMP_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let tmp_path = path.with_extension(format!("tmp{}", tmp_id));
+
+        let result = std::fs::write(&tmp_path, encode(mesh)).and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(e) = result {
+            log::warn!("Couldn't cache chunk {:?}: {}", pos, e);
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn path(&self, pos: (i32, i32, i32), lod: chunk::Lod, borders: chunk::Borders) -> std::path::PathBuf {
+        self.dir.join(format!("{}_{}_{}_{}_{}.chunk", lod as u8, borders as u8, pos.0, pos.1, pos.2))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn start_load(&self, pos: (i32, i32, i32), lod: chunk::Lod, borders: chunk::Borders) -> Lookup {
+        Lookup {
+            key: self.key(pos, lod, borders),
+            lod,
+            borders,
+            request: std::cell::RefCell::new(None),
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn poll(&self, lookup: &Lookup) -> Loaded {
+        let mut request = lookup.request.borrow_mut();
+        if request.is_none() {
+            let db = self.db();
+            let db = match &*db {
+                DbState::Opening => return Loaded::Waiting,
+                DbState::Failed => return Loaded::Miss,
+                DbState::Open(db) => db,
+            };
+            let store = db.transaction_with_str_and_mode(STORE_NAME, web_sys::IdbTransactionMode::Readonly)
+                .and_then(|transaction| transaction.object_store(STORE_NAME));
+            match store.and_then(|store| store.get(&wasm_bindgen::JsValue::from_str(&lookup.key))) {
+                Ok(started) => *request = Some(started),
+                Err(_e) => return Loaded::Miss,
+            }
+        }
+
+        let request = request.as_ref().unwrap();
+        if request.ready_state() == web_sys::IdbRequestReadyState::Pending {
+            return Loaded::Waiting;
+        }
+        let bytes = match request.result() {
+            Ok(value) if !value.is_undefined() => js_sys::Uint8Array::new(&value).to_vec(),
+            _ => return Loaded::Miss,
+        };
+        match decode(&bytes, lookup.lod, lookup.borders) {
+            Some(mesh) => Loaded::Hit(mesh),
+            None => Loaded::Miss,
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn store(&self, pos: (i32, i32, i32), lod: chunk::Lod, mesh: &chunk::ChunkMesh) {
+        let db = self.db();
+        let DbState::Open(db) = &*db else { return; };
+
+        let bytes = js_sys::Uint8Array::from(encode(mesh).as_slice());
+        let key = wasm_bindgen::JsValue::from_str(&self.key(pos, lod, mesh.borders));
+        let stored = db.transaction_with_str_and_mode(STORE_NAME, web_sys::IdbTransactionMode::Readwrite)
+            .and_then(|transaction| transaction.object_store(STORE_NAME))
+            .and_then(|store| store.put_with_key(&bytes, &key));
+
+        // once is enough, the chunks just won't be cached
+        if stored.is_err() && !self.warned.replace(true) {
+            log::warn!("Couldn't cache chunk {:?}, later chunks may not be cached either", pos);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn db(&self) -> std::cell::Ref<'_, DbState> {
+        use wasm_bindgen::JsCast;
+
+        let opening = matches!(*self.db.borrow(), DbState::Opening);
+        if opening && self.open_request.ready_state() == web_sys::IdbRequestReadyState::Done {
+            let db = self.open_request.result().ok().and_then(|db| db.dyn_into::<web_sys::IdbDatabase>().ok());
+            *self.db.borrow_mut() = match db {
+                Some(db) => DbState::Open(db),
+                None => {
+                    log::warn!("Couldn't open the chunk cache");
+                    DbState::Failed
+                }
+            };
+        }
+        self.db.borrow()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn key(&self, pos: (i32, i32, i32), lod: chunk::Lod, borders: chunk::Borders) -> String {
+        format!("{}_{}_{}_{}_{}_{}", self.key_prefix, lod as u8, borders as u8, pos.0, pos.1, pos.2)
+    }
+}
+
+// Layout (little endian):
+// magic, version, vert count, verts, ind count, inds,
+// cell count, then per cell: x y z (u8), tri count (u16), tris (9 f32 each)
+fn encode(mesh: &chunk::ChunkMesh) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&CACHE_VERSION.to_le_bytes());
+
+    bytes.extend_from_slice(&(mesh.verts.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(bytemuck::cast_slice(&mesh.verts));
+
+    bytes.extend_from_slice(&(mesh.inds.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(bytemuck::cast_slice(&mesh.inds));
+
+    bytes.extend_from_slice(&(mesh.tris.len() as u32).to_le_bytes());
+    for (cell, tris) in &mesh.tris {
+        bytes.extend_from_slice(&[cell.0 as u8, cell.1 as u8, cell.2 as u8]);
+        bytes.extend_from_slice(&(tris.len() as u16).to_le_bytes());
+        for tri in tris {
+            for vert in tri.verts {
+                for v in [vert.x, vert.y, vert.z] {
+                    bytes.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+        }
+    }
+
+    bytes
+}
+
+fn decode(bytes: &[u8], lod: chunk::Lod, borders: chunk::Borders) -> Option<chunk::ChunkMesh> {
+    let mut reader = Reader { bytes };
+
+    if reader.take(MAGIC.len())? != MAGIC { return None; }
+    if reader.u32()? != CACHE_VERSION { return None; }
+
+    let num_verts = reader.u32()? as usize;
+    let vert_bytes = reader.take(num_verts * std::mem::size_of::<draw::VertColorNormal>())?;
+    let verts = vert_bytes
+        .chunks_exact(std::mem::size_of::<draw::VertColorNormal>())
+        .map(bytemuck::pod_read_unaligned)
+        .collect::<Vec<draw::VertColorNormal>>();
+
+    let num_inds = reader.u32()? as usize;
+    let ind_bytes = reader.take(num_inds * std::mem::size_of::<u16>())?;
+    let inds = ind_bytes
+        .chunks_exact(std::mem::size_of::<u16>())
+        .map(bytemuck::pod_read_unaligned)
+        .collect::<Vec<u16>>();
+    if inds.iter().any(|ind| *ind as usize >= verts.len()) { return None; }
+
+    let num_cells = reader.u32()? as usize;
+    let mut tris = HashMap::with_capacity(num_cells);
+    for _ in 0..num_cells {
+        let cell = reader.take(3)?;
+        let cell = (cell[0] as usize, cell[1] as usize, cell[2] as usize);
+
+        let num_tris = reader.u16()? as usize;
+        let mut cell_tris = Vec::with_capacity(num_tris);
+        for _ in 0..num_tris {
+            let mut tri = [[0.0; 3]; 3];
+            for vert in tri.iter_mut() {
+                for v in vert.iter_mut() {
+                    *v = reader.f32()?;
+                }
+            }
+            cell_tris.push(util::Tri::new(tri));
+        }
+        tris.insert(cell, cell_tris);
+    }
+
+    Some(chunk::ChunkMesh { verts, inds, tris, lod, borders })
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < len { return None; }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Some(taken)
+    }
+
+    fn u16(&mut self) -> Option<u16> { Some(u16::from_le_bytes(self.take(2)?.try_into().ok()?)) }
+    fn u32(&mut self) -> Option<u32> { Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?)) }
+    fn f32(&mut self) -> Option<f32> { Some(f32::from_le_bytes(self.take(4)?.try_into().ok()?)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::density;
+
+    fn mesh() -> chunk::ChunkMesh {
+        // half detail for the skirts, which only the inds have
+        let mesh = chunk::mesh_chunk((2, -1, -1), chunk::Lod::Half, chunk::Borders::Local, &density::PerlinDensity::new(1));
+        assert!(!mesh.is_blank());
+        mesh
+    }
+
+    // where the inds start, after the magic, version, vert count, verts and ind count
+    fn inds_offset(mesh: &chunk::ChunkMesh) -> usize {
+        MAGIC.len() + 4 + 4 + mesh.verts.len() * std::mem::size_of::<draw::VertColorNormal>() + 4
+    }
+
+    #[test]
+    fn round_trip() {
+        let mesh = mesh();
+        let decoded = decode(&encode(&mesh), mesh.lod, mesh.borders).unwrap();
+
+        assert_eq!(bytemuck::cast_slice::<_, u8>(&decoded.verts), bytemuck::cast_slice::<_, u8>(&mesh.verts));
+        assert_eq!(decoded.inds, mesh.inds);
+        assert_eq!(decoded.surface_inds(), mesh.surface_inds());
+        assert_eq!(decoded.lod, mesh.lod);
+        assert_eq!(decoded.borders, mesh.borders);
+
+        assert_eq!(decoded.tris.len(), mesh.tris.len());
+        for (cell, tris) in &mesh.tris {
+            let decoded_tris = &decoded.tris[cell];
+            assert_eq!(decoded_tris.len(), tris.len());
+            for (a, b) in decoded_tris.iter().zip(tris) {
+                assert_eq!(a.verts, b.verts);
+                assert_eq!(a.normal, b.normal);
+            }
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        let mesh = mesh();
+        let bytes = encode(&mesh);
+        for len in [0, 3, MAGIC.len() + 4, inds_offset(&mesh), bytes.len() - 1] {
+            assert!(decode(&bytes[..len], mesh.lod, mesh.borders).is_none(), "{} of {} bytes", len, bytes.len());
+        }
+    }
+
+    #[test]
+    fn wrong_magic_or_version() {
+        let mesh = mesh();
+
+        let mut bytes = encode(&mesh);
+        bytes[0] = b'X';
+        assert!(decode(&bytes, mesh.lod, mesh.borders).is_none());
+
+        let mut bytes = encode(&mesh);
+        bytes[MAGIC.len()..MAGIC.len() + 4].copy_from_slice(&(CACHE_VERSION + 1).to_le_bytes());
+        assert!(decode(&bytes, mesh.lod, mesh.borders).is_none());
+    }
+
+    #[test]
+    fn index_out_of_range() {
+        let mesh = mesh();
+        let mut bytes = encode(&mesh);
+        let offset = inds_offset(&mesh);
+        bytes[offset..offset + 2].copy_from_slice(&(mesh.verts.len() as u16).to_le_bytes());
+        assert!(decode(&bytes, mesh.lod, mesh.borders).is_none());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{chunk, chunk_cache, density};
+use std::collections::VecDeque;
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+
+const MAX_WORKERS: usize = 8;
+
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JobKind {
+    // a chunk to draw, back through try_recv
+    Render,
+    // always full detail, for what the sub and the fish collide with, back through try_recv_collision
+    Collision,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChunkJob {
+    pub pos: (i32, i32, i32),
+    pub lod: chunk::Lod,
+    pub borders: chunk::Borders,
+    // World's edit version when the job was queued
+    pub edit_version: u64,
+    pub kind: JobKind,
+}
+
+struct Queue {
+    // taken before any of the jobs below, oldest first (remeshes after an edit and collision)
+    urgent: VecDeque<ChunkJob>,
+    // sorted like World::chunks_to_generate, the next job is at the end
+    jobs: Vec<ChunkJob>,
+    // taken by a worker, but not received on the main thread yet
+    building: Vec<ChunkJob>,
+    stop: bool,
+}
+
+struct Shared {
+    queue: Mutex<Queue>,
+    has_jobs: Condvar,
+}
+
+// Meshes chunks on background threads (native only), the main thread only uploads them
+pub struct ChunkWorkers {
+    shared: Arc<Shared>,
+    finished: mpsc::Receiver<(ChunkJob, chunk::ChunkMesh)>,
+    // kept apart so the simulation isn't held up behind the uploads
+    finished_collision: mpsc::Receiver<(ChunkJob, chunk::ChunkMesh)>,
+}
+
+impl ChunkWorkers {
+    pub fn new(density: Arc<dyn density::Density>, cache: Option<chunk_cache::ChunkCache>) -> Self {
+        let cache = cache.map(Arc::new);
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(Queue {
+                urgent: VecDeque::new(),
+                jobs: Vec::new(),
+                building: Vec::new(),
+                stop: false,
+            }),
+            has_jobs: Condvar::new(),
+        });
+        let (sender, finished) = mpsc::channel();
+        let (collision_sender, finished_collision) = mpsc::channel();
+
+        // leaves a core for the render thread
+        let num_workers = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(2)
+            .saturating_sub(1)
+            .clamp(1, MAX_WORKERS);
+        log::info!("Generating chunks on {} threads", num_workers);
+
+        for i in 0..num_workers {
+            let shared = shared.clone();
+            let density = density.clone();
+            let cache = cache.clone();
+            let sender = sender.clone();
+            let collision_sender = collision_sender.clone();
+
+            std::thread::Builder::new()
+                .name(format!("chunk worker {}", i))
+                .spawn(move || work(&shared, density.as_ref(), cache.as_deref(), &sender, &collision_sender))
+                .expect("Couldn't spawn chunk worker");
+        }
+
+        Self { shared, finished, finished_collision }
+    }
+
+    // Replaces every job not started yet, so jobs for chunks the sub has left behind are dropped
+    pub fn set_jobs(&self, jobs: Vec<ChunkJob>) {
+        let mut queue = self.shared.queue.lock().unwrap();
+        queue.jobs = jobs;
+        self.shared.has_jobs.notify_all();
+    }
+
+    // Goes ahead of the jobs from set_jobs and isn't replaced by them. An urgent job for
+    // the same chunk that hasn't started yet is out of date, so this takes its place.
+    pub fn add_urgent(&self, job: ChunkJob) {
+        let mut queue = self.shared.queue.lock().unwrap();
+        match queue.urgent.iter_mut().find(|queued| queued.pos == job.pos && queued.kind == job.kind) {
+            Some(queued) => *queued = ChunkJob { lod: queued.lod.min(job.lod), ..job },
+            None => queue.urgent.push_back(job),
+        }
+        self.shared.has_jobs.notify_one();
+    }
+
+    pub fn building(&self) -> Vec<ChunkJob> {
+        self.shared.queue.lock().unwrap().building.clone()
+    }
+
+    pub fn try_recv(&self) -> Option<(ChunkJob, chunk::ChunkMesh)> {
+        let (job, mesh) = self.finished.try_recv().ok()?;
+        self.finish_building(job);
+        Some((job, mesh))
+    }
+
+    pub fn try_recv_collision(&self) -> Option<(ChunkJob, chunk::ChunkMesh)> {
+        let (job, mesh) = self.finished_collision.try_recv().ok()?;
+        self.finish_building(job);
+        Some((job, mesh))
+    }
+
+    fn finish_building(&self, job: ChunkJob) {
+        let mut queue = self.shared.queue.lock().unwrap();
+        if let Some(i) = queue.building.iter().position(|building| *building == job) {
+            queue.building.swap_remove(i);
+        }
+    }
+
+    // of every job queued or building, so World knows which edits they could be older than
+    pub fn oldest_edit_version(&self) -> Option<u64> {
+        let queue = self.shared.queue.lock().unwrap();
+        queue.urgent.iter()
+            .chain(queue.jobs.iter())
+            .chain(queue.building.iter())
+            .map(|job| job.edit_version)
+            .min()
+    }
+
+    pub fn count(&self) -> usize {
+        let queue = self.shared.queue.lock().unwrap();
+        queue.urgent.len() + queue.jobs.len() + queue.building.len()
+    }
+}
+
+impl Drop for ChunkWorkers {
+    fn drop(&mut self) {
+        self.shared.queue.lock().unwrap().stop = true;
+        self.shared.has_jobs.notify_all();
+    }
+}
+
+fn work(
+    shared: &Shared,
+    density: &dyn density::Density,
+    cache: Option<&chunk_cache::ChunkCache>,
+    sender: &mpsc::Sender<(ChunkJob, chunk::ChunkMesh)>,
+    collision_sender: &mpsc::Sender<(ChunkJob, chunk::ChunkMesh)>,
+) {
+    loop {
+        let job = {
+            let mut queue = shared.queue.lock().unwrap();
+            while queue.urgent.is_empty() && queue.jobs.is_empty() && !queue.stop {
+                queue = shared.has_jobs.wait(queue).unwrap();
+            }
+            if queue.stop { return; }
+
+            let job = queue.urgent.pop_front().or_else(|| queue.jobs.pop()).unwrap();
+            queue.building.push(job);
+            job
+        };
+
+        // edited chunks are never cached, the edits aren't saved
+        let cache = cache.filter(|_cache| density.cacheable(job.pos));
+
+        let cached = cache.and_then(|cache| cache.load(job.pos, job.lod, job.borders));
+        let mesh = cached.unwrap_or_else(|| {
+            let mesh = chunk::mesh_chunk(job.pos, job.lod, job.borders, density);
+            if let Some(cache) = cache {
+                cache.store(job.pos, job.lod, &mesh);
+            }
+            mesh
+        });
+        let sender = match job.kind {
+            JobKind::Render => sender,
+            JobKind::Collision => collision_sender,
+        };
+        if sender.send((job, mesh)).is_err() { return; }
+    }
+}
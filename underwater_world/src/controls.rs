@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use winit::event::VirtualKeyCode;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::util;
+
+#[cfg(not(target_arch = "wasm32"))]
+const CONTROLS_ARG: &str = "--controls";
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_CONTROLS_FILE: &str = "controls.toml";
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "underwater_world_controls";
+
+
+// What a key does, so the keys themselves can be rebound
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    PitchUp,
+    PitchDown,
+    YawLeft,
+    YawRight,
+    RollLeft,
+    RollRight,
+    Throttle,
+    Brake,
+    Reset,
+    Dig,
+    Fill,
+    CycleCamera,
+    DebugCamera,
+    MouseSteering,
+    TuningPanel,
+    // the debug camera flies faster while it's held
+    FlyFast,
+    // native only, hold shift to export a box around the sub
+    ExportObj,
+    ExportPly,
+    // stops mouse steering or closes the tuning panel
+    Cancel,
+    // only while the tuning panel is open
+    PanelUp,
+    PanelDown,
+    PanelDecrease,
+    PanelIncrease,
+    PanelNextSpecies,
+    PanelReset,
+}
+impl Action {
+    const ALL: [Action; 25] = [
+        Action::PitchUp,
+        Action::PitchDown,
+        Action::YawLeft,
+        Action::YawRight,
+        Action::RollLeft,
+        Action::RollRight,
+        Action::Throttle,
+        Action::Brake,
+        Action::Reset,
+        Action::Dig,
+        Action::Fill,
+        Action::CycleCamera,
+        Action::DebugCamera,
+        Action::MouseSteering,
+        Action::TuningPanel,
+        Action::FlyFast,
+        Action::ExportObj,
+        Action::ExportPly,
+        Action::Cancel,
+        Action::PanelUp,
+        Action::PanelDown,
+        Action::PanelDecrease,
+        Action::PanelIncrease,
+        Action::PanelNextSpecies,
+        Action::PanelReset,
+    ];
+
+    // as written in the controls file (and the input log)
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::PitchUp => "PitchUp",
+            Action::PitchDown => "PitchDown",
+            Action::YawLeft => "YawLeft",
+            Action::YawRight => "YawRight",
+            Action::RollLeft => "RollLeft",
+            Action::RollRight => "RollRight",
+            Action::Throttle => "Throttle",
+            Action::Brake => "Brake",
+            Action::Reset => "Reset",
+            Action::Dig => "Dig",
+            Action::Fill => "Fill",
+            Action::CycleCamera => "CycleCamera",
+            Action::DebugCamera => "DebugCamera",
+            Action::MouseSteering => "MouseSteering",
+            Action::TuningPanel => "TuningPanel",
+            Action::FlyFast => "FlyFast",
+            Action::ExportObj => "ExportObj",
+            Action::ExportPly => "ExportPly",
+            Action::Cancel => "Cancel",
+            Action::PanelUp => "PanelUp",
+            Action::PanelDown => "PanelDown",
+            Action::PanelDecrease => "PanelDecrease",
+            Action::PanelIncrease => "PanelIncrease",
+            Action::PanelNextSpecies => "PanelNextSpecies",
+            Action::PanelReset => "PanelReset",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Action::ALL.iter().copied().find(|action| action.name() == name)
+    }
+
+    fn default_keys(self) -> &'static [VirtualKeyCode] {
+        match self {
+            Action::PitchUp => &[VirtualKeyCode::W, VirtualKeyCode::Up],
+            Action::PitchDown => &[VirtualKeyCode::S, VirtualKeyCode::Down],
+            Action::YawLeft => &[VirtualKeyCode::A, VirtualKeyCode::Left],
+            Action::YawRight => &[VirtualKeyCode::D, VirtualKeyCode::Right],
+            Action::RollLeft => &[VirtualKeyCode::Q, VirtualKeyCode::PageUp],
+            Action::RollRight => &[VirtualKeyCode::E, VirtualKeyCode::PageDown],
+            Action::Throttle => &[VirtualKeyCode::Space],
+            Action::Brake => &[VirtualKeyCode::LControl, VirtualKeyCode::RControl],
+            Action::Reset => &[VirtualKeyCode::R, VirtualKeyCode::Return],
+            Action::Dig => &[VirtualKeyCode::F],
+            Action::Fill => &[VirtualKeyCode::G],
+            Action::CycleCamera => &[VirtualKeyCode::V],
+            Action::DebugCamera => &[VirtualKeyCode::C],
+            Action::MouseSteering => &[VirtualKeyCode::M],
+            Action::TuningPanel => &[VirtualKeyCode::T],
+            Action::FlyFast => &[VirtualKeyCode::LShift, VirtualKeyCode::RShift],
+            Action::ExportObj => &[VirtualKeyCode::O],
+            Action::ExportPly => &[VirtualKeyCode::P],
+            Action::Cancel => &[VirtualKeyCode::Escape],
+            // away from the sub's keys, so steering still works with the panel open
+            Action::PanelUp => &[VirtualKeyCode::I],
+            Action::PanelDown => &[VirtualKeyCode::K],
+            Action::PanelDecrease => &[VirtualKeyCode::J],
+            Action::PanelIncrease => &[VirtualKeyCode::L],
+            Action::PanelNextSpecies => &[VirtualKeyCode::Tab],
+            Action::PanelReset => &[VirtualKeyCode::Back],
+        }
+    }
+}
+
+// Which key triggers which action.
+// The controls file has a line per action it rebinds (the rest keep their default keys), for example:
+//     PitchUp = ["Z", "Up"]
+//     YawLeft = ["Q", "Left"]
+// with the key names from winit's VirtualKeyCode.
+pub struct Bindings {
+    keys: HashMap<VirtualKeyCode, Action>,
+}
+
+impl Bindings {
+    pub fn defaults() -> Self {
+        let mut keys = HashMap::new();
+        for action in Action::ALL {
+            for key in action.default_keys() {
+                keys.insert(*key, action);
+            }
+        }
+        Self { keys }
+    }
+
+    // Falls back to the defaults if there is no controls file or it can't be read
+    pub fn from_env() -> Self {
+        let text = match controls_text_from_env() {
+            Some(text) => text,
+            None => return Self::defaults(),
+        };
+
+        match Self::from_toml(&text) {
+            Ok(bindings) => {
+                log::info!("Loaded {} key bindings", bindings.keys.len());
+                bindings
+            }
+            Err(e) => {
+                log::error!("Couldn't read the controls, using the defaults: {}", e);
+                Self::defaults()
+            }
+        }
+    }
+
+    fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+        let rebound: HashMap<String, Vec<VirtualKeyCode>> = toml::from_str(text)?;
+
+        let mut rebound_actions = HashMap::new();
+        for (name, keys) in rebound {
+            match Action::from_name(&name) {
+                Some(action) => { rebound_actions.insert(action, keys); }
+                None => log::warn!("Unknown action in the controls: {}", name),
+            }
+        }
+
+        let mut bindings = Self::defaults();
+        bindings.keys.retain(|_key, action| !rebound_actions.contains_key(action));
+        for (action, keys) in rebound_actions {
+            for key in keys {
+                if let Some(old) = bindings.keys.insert(key, action) {
+                    log::warn!("{:?} was bound to {}, now it is bound to {}", key, old.name(), action.name());
+                }
+            }
+        }
+
+        Ok(bindings)
+    }
+
+    // For showing on screen, the first by name if there's more than one
+    pub fn key_name(&self, action: Action) -> String {
+        let mut names = self.keys.iter()
+            .filter(|(_key, bound)| **bound == action)
+            .map(|(key, _bound)| format!("{:?}", key))
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.into_iter().next().unwrap_or_else(|| "UNBOUND".to_string())
+    }
+
+    // Some((action, pressed)) if the event is for a bound key
+    pub fn action(&self, event: &winit::event::WindowEvent) -> Option<(Action, bool)> {
+        match event {
+            winit::event::WindowEvent::KeyboardInput {
+                input:
+                winit::event::KeyboardInput {
+                        state,
+                        virtual_keycode: Some(keycode),
+                        ..
+                    },
+                ..
+            } => {
+                let action = self.keys.get(keycode)?;
+                Some((*action, *state == winit::event::ElementState::Pressed))
+            }
+            _ => None,
+        }
+    }
+}
+
+// native: `--controls <file>`, otherwise `controls.toml` if there is one
+#[cfg(not(target_arch = "wasm32"))]
+fn controls_text_from_env() -> Option<String> {
+    let path = util::arg_value(CONTROLS_ARG);
+
+    match path {
+        Some(path) => match std::fs::read_to_string(&path) {
+            Ok(text) => Some(text),
+            Err(e) => {
+                log::error!("Couldn't read controls file {}: {}", path, e);
+                None
+            }
+        },
+        None => std::fs::read_to_string(DEFAULT_CONTROLS_FILE).ok(),
+    }
+}
+
+// web: the same TOML text, saved in localStorage
+#[cfg(target_arch = "wasm32")]
+fn controls_text_from_env() -> Option<String> {
+    let storage = web_sys::window()?.local_storage().ok()??;
+    storage.get_item(STORAGE_KEY).ok()?
+}
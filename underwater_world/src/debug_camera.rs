@@ -0,0 +1,160 @@
+use crate::{camera, controls};
+use cgmath::{EuclideanSpace, InnerSpace};
+
+const MOVE_SPEED: f32 = 20.0;
+// while FlyFast is held
+const FAST_MOVE_SPEED: f32 = 80.0;
+// radians per pixel the cursor moves while looking
+const LOOK_SENSITIVITY: f32 = 0.004;
+// stops just short of straight up/down so look_at never gets a forward parallel to up
+const MAX_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.01;
+
+
+// The debug camera flies with the sub's actions: pitch up/down goes forward and back,
+// yaw left/right sideways and throttle/brake up and down
+struct Keys {
+    forward: bool,
+    back: bool,
+    left: bool,
+    right: bool,
+    up: bool,
+    down: bool,
+    fast: bool,
+}
+impl Keys {
+    fn new() -> Self {
+        Self {
+            forward: false,
+            back: false,
+            left: false,
+            right: false,
+            up: false,
+            down: false,
+            fast: false,
+        }
+    }
+
+    fn process_action(&mut self, action: controls::Action, pressed: bool) -> bool {
+        let key = match action {
+            controls::Action::PitchUp => &mut self.forward,
+            controls::Action::PitchDown => &mut self.back,
+            controls::Action::YawLeft => &mut self.left,
+            controls::Action::YawRight => &mut self.right,
+            controls::Action::Throttle => &mut self.up,
+            controls::Action::Brake => &mut self.down,
+            controls::Action::FlyFast => &mut self.fast,
+            _ => return false,
+        };
+        *key = pressed;
+        true
+    }
+}
+
+// Free flying camera for looking at the world from outside the sub.
+// It only changes what is rendered, chunk generation and culling still use the sub's camera.
+pub struct DebugCamera {
+    active: bool,
+
+    pos: cgmath::Point3<f32>,
+    // around +z, 0 is looking along +x
+    yaw: f32,
+    pitch: f32,
+
+    keys: Keys,
+    looking: bool,
+    last_cursor: Option<(f64, f64)>,
+}
+
+impl DebugCamera {
+    pub fn new() -> Self {
+        Self {
+            active: false,
+            pos: cgmath::Point3::origin(),
+            yaw: 0.0,
+            pitch: 0.0,
+            keys: Keys::new(),
+            looking: false,
+            last_cursor: None,
+        }
+    }
+
+    pub fn active(&self) -> bool { self.active }
+
+    // Starts from wherever the sub's camera currently is
+    pub fn toggle(&mut self, camera: &camera::Camera) {
+        self.active = !self.active;
+        self.keys = Keys::new();
+        self.looking = false;
+
+        if self.active {
+            let dir = (camera.target - camera.eye).normalize();
+            self.pos = camera.eye;
+            self.yaw = dir.y.atan2(dir.x);
+            self.pitch = dir.z.asin().clamp(-MAX_PITCH, MAX_PITCH);
+        }
+    }
+
+    // Hold the left mouse button and move the cursor to look around
+    pub fn process_events(&mut self, event: &winit::event::WindowEvent) -> bool {
+        match event {
+            winit::event::WindowEvent::MouseInput {
+                state,
+                button: winit::event::MouseButton::Left,
+                ..
+            } => {
+                self.looking = *state == winit::event::ElementState::Pressed;
+                true
+            }
+            winit::event::WindowEvent::CursorMoved { position, .. } => {
+                if let (true, Some(last)) = (self.looking, self.last_cursor) {
+                    self.yaw -= (position.x - last.0) as f32 * LOOK_SENSITIVITY;
+                    self.pitch -= (position.y - last.1) as f32 * LOOK_SENSITIVITY;
+                    self.pitch = self.pitch.clamp(-MAX_PITCH, MAX_PITCH);
+                }
+                self.last_cursor = Some((position.x, position.y));
+                true
+            }
+            winit::event::WindowEvent::CursorLeft { .. } => {
+                self.last_cursor = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn process_action(&mut self, action: controls::Action, pressed: bool) -> bool {
+        self.keys.process_action(action, pressed)
+    }
+
+    fn forward(&self) -> cgmath::Vector3<f32> {
+        cgmath::Vector3::new(
+            self.pitch.cos() * self.yaw.cos(),
+            self.pitch.cos() * self.yaw.sin(),
+            self.pitch.sin(),
+        )
+    }
+
+    pub fn update(&mut self, delta: f32) {
+        let forward = self.forward();
+        let right = forward.cross(cgmath::Vector3::unit_z()).normalize();
+        let up = cgmath::Vector3::unit_z();
+
+        let mut dir = cgmath::Vector3::new(0.0, 0.0, 0.0);
+        if self.keys.forward { dir += forward; }
+        if self.keys.back { dir -= forward; }
+        if self.keys.right { dir += right; }
+        if self.keys.left { dir -= right; }
+        if self.keys.up { dir += up; }
+        if self.keys.down { dir -= up; }
+
+        if dir.magnitude2() > 0.0 {
+            let speed = if self.keys.fast { FAST_MOVE_SPEED } else { MOVE_SPEED };
+            self.pos += dir.normalize() * speed * delta;
+        }
+    }
+
+    // Overrides the rendered view, leaving camera.eye/target/up alone for the world
+    pub fn update_camera(&self, camera: &mut camera::Camera) {
+        camera.update_uniform_from(self.pos, self.pos + self.forward(), cgmath::Vector3::unit_z());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{biome, chunk, perlin_util};
+
+// Terrain density source for chunk meshing and boid spawning.
+// Coordinates are in chunk units (world position / chunk::CHUNK_SIZE).
+// Values below chunk::ISO_LEVEL are solid, values above are open water.
+// Shared with the chunk worker threads on native, so it has to be Send + Sync.
+pub trait Density: Send + Sync {
+    fn iso_at(&self, x: f64, y: f64, z: f64) -> f32;
+
+    // Biome blend in XY, drives terrain colors, fog and boid spawning
+    fn biome_at(&self, _x: f64, _y: f64) -> biome::BiomeWeights {
+        biome::BiomeWeights::default()
+    }
+
+    // false if the chunk's mesh depends on more than the seed (edits), so it can't be cached
+    fn cacheable(&self, _chunk_pos: (i32, i32, i32)) -> bool {
+        true
+    }
+
+    // Names the terrain this source makes from a seed, so the chunk cache doesn't mix up
+    // two sources with the same seed. Without one its chunks aren't cached at all.
+    fn cache_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+// Creates the density source for a world seed
+pub type DensityBuilder = fn(u32) -> Box<dyn Density>;
+
+
+// Octave perlin noise + height + terracing, the original terrain
+pub struct PerlinDensity {
+    perlin: noise::Perlin,
+}
+impl PerlinDensity {
+    pub fn new(seed: u32) -> Self {
+        Self { perlin: noise::Perlin::new(seed) }
+    }
+}
+impl Density for PerlinDensity {
+    fn iso_at(&self, x: f64, y: f64, z: f64) -> f32 {
+        perlin_util::iso_at(&self.perlin, x, y, z)
+    }
+
+    fn cache_id(&self) -> Option<&str> {
+        Some("perlin")
+    }
+}
+
+// Same noise as PerlinDensity, but the shape is blended between biomes
+pub struct BiomeDensity {
+    perlin: noise::Perlin,
+    biome_map: biome::BiomeMap,
+}
+impl BiomeDensity {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            perlin: noise::Perlin::new(seed),
+            biome_map: biome::BiomeMap::new(seed),
+        }
+    }
+}
+impl Density for BiomeDensity {
+    fn iso_at(&self, x: f64, y: f64, z: f64) -> f32 {
+        let p = perlin_util::perlin_3d_octaves(&self.perlin, [x, y, z], chunk::PERLIN_OCTAVES) as f32;
+
+        self.biome_at(x, y)
+            .iter()
+            .map(|(biome, weight)| {
+                let params = biome.params();
+                perlin_util::shaped_iso(p, z, params.height_offset, params.noise_amp, params.terrace) * weight
+            })
+            .sum()
+    }
+
+    fn biome_at(&self, x: f64, y: f64) -> biome::BiomeWeights {
+        self.biome_map.weights_at(x, y)
+    }
+
+    fn cache_id(&self) -> Option<&str> {
+        Some("biome")
+    }
+}
+
+pub fn default_density(seed: u32) -> Box<dyn Density> {
+    Box::new(BiomeDensity::new(seed))
+}
@@ -32,6 +32,43 @@ impl VertColor {
     }
 }
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct VertColorNormal {
+	pub pos: [f32; 3],
+    pub color: [f32; 3],
+    pub normal: [f32; 3],
+}
+impl VertColorNormal {
+	pub fn new(pos: [f32; 3], color: [f32; 3], normal: [f32; 3]) -> Self {
+		Self { pos, color, normal }
+	}
+
+	pub fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<VertColorNormal>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[
+                wgpu::VertexAttribute {
+                    offset: 0,
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+				wgpu::VertexAttribute {
+					offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
+					shader_location: 1,
+					format: wgpu::VertexFormat::Float32x3,
+				},
+				wgpu::VertexAttribute {
+					offset: std::mem::size_of::<[f32; 6]>() as wgpu::BufferAddress,
+					shader_location: 2,
+					format: wgpu::VertexFormat::Float32x3,
+				}
+            ]
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct VertTex {
@@ -114,12 +151,15 @@ impl Instance {
 pub struct InstanceTime {
     model: [[f32; 4]; 4],
     time: f32,
+    // the last simulation step's movement, for drawing between steps
+    moved: [f32; 3],
 }
 impl InstanceTime {
-    pub fn new(model: cgmath::Matrix4<f32>, time: f32) -> Self {
+    pub fn new(model: cgmath::Matrix4<f32>, time: f32, moved: cgmath::Vector3<f32>) -> Self {
         Self {
             model: model.into(),
             time,
+            moved: moved.into(),
         }
     }
     pub fn desc() -> wgpu::VertexBufferLayout<'static> {
@@ -152,6 +192,11 @@ impl InstanceTime {
                     shader_location: 9,
                     format: wgpu::VertexFormat::Float32,
                 },
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 17]>() as wgpu::BufferAddress,
+                    shader_location: 10,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
             ],
         }
     }
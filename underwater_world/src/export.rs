@@ -0,0 +1,217 @@
+use crate::{chunk, draw, util, world};
+use std::collections::HashMap;
+use std::io::Write;
+
+// Verts closer than 1 / WELD_PRECISION on every axis are merged
+const WELD_PRECISION: f32 = 1000.0;
+
+
+#[derive(Copy, Clone, Debug)]
+pub enum ExportFormat {
+    Obj,
+    Ply,
+}
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Obj => "obj",
+            ExportFormat::Ply => "ply",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum ExportRegion {
+    // world space box, a triangle is kept if its center is inside
+    Bounds {
+        min: cgmath::Vector3<f32>,
+        max: cgmath::Vector3<f32>,
+    },
+    // every chunk within `dist` chunks of `center`
+    AroundChunk {
+        center: (i32, i32, i32),
+        dist: i32,
+    },
+}
+impl ExportRegion {
+    fn includes_chunk(&self, pos: (i32, i32, i32)) -> bool {
+        match self {
+            ExportRegion::Bounds { min, max } => {
+                let chunk_min = cgmath::Vector3::new(
+                    (pos.0 * chunk::CHUNK_SIZE as i32) as f32,
+                    (pos.1 * chunk::CHUNK_SIZE as i32) as f32,
+                    (pos.2 * chunk::CHUNK_SIZE as i32) as f32,
+                );
+                let chunk_max = chunk_min + cgmath::Vector3::new(
+                    chunk::CHUNK_SIZE as f32,
+                    chunk::CHUNK_SIZE as f32,
+                    chunk::CHUNK_SIZE as f32,
+                );
+
+                chunk_min.x <= max.x && chunk_max.x >= min.x
+                    && chunk_min.y <= max.y && chunk_max.y >= min.y
+                    && chunk_min.z <= max.z && chunk_max.z >= min.z
+            }
+            ExportRegion::AroundChunk { center, dist } => {
+                util::dist_sq(pos, *center) <= dist * dist
+            }
+        }
+    }
+
+    fn includes_tri(&self, tri: [[f32; 3]; 3]) -> bool {
+        match self {
+            ExportRegion::Bounds { min, max } => {
+                let center = (
+                    cgmath::Vector3::from(tri[0])
+                    + cgmath::Vector3::from(tri[1])
+                    + cgmath::Vector3::from(tri[2])
+                ) / 3.0;
+
+                center.x >= min.x && center.x <= max.x
+                    && center.y >= min.y && center.y <= max.y
+                    && center.z >= min.z && center.z <= max.z
+            }
+            ExportRegion::AroundChunk { .. } => true,
+        }
+    }
+}
+
+pub struct MergedMesh {
+    verts: Vec<draw::VertColorNormal>,
+    inds: Vec<u32>,
+}
+impl MergedMesh {
+    pub fn from_world(world: &world::World, region: ExportRegion) -> Self {
+        let mut verts = Vec::new();
+        let mut inds = Vec::new();
+        let mut welded: HashMap<(i64, i64, i64), u32> = HashMap::new();
+
+        // sort so the output is the same no matter the HashMap order
+        let mut chunk_poses = world.chunks()
+            .filter(|(pos, chunk)| region.includes_chunk(**pos) && !chunk.mesh().is_blank())
+            .map(|(pos, _chunk)| *pos)
+            .collect::<Vec<_>>();
+        chunk_poses.sort_unstable();
+
+        for pos in chunk_poses {
+            let mesh = world.get_chunk(pos).unwrap().mesh();
+
+            // the skirts only hide cracks between LODs, they'd be stray walls in the export
+            for tri_inds in mesh.surface_inds().chunks_exact(3) {
+                let tri_verts = [
+                    mesh.verts[tri_inds[0] as usize],
+                    mesh.verts[tri_inds[1] as usize],
+                    mesh.verts[tri_inds[2] as usize],
+                ];
+                if !region.includes_tri([tri_verts[0].pos, tri_verts[1].pos, tri_verts[2].pos]) {
+                    continue;
+                }
+
+                for vert in tri_verts {
+                    let key = weld_key(vert.pos);
+                    let ind = *welded.entry(key).or_insert_with(|| {
+                        verts.push(vert);
+                        verts.len() as u32 - 1
+                    });
+                    inds.push(ind);
+                }
+            }
+        }
+
+        Self { verts, inds }
+    }
+
+    pub fn num_verts(&self) -> usize { self.verts.len() }
+    pub fn num_tris(&self) -> usize { self.inds.len() / 3 }
+
+    pub fn write(&self, format: ExportFormat, out: &mut impl Write) -> std::io::Result<()> {
+        match format {
+            ExportFormat::Obj => self.write_obj(out),
+            ExportFormat::Ply => self.write_ply(out),
+        }
+    }
+
+    // Vertex colors use the common "v x y z r g b" extension (Blender, MeshLab)
+    fn write_obj(&self, out: &mut impl Write) -> std::io::Result<()> {
+        writeln!(out, "# Underwater World terrain")?;
+        writeln!(out, "o terrain")?;
+
+        for vert in &self.verts {
+            let color = util::from_srgb_decimal(vert.color);
+            writeln!(
+                out,
+                "v {} {} {} {:.4} {:.4} {:.4}",
+                vert.pos[0], vert.pos[1], vert.pos[2],
+                color[0], color[1], color[2],
+            )?;
+        }
+        for vert in &self.verts {
+            writeln!(out, "vn {:.4} {:.4} {:.4}", vert.normal[0], vert.normal[1], vert.normal[2])?;
+        }
+
+        // OBJ indices start at 1, every vert has a normal with the same index
+        for tri in self.inds.chunks_exact(3) {
+            let (a, b, c) = (tri[0] + 1, tri[1] + 1, tri[2] + 1);
+            writeln!(out, "f {}//{} {}//{} {}//{}", a, a, b, b, c, c)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_ply(&self, out: &mut impl Write) -> std::io::Result<()> {
+        writeln!(out, "ply")?;
+        writeln!(out, "format binary_little_endian 1.0")?;
+        writeln!(out, "comment Underwater World terrain")?;
+        writeln!(out, "element vertex {}", self.verts.len())?;
+        writeln!(out, "property float x")?;
+        writeln!(out, "property float y")?;
+        writeln!(out, "property float z")?;
+        writeln!(out, "property float nx")?;
+        writeln!(out, "property float ny")?;
+        writeln!(out, "property float nz")?;
+        writeln!(out, "property uchar red")?;
+        writeln!(out, "property uchar green")?;
+        writeln!(out, "property uchar blue")?;
+        writeln!(out, "element face {}", self.num_tris())?;
+        writeln!(out, "property list uchar uint vertex_indices")?;
+        writeln!(out, "end_header")?;
+
+        for vert in &self.verts {
+            for p in vert.pos.iter().chain(vert.normal.iter()) {
+                out.write_all(&p.to_le_bytes())?;
+            }
+            let color = util::from_srgb_decimal(vert.color);
+            for c in color {
+                out.write_all(&[(c.clamp(0.0, 1.0) * 255.0).round() as u8])?;
+            }
+        }
+
+        for tri in self.inds.chunks_exact(3) {
+            out.write_all(&[3])?;
+            for ind in tri {
+                out.write_all(&ind.to_le_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn export_to_file(world: &world::World, region: ExportRegion, format: ExportFormat, path: &std::path::Path) -> std::io::Result<MergedMesh> {
+    let mesh = MergedMesh::from_world(world, region);
+
+    let file = std::fs::File::create(path)?;
+    let mut out = std::io::BufWriter::new(file);
+    mesh.write(format, &mut out)?;
+    out.flush()?;
+
+    Ok(mesh)
+}
+
+fn weld_key(pos: [f32; 3]) -> (i64, i64, i64) {
+    (
+        (pos[0] * WELD_PRECISION).round() as i64,
+        (pos[1] * WELD_PRECISION).round() as i64,
+        (pos[2] * WELD_PRECISION).round() as i64,
+    )
+}
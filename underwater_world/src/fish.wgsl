@@ -1,11 +1,11 @@
 struct CameraUniform {
     view_proj: mat4x4<f32>,
     fog_color: vec3<f32>,
-    _padding1: f32,
+    fog_near: f32,
     sub_pos: vec3<f32>,
-    _padding2: f32,
+    fog_far: f32,
     sub_forward: vec3<f32>,
-    _padding3: f32,
+    step_alpha: f32,
 };
 
 @group(0) @binding(0)
@@ -27,6 +27,7 @@ struct InstanceInput {
     @location(7) model_matrix_2: vec4<f32>,
     @location(8) model_matrix_3: vec4<f32>,
     @location(9) time: f32,
+    @location(10) moved: vec3<f32>,
 };
 
 struct VertexOutput {
@@ -77,7 +78,8 @@ fn vs_main(
     var out: VertexOutput;
     out.tex_coords = model.tex_coords;
 
-    let world_pos = model_matrix * pos;
+    // back to between the last two steps
+    let world_pos = model_matrix * pos - vec4<f32>(instance.moved * (1.0 - camera.step_alpha), 0.0);
     out.clip_position = camera.view_proj * world_pos;
 
     let dist_vec = world_pos.xyz - camera.sub_pos;
@@ -101,8 +103,8 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let in_color = textureSample(t_diffuse, s_diffuse, in.tex_coords);
     let fog_color = vec4<f32>(camera.fog_color, 1.0);
 
-    let min_dist_value = smoothstep(0.0, 20.0, clamp(in.dist, 0.0, 20.0));
-    let max_dist_value = smoothstep(0.0, 45.0, clamp(in.dist, 0.0, 45.0));
+    let min_dist_value = smoothstep(0.0, camera.fog_near, clamp(in.dist, 0.0, camera.fog_near));
+    let max_dist_value = smoothstep(0.0, camera.fog_far, clamp(in.dist, 0.0, camera.fog_far));
     let dark_value = clamp(1.0 - in.light, max_dist_value, min_dist_value);
 
     let output = mix(in_color, fog_color, dark_value);
@@ -0,0 +1,220 @@
+use crate::{analog_input, controls, seed, sub, util};
+use std::io::Write;
+
+// Records what steered the sub on every simulation step, or plays a recording back.
+// The log starts with the world seed, which with the inputs is everything a session
+// depends on (see the README for what isn't covered).
+//
+// The file is a line per step the inputs changed on:
+// `<step> <held actions, comma separated, or -> <pitch> <yaw> <roll> <throttle>`
+// Native only, there's nowhere to keep a log on the web.
+
+const RECORD_ARG: &str = "--record";
+const REPLAY_ARG: &str = "--replay";
+
+const SEED_PREFIX: &str = "seed ";
+const NO_ACTIONS: &str = "-";
+
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct StepInput {
+    held: Vec<controls::Action>,
+    analog: analog_input::AnalogAxes,
+}
+impl StepInput {
+    fn to_line(&self, step: u64) -> String {
+        let held = if self.held.is_empty() {
+            NO_ACTIONS.to_string()
+        } else {
+            self.held.iter().map(|action| action.name()).collect::<Vec<_>>().join(",")
+        };
+        // floats print with as many digits as it takes to read back the same value
+        let analog = self.analog;
+        format!("{} {} {} {} {} {}", step, held, analog.pitch, analog.yaw, analog.roll, analog.throttle)
+    }
+
+    fn from_line(line: &str) -> Result<(u64, Self), String> {
+        let parts: Vec<_> = line.split_whitespace().collect();
+        let [step, held, pitch, yaw, roll, throttle] = parts[..] else {
+            return Err(format!("expected 6 values, got {}", parts.len()));
+        };
+
+        let step = step.parse().map_err(|_e| format!("bad step {}", step))?;
+        let held = if held == NO_ACTIONS {
+            Vec::new()
+        } else {
+            held.split(',')
+                .map(|name| controls::Action::from_name(name).ok_or_else(|| format!("unknown action {}", name)))
+                .collect::<Result<_, _>>()?
+        };
+        let axis = |text: &str| text.parse::<f32>().map_err(|_e| format!("bad axis {}", text));
+        let analog = analog_input::AnalogAxes {
+            pitch: axis(pitch)?,
+            yaw: axis(yaw)?,
+            roll: axis(roll)?,
+            throttle: axis(throttle)?,
+        };
+
+        Ok((step, Self { held, analog }))
+    }
+}
+
+enum Mode {
+    Off,
+    Recording {
+        file: std::io::LineWriter<std::fs::File>,
+        last: Option<StepInput>,
+    },
+    Replaying {
+        steps: Vec<(u64, StepInput)>,
+        next: usize,
+        current: StepInput,
+    },
+}
+
+pub struct InputLog {
+    mode: Mode,
+    // steps run so far
+    step: u64,
+}
+
+impl InputLog {
+    fn new(mode: Mode) -> Self {
+        Self { mode, step: 0 }
+    }
+
+    // The log and the seed the session runs on, which for a replay is the recorded one.
+    // `--record <file>` or `--replay <file>`
+    pub fn from_env() -> (Self, seed::WorldSeed) {
+        if let Some(path) = util::arg_value(REPLAY_ARG) {
+            let replay = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| Self::from_text(&text));
+            match replay {
+                Ok((log, seed)) => {
+                    log::info!("Replaying {}", path);
+                    return (log, seed);
+                }
+                Err(e) => log::error!("Couldn't read input log {}: {}", path, e),
+            }
+        } else if let Some(path) = util::arg_value(RECORD_ARG) {
+            let seed = seed::WorldSeed::from_env();
+            let recording = std::fs::File::create(&path).and_then(|file| {
+                let mut file = std::io::LineWriter::new(file);
+                writeln!(file, "{}{}", SEED_PREFIX, seed.value())?;
+                Ok(file)
+            });
+            return match recording {
+                Ok(file) => {
+                    log::info!("Recording to {}", path);
+                    (Self::new(Mode::Recording { file, last: None }), seed)
+                }
+                Err(e) => {
+                    log::error!("Couldn't create input log {}: {}", path, e);
+                    (Self::new(Mode::Off), seed)
+                }
+            };
+        }
+        (Self::new(Mode::Off), seed::WorldSeed::from_env())
+    }
+
+    fn from_text(text: &str) -> Result<(Self, seed::WorldSeed), String> {
+        let mut lines = text.lines().enumerate().filter(|(_i, line)| !line.trim().is_empty());
+
+        let seed = lines.next()
+            .and_then(|(_i, line)| line.strip_prefix(SEED_PREFIX))
+            .and_then(|seed| seed.trim().parse::<u32>().ok())
+            .ok_or_else(|| "the first line should be the seed".to_string())?;
+
+        let steps = lines
+            .map(|(i, line)| StepInput::from_line(line).map_err(|e| format!("line {}: {}", i + 1, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let log = Self::new(Mode::Replaying { steps, next: 0, current: StepInput::default() });
+        Ok((log, seed::WorldSeed::from_text(&seed.to_string())))
+    }
+
+    pub fn hud_text(&self) -> Option<&'static str> {
+        match self.mode {
+            Mode::Off => None,
+            Mode::Recording { .. } => Some("RECORDING"),
+            Mode::Replaying { .. } => Some("REPLAY"),
+        }
+    }
+
+    // Call once per step with what steers the sub, and steer it with what this gives back.
+    // Recording writes the inputs down when they change, replaying throws away the live
+    // inputs for the recording's. The log can't tell when the recording stopped, so its
+    // last inputs carry on until quitting.
+    pub fn step(&mut self, sub: &mut sub::Sub, analog: analog_input::AnalogAxes) -> analog_input::AnalogAxes {
+        let step = self.step;
+        self.step += 1;
+
+        match &mut self.mode {
+            Mode::Off => analog,
+            Mode::Recording { file, last } => {
+                let input = StepInput { held: sub.held_actions(), analog };
+                if last.as_ref() != Some(&input) {
+                    if let Err(e) = writeln!(file, "{}", input.to_line(step)) {
+                        log::error!("Couldn't write to the input log, stopping the recording: {}", e);
+                        self.mode = Mode::Off;
+                        return analog;
+                    }
+                    *last = Some(input);
+                }
+                analog
+            }
+            Mode::Replaying { steps, next, current } => {
+                while let Some((_step, input)) = steps.get(*next).filter(|(at, _input)| *at <= step) {
+                    *current = input.clone();
+                    *next += 1;
+                }
+
+                sub.hold_actions(&current.held);
+                current.analog
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_input_round_trip() {
+        let inputs = [
+            StepInput::default(),
+            StepInput {
+                held: vec![controls::Action::PitchUp, controls::Action::Throttle],
+                analog: analog_input::AnalogAxes { pitch: 0.1, yaw: -1.0 / 3.0, roll: 1e-7, throttle: -0.0 },
+            },
+        ];
+        for input in inputs {
+            let line = input.to_line(42);
+            assert_eq!(StepInput::from_line(&line), Ok((42, input)), "{}", line);
+        }
+        assert!(StepInput::default().to_line(0).contains(" - "));
+    }
+
+    #[test]
+    fn bad_lines() {
+        assert!(StepInput::from_line("1 - 0 0 0").is_err());
+        assert!(StepInput::from_line("x - 0 0 0 0").is_err());
+        assert!(StepInput::from_line("1 NotAnAction 0 0 0 0").is_err());
+        assert!(StepInput::from_line("1 - 0 nan? 0 0").is_err());
+    }
+
+    #[test]
+    fn from_text_needs_the_seed() {
+        assert!(InputLog::from_text("0 - 0 0 0 0\n").is_err());
+        assert!(InputLog::from_text("seed x\n0 - 0 0 0 0\n").is_err());
+
+        let Ok((log, seed)) = InputLog::from_text("seed 1234\n\n0 - 0 0 0 0\n5 Dig 0 0 0 1\n") else {
+            panic!("a seed line then steps should read");
+        };
+        assert_eq!(seed.value(), 1234);
+        let Mode::Replaying { steps, .. } = log.mode else { panic!("should be replaying"); };
+        assert_eq!(steps.iter().map(|(step, _input)| *step).collect::<Vec<_>>(), vec![0, 5]);
+    }
+}
@@ -1,16 +1,36 @@
+mod analog_input;
+pub mod biome;
 mod boid;
-mod boid_obj;
+mod boid_compute;
+mod boid_step;
 mod camera;
-mod chunk;
+mod camera_rig;
+pub mod chunk;
+mod chunk_cache;
+#[cfg(not(target_arch = "wasm32"))]
+mod chunk_workers;
 mod consts;
+mod controls;
+mod debug_camera;
+pub mod density;
 mod draw;
+#[cfg(not(target_arch = "wasm32"))]
+mod export;
+#[cfg(not(target_arch = "wasm32"))]
+mod input_log;
 mod marching_table;
 mod perlin_util;
+mod population;
+mod seed;
+mod settings;
+mod species;
 mod state;
 mod sub;
 mod sub_obj;
+mod terrain_edit;
 mod texture;
 mod timer;
+mod tuning_panel;
 mod util;
 mod world;
 
@@ -30,6 +50,11 @@ fn set_up_logger() {
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen(start))]
 pub async fn run() {
+    run_with_density(density::default_density).await;
+}
+
+// Same as run, but the terrain comes from a custom density source
+pub async fn run_with_density(density_builder: density::DensityBuilder) {
     set_up_logger();
 
     let event_loop = winit::event_loop::EventLoop::new();
@@ -63,7 +88,7 @@ pub async fn run() {
             .expect("Couldn't append canvas to document body.");
     }
 
-    let mut state = state::State::new(window).await;
+    let mut state = state::State::new(window, density_builder).await;
 
     event_loop.run(move |event, _, control_flow|
         match event {
@@ -85,6 +110,7 @@ pub async fn run() {
                     }
                 }
             },
+            winit::event::Event::DeviceEvent { ref event, .. } => state.device_input(event),
             winit::event::Event::RedrawRequested(window_id) if window_id == state.window().id() => {
                 state.update();
                 match state.render() {
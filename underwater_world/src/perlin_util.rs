@@ -24,6 +24,11 @@ pub fn perlin_3d_octaves(perlin: &noise::Perlin, point: [f64; 3], octaves: u32)
 pub fn iso_at(perlin: &noise::Perlin, x: f64, y: f64, z: f64) -> f32 {
     let corner = [x, y, z];
     let p = perlin_3d_octaves(perlin, corner, chunk::PERLIN_OCTAVES) as f32;
-    let adj_z = z as f32 * chunk::CHUNK_SIZE as f32 / chunk::MAX_HEIGHT;
-    adj_z + p - adj_z % chunk::ADJ_Z_MOD
+    shaped_iso(p, z, 0.0, 1.0, chunk::ADJ_Z_MOD)
+}
+
+// height term + scaled noise - terracing
+pub fn shaped_iso(p: f32, z: f64, height_offset: f32, noise_amp: f32, terrace: f32) -> f32 {
+    let adj_z = (z as f32 + height_offset) * chunk::CHUNK_SIZE as f32 / chunk::MAX_HEIGHT;
+    adj_z + p * noise_amp - adj_z % terrace
 }
\ No newline at end of file
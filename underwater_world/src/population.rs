@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use crate::{chunk, density, species, util, world};
+
+const SPAWN_TABLE_FILE: &str = "spawn_table.toml";
+
+// samples per axis when working out how much of a chunk is water
+const TERRAIN_SAMPLES: usize = 4;
+// chunks with less water than this (but some) count as caves
+const CAVE_WATER_FRACTION: f32 = 0.5;
+
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum TerrainKind {
+    OpenWater,
+    NearTerrain,
+    Cave,
+}
+
+// None if the chunk is all rock
+fn terrain_kind(pos: (i32, i32, i32), density: &dyn density::Density) -> Option<TerrainKind> {
+    let mut water = 0;
+    for x in 0..TERRAIN_SAMPLES {
+        for y in 0..TERRAIN_SAMPLES {
+            for z in 0..TERRAIN_SAMPLES {
+                // cell centers, in chunk units
+                let offset = |i: usize| (i as f64 + 0.5) / TERRAIN_SAMPLES as f64;
+                let iso = density.iso_at(
+                    pos.0 as f64 + offset(x),
+                    pos.1 as f64 + offset(y),
+                    pos.2 as f64 + offset(z),
+                );
+                if iso > chunk::ISO_LEVEL { water += 1; }
+            }
+        }
+    }
+
+    let water_fraction = water as f32 / TERRAIN_SAMPLES.pow(3) as f32;
+    if water == 0 {
+        None
+    } else if water_fraction >= 1.0 {
+        Some(TerrainKind::OpenWater)
+    } else if water_fraction < CAVE_WATER_FRACTION {
+        Some(TerrainKind::Cave)
+    } else {
+        Some(TerrainKind::NearTerrain)
+    }
+}
+
+
+#[derive(serde::Deserialize)]
+struct SpawnTable {
+    band: Vec<SpawnBand>,
+}
+
+// A [[band]] in fish/spawn_table.toml, schools per chunk for each kind of terrain
+#[derive(serde::Deserialize)]
+struct SpawnBand {
+    min_z: Option<f32>,
+    max_z: Option<f32>,
+    #[serde(default)]
+    open_water: f32,
+    #[serde(default)]
+    near_terrain: f32,
+    #[serde(default)]
+    cave: f32,
+}
+
+impl SpawnTable {
+    fn load() -> Self {
+        let text = species::fish_file(SPAWN_TABLE_FILE).expect("fish/spawn_table.toml is missing");
+        let text = std::str::from_utf8(&text).expect("fish/spawn_table.toml isn't UTF-8");
+        toml::from_str(text).unwrap_or_else(|e| panic!("Couldn't read fish/spawn_table.toml: {}", e))
+    }
+
+    fn schools_per_chunk(&self, z: f32, terrain: TerrainKind) -> f32 {
+        let band = self.band.iter().find(|band| {
+            !band.min_z.is_some_and(|min_z| z < min_z) && !band.max_z.is_some_and(|max_z| z >= max_z)
+        });
+        match (band, terrain) {
+            (None, _) => 0.0,
+            (Some(band), TerrainKind::OpenWater) => band.open_water,
+            (Some(band), TerrainKind::NearTerrain) => band.near_terrain,
+            (Some(band), TerrainKind::Cave) => band.cave,
+        }
+    }
+}
+
+
+// Which chunks around the sub have been given their fish.
+// A chunk is populated once World has loaded its collision and it's within population_dist of the sub,
+// and its fish are despawned once it is out of range or unloaded. The simulation waits for that
+// collision every step (see World::update_collision), so this happens on the same step every run.
+pub struct Population {
+    table: SpawnTable,
+    populated: HashSet<(i32, i32, i32)>,
+}
+
+impl Population {
+    pub fn new() -> Self {
+        Self {
+            table: SpawnTable::load(),
+            populated: HashSet::new(),
+        }
+    }
+
+    pub fn is_populated(&self, pos: (i32, i32, i32)) -> bool { self.populated.contains(&pos) }
+
+    // A populated chunk at least min_dist from the sub, so whatever spawns there isn't seen appearing
+    pub fn random_chunk_away_from(&self, sub_chunk: (i32, i32, i32), min_dist: i32, rng: &mut impl rand::Rng) -> Option<(i32, i32, i32)> {
+        let mut far: Vec<_> = self.populated.iter()
+            .filter(|pos| util::dist_sq(**pos, sub_chunk) >= min_dist * min_dist)
+            .collect();
+        if far.is_empty() { return None; }
+        // a HashSet's order changes from run to run, so the same rng picks the same chunk
+        far.sort_unstable();
+        Some(*far[rng.gen_range(0..far.len())])
+    }
+
+    // The chunks to spawn fish in, with how many schools each
+    pub fn update(
+        &mut self,
+        sub_chunk: (i32, i32, i32),
+        world: &world::World,
+        population_dist: i32,
+        max_populates: usize,
+        rng: &mut impl rand::Rng,
+    ) -> Vec<((i32, i32, i32), usize)> {
+        let dist_sq = population_dist * population_dist;
+
+        self.populated.retain(|pos| util::dist_sq(*pos, sub_chunk) <= dist_sq && world.has_collision(*pos));
+
+        // closest first, so the fish in view come in before the ones in the fog
+        let mut to_populate = Vec::new();
+        for x in -population_dist..=population_dist {
+            for y in -population_dist..=population_dist {
+                for z in world::MIN_Z..=world::MAX_Z {
+                    let pos = (sub_chunk.0 + x, sub_chunk.1 + y, z);
+                    let dist = util::dist_sq(pos, sub_chunk);
+                    if dist <= dist_sq && !self.populated.contains(&pos) && world.has_collision(pos) {
+                        to_populate.push((dist, pos));
+                    }
+                }
+            }
+        }
+        to_populate.sort_unstable();
+
+        let mut spawn = Vec::new();
+        for (_dist, pos) in to_populate.into_iter().take(max_populates) {
+            self.populated.insert(pos);
+
+            let Some(terrain) = terrain_kind(pos, world.density()) else { continue; };
+            let center_z = (pos.2 as f32 + 0.5) * chunk::CHUNK_SIZE as f32;
+            let schools = self.table.schools_per_chunk(center_z, terrain);
+
+            // the fraction is the chance of one more
+            let count = schools.floor() as usize + (rng.gen::<f32>() < schools.fract()) as usize;
+            if count > 0 {
+                spawn.push((pos, count));
+            }
+        }
+
+        spawn
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::util;
+
+// FNV-1a, used instead of std's hasher so phrases give the same seed on every platform/build
+const FNV_OFFSET_BASIS: u32 = 0x811c9dc5;
+const FNV_PRIME: u32 = 0x01000193;
+
+#[cfg(not(target_arch = "wasm32"))]
+const SEED_ARG: &str = "--seed";
+#[cfg(target_arch = "wasm32")]
+const SEED_QUERY_PARAM: &str = "seed";
+
+
+pub struct WorldSeed {
+    value: u32,
+    phrase: Option<String>,
+}
+impl WorldSeed {
+    // numbers are used as is, anything else is hashed as a phrase
+    pub fn from_text(text: &str) -> Self {
+        let text = text.trim();
+        match text.parse::<u32>() {
+            Ok(value) => Self { value, phrase: None },
+            Err(_) => Self {
+                value: hash_phrase(text),
+                phrase: Some(text.to_string()),
+            },
+        }
+    }
+
+    pub fn random() -> Self {
+        let value = (instant::now().round() % u32::MAX as f64) as u32;
+        Self { value, phrase: None }
+    }
+
+    // native: `--seed <number or phrase>`, web: `?seed=<number or phrase>`
+    pub fn from_env() -> Self {
+        match seed_text_from_env() {
+            Some(text) if !text.trim().is_empty() => Self::from_text(&text),
+            _ => Self::random(),
+        }
+    }
+
+    pub fn value(&self) -> u32 { self.value }
+
+    pub fn hud_text(&self) -> String {
+        match &self.phrase {
+            Some(phrase) => format!("SEED: {} (\"{}\")", self.value, phrase),
+            None => format!("SEED: {}", self.value),
+        }
+    }
+}
+
+fn hash_phrase(phrase: &str) -> u32 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in phrase.bytes() {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn seed_text_from_env() -> Option<String> {
+    util::arg_value(SEED_ARG)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn seed_text_from_env() -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    let params = web_sys::UrlSearchParams::new_with_str(&search).ok()?;
+    params.get(SEED_QUERY_PARAM)
+}
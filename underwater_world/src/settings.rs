@@ -0,0 +1,146 @@
+use crate::{boid, sub, world};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::util;
+
+#[cfg(not(target_arch = "wasm32"))]
+const SETTINGS_ARG: &str = "--settings";
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_SETTINGS_FILE: &str = "settings.toml";
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "underwater_world_settings";
+
+// seconds between checking the settings for changes
+const RELOAD_CHECK_INTERVAL: f32 = 1.0;
+
+
+// Tuning values, every one defaults to the const it replaced.
+// In the settings file each part is a section ([world], [boids], [sub]) and anything left out keeps its default.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Settings {
+    pub world: world::WorldSettings,
+    pub boids: boid::BoidSettings,
+    pub sub: sub::SubSettings,
+}
+
+impl Settings {
+    fn from_toml(text: &str) -> Result<Self, String> {
+        let settings: Settings = toml::from_str(text).map_err(|e| e.to_string())?;
+        settings.check()?;
+        Ok(settings)
+    }
+
+    // values that would panic or make no sense, the rest is up to whoever is tuning
+    fn check(&self) -> Result<(), String> {
+        if self.world.view_dist < 1 {
+            return Err("world.view_dist must be at least 1".to_string());
+        }
+        if self.boids.population_dist < 1 {
+            return Err("boids.population_dist must be at least 1".to_string());
+        }
+        if self.sub.min_speed > self.sub.max_speed {
+            return Err("sub.min_speed can't be more than sub.max_speed".to_string());
+        }
+        if self.sub.middle_speed <= 0.0 || self.sub.hull_radius <= 0.0 {
+            return Err("sub.middle_speed and sub.hull_radius must be more than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+// Where the settings come from, checked every so often so they can be changed while running.
+// native: `--settings=<file>`, otherwise `settings.toml` if there is one
+// web: the same TOML text, saved in localStorage
+pub struct SettingsSource {
+    #[cfg(not(target_arch = "wasm32"))]
+    path: std::path::PathBuf,
+    #[cfg(not(target_arch = "wasm32"))]
+    last_modified: Option<std::time::SystemTime>,
+    #[cfg(target_arch = "wasm32")]
+    last_text: Option<String>,
+
+    check_timer: f32,
+}
+
+impl SettingsSource {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_env() -> Self {
+        let path = util::arg_value(SETTINGS_ARG).unwrap_or_else(|| DEFAULT_SETTINGS_FILE.to_string());
+
+        Self {
+            path: std::path::PathBuf::from(path),
+            last_modified: None,
+            check_timer: 0.0,
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_env() -> Self {
+        Self {
+            last_text: None,
+            check_timer: 0.0,
+        }
+    }
+
+    // The defaults if there is nothing to load or it isn't valid
+    pub fn load(&mut self) -> Settings {
+        let text = match self.changed_text() {
+            Some(text) => text,
+            None => return Settings::default(),
+        };
+
+        match Settings::from_toml(&text) {
+            Ok(settings) => {
+                log::info!("Loaded settings");
+                settings
+            }
+            Err(e) => {
+                log::error!("Couldn't read the settings, using the defaults: {}", e);
+                Settings::default()
+            }
+        }
+    }
+
+    // Some if the settings changed since they were last loaded (and are valid)
+    pub fn poll(&mut self, delta: f32) -> Option<Settings> {
+        self.check_timer -= delta;
+        if self.check_timer > 0.0 { return None; }
+        self.check_timer = RELOAD_CHECK_INTERVAL;
+
+        let text = self.changed_text()?;
+        match Settings::from_toml(&text) {
+            Ok(settings) => {
+                log::info!("Reloaded settings");
+                Some(settings)
+            }
+            Err(e) => {
+                log::error!("Couldn't reload the settings, keeping the old ones: {}", e);
+                None
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn changed_text(&mut self) -> Option<String> {
+        let modified = std::fs::metadata(&self.path).and_then(|metadata| metadata.modified()).ok()?;
+        if self.last_modified == Some(modified) { return None; }
+        self.last_modified = Some(modified);
+
+        match std::fs::read_to_string(&self.path) {
+            Ok(text) => Some(text),
+            Err(e) => {
+                log::error!("Couldn't read settings file {}: {}", self.path.display(), e);
+                None
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn changed_text(&mut self) -> Option<String> {
+        let storage = web_sys::window()?.local_storage().ok()??;
+        let text = storage.get_item(STORAGE_KEY).ok()??;
+        if self.last_text.as_ref() == Some(&text) { return None; }
+        self.last_text = Some(text.clone());
+        Some(text)
+    }
+}
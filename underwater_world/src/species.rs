@@ -0,0 +1,315 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::{biome, boid_step, draw};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::util;
+
+const SPECIES_FILE: &str = "species.toml";
+#[cfg(not(target_arch = "wasm32"))]
+const FISH_DIR_ARG: &str = "--fish";
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_FISH_DIR: &str = "fish";
+
+// FISH_FILES: (file name, contents) for everything in fish/, from build.rs
+include!(concat!(env!("OUT_DIR"), "/fish_files.rs"));
+
+// native: from the fish folder if it's there, so fish can be changed without a rebuild.
+// Anything missing from it, and everything on the web, comes from the copy built into the game.
+pub fn fish_file(name: &str) -> Option<Cow<'static, [u8]>> {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(bytes) = read_fish_dir(name) {
+        return Some(Cow::Owned(bytes));
+    }
+    FISH_FILES.iter().find(|(file_name, _bytes)| *file_name == name).map(|(_file_name, bytes)| Cow::Borrowed(*bytes))
+}
+
+// `--fish <dir>`, otherwise `fish` in the working directory
+#[cfg(not(target_arch = "wasm32"))]
+fn read_fish_dir(name: &str) -> Option<Vec<u8>> {
+    let arg_dir = util::arg_value(FISH_DIR_ARG);
+    let path = std::path::Path::new(arg_dir.as_deref().unwrap_or(DEFAULT_FISH_DIR)).join(name);
+
+    match std::fs::read(&path) {
+        Ok(bytes) => Some(bytes),
+        // only worth an error when the folder was asked for
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && arg_dir.is_none() => None,
+        Err(e) => {
+            log::error!("Couldn't read fish file {}, using the built in one: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+
+#[derive(serde::Deserialize)]
+struct SpeciesFile {
+    species: Vec<SpeciesDef>,
+}
+
+// One kind of fish, a [[species]] entry in fish/species.toml (which describes every value)
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct SpeciesDef {
+    pub name: String,
+    mesh: String,
+    texture: String,
+    axes: [String; 3],
+    scale: f32,
+    speed: [f32; 2],
+    pub perception_radius: f32,
+    pub flock_size: usize,
+    pub min_z: Option<f32>,
+    pub max_z: Option<f32>,
+    #[serde(default)]
+    biomes: HashMap<String, f32>,
+    #[serde(default)]
+    hunts: Vec<String>,
+    hunt_speed: Option<f32>,
+    panic_speed: Option<f32>,
+    #[serde(default)]
+    pub sub_reaction: SubReaction,
+}
+
+// What a species does when it sees the sub
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubReaction {
+    // only keeps its distance, like from any other species
+    #[default]
+    KeepsAway,
+    // flees when the sub comes at it fast
+    Skittish,
+    // follows behind the sub
+    Curious,
+    // swims into the headlight's beam
+    DrawnToLight,
+}
+
+// How one species treats another when it sees it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interaction {
+    // its own species
+    Flocks,
+    // keeps its distance
+    Avoids,
+    Hunts,
+    Flees,
+}
+
+// interactions[a][b] is how species a treats species b
+pub fn interaction_matrix(defs: &[&SpeciesDef]) -> Vec<Vec<Interaction>> {
+    for def in defs.iter() {
+        for prey in def.hunts.iter() {
+            if !defs.iter().any(|other| other.name == *prey) {
+                log::warn!("Species {} hunts {}, which isn't loaded", def.name, prey);
+            }
+        }
+    }
+
+    defs.iter().enumerate().map(|(a, def_a)| {
+        defs.iter().enumerate().map(|(b, def_b)| {
+            if a == b {
+                Interaction::Flocks
+            } else if def_a.hunts.contains(&def_b.name) {
+                Interaction::Hunts
+            } else if def_b.hunts.contains(&def_a.name) {
+                Interaction::Flees
+            } else {
+                Interaction::Avoids
+            }
+        }).collect()
+    }).collect()
+}
+
+impl SpeciesDef {
+    pub fn min_speed(&self) -> f32 { self.speed[0] }
+    pub fn max_speed(&self) -> f32 { self.speed[1] }
+    pub fn middle_speed(&self) -> f32 { (self.speed[0] + self.speed[1]) / 2.0 }
+    pub fn hunt_speed(&self) -> f32 { self.hunt_speed.unwrap_or(self.speed[1]) }
+    pub fn panic_speed(&self) -> f32 { self.panic_speed.unwrap_or(self.speed[1]) }
+    pub fn scale(&self) -> f32 { self.scale }
+
+    // relative to the other species, when picking which species a school in the biome is
+    pub fn biome_weight(&self, biome: biome::Biome) -> f32 {
+        self.biomes.get(biome.key()).copied().unwrap_or(1.0)
+    }
+
+    // values that would panic or make no sense
+    fn check(&self) -> Result<(), String> {
+        if self.speed[0] <= 0.0 || self.speed[0] > self.speed[1] {
+            return Err("speed must be [min, max] with 0 < min <= max".to_string());
+        }
+        if self.hunt_speed.is_some_and(|hunt_speed| hunt_speed < self.speed[1]) {
+            return Err("hunt_speed can't be less than the max speed".to_string());
+        }
+        if self.panic_speed.is_some_and(|panic_speed| panic_speed < self.speed[1]) {
+            return Err("panic_speed can't be less than the max speed".to_string());
+        }
+        if self.hunts.contains(&self.name) {
+            return Err("can't hunt its own species".to_string());
+        }
+        if self.perception_radius <= 0.0 {
+            return Err("perception_radius must be more than 0".to_string());
+        }
+        if self.flock_size < 1 {
+            return Err("flock_size must be at least 1".to_string());
+        }
+        if self.scale <= 0.0 {
+            return Err("scale must be more than 0".to_string());
+        }
+        if let (Some(min_z), Some(max_z)) = (self.min_z, self.max_z) {
+            if min_z > max_z {
+                return Err("min_z can't be more than max_z".to_string());
+            }
+        }
+        for (biome, weight) in self.biomes.iter() {
+            if !biome::ALL_BIOMES.iter().any(|b| b.key() == biome) {
+                return Err(format!("unknown biome {}", biome));
+            }
+            if *weight < 0.0 {
+                return Err(format!("biome weight for {} can't be negative", biome));
+            }
+        }
+        // otherwise it would never spawn
+        if biome::ALL_BIOMES.iter().all(|biome| self.biome_weight(*biome) <= 0.0) {
+            return Err("needs a biome weight above 0".to_string());
+        }
+        Ok(())
+    }
+
+    fn load_model(&self) -> Result<FishModel, String> {
+        let texture = fish_file(&self.texture).ok_or_else(|| format!("no texture file {}", self.texture))?;
+        let obj = fish_file(&self.mesh).ok_or_else(|| format!("no mesh file {}", self.mesh))?;
+        let obj = std::str::from_utf8(&obj).map_err(|e| format!("{}: {}", self.mesh, e))?;
+
+        let mut axes = [(0, 1.0); 3];
+        for (axis, name) in axes.iter_mut().zip(self.axes.iter()) {
+            *axis = parse_axis(name).ok_or_else(|| format!("unknown axis {}, expected x, y, z, -x, -y or -z", name))?;
+        }
+
+        let (verts, inds) = parse_obj(obj, axes, self.scale).map_err(|e| format!("{}: {}", self.mesh, e))?;
+        Ok(FishModel { verts, inds, texture })
+    }
+}
+
+pub struct FishModel {
+    pub verts: Vec<draw::VertTex>,
+    pub inds: Vec<u16>,
+    // the encoded image
+    pub texture: Cow<'static, [u8]>,
+}
+
+// Every species in fish/species.toml with its model, the invalid ones are left out with an error
+pub fn load_all() -> Vec<(SpeciesDef, FishModel)> {
+    let text = fish_file(SPECIES_FILE).expect("fish/species.toml is missing");
+    let text = std::str::from_utf8(&text).expect("fish/species.toml isn't UTF-8");
+    let file: SpeciesFile = toml::from_str(text).unwrap_or_else(|e| panic!("Couldn't read fish/species.toml: {}", e));
+
+    let mut loaded: Vec<(SpeciesDef, FishModel)> = Vec::new();
+    for def in file.species {
+        if loaded.iter().any(|(other, _model)| other.name == def.name) {
+            log::error!("Leaving out species {}: there is already one with that name", def.name);
+            continue;
+        }
+        if loaded.len() >= boid_step::MAX_SPECIES {
+            log::error!("Leaving out species {}: at most {} species are supported", def.name, boid_step::MAX_SPECIES);
+            continue;
+        }
+        match def.check().and_then(|_| def.load_model()) {
+            Ok(model) => loaded.push((def, model)),
+            Err(e) => log::error!("Leaving out species {}: {}", def.name, e),
+        }
+    }
+
+    assert!(!loaded.is_empty(), "fish/species.toml has no valid species");
+    loaded
+}
+
+// "x", "-y", ... to (source axis, sign)
+fn parse_axis(name: &str) -> Option<(usize, f32)> {
+    let (sign, axis) = match name.strip_prefix('-') {
+        Some(axis) => (-1.0, axis),
+        None => (1.0, name),
+    };
+    let i = match axis {
+        "x" => 0,
+        "y" => 1,
+        "z" => 2,
+        _ => return None,
+    };
+    Some((i, sign))
+}
+
+// Verts are shared by (v, vt) pair, then scaled so the furthest one is `scale` from the origin along an axis
+fn parse_obj(obj: &str, axes: [(usize, f32); 3], scale: f32) -> Result<(Vec<draw::VertTex>, Vec<u16>), String> {
+    let mut vert_poses = Vec::new();
+    let mut vert_txs = Vec::new();
+
+    let mut verts = Vec::new();
+    let mut vert_pairs = HashMap::new(); // (v, vt) -> ind
+    let mut inds = Vec::new();
+
+    let mut highest_v: f32 = 0.0;
+
+    for (line_i, line) in obj.lines().enumerate() {
+        let bad_line = || format!("bad line {}: {}", line_i + 1, line);
+        let mut split = line.split_whitespace();
+        let first = split.next();
+        match first {
+            Some("v") => {
+                let mut v = [0.0; 3];
+                for c in v.iter_mut() {
+                    *c = split.next().and_then(|s| s.parse::<f32>().ok()).ok_or_else(bad_line)?;
+                }
+
+                let pos = axes.map(|(i, sign)| v[i] * sign);
+                vert_poses.push(pos);
+
+                highest_v = highest_v.max(v[0].abs()).max(v[1].abs()).max(v[2].abs());
+            }
+            Some("vt") => {
+                let x: f32 = split.next().and_then(|s| s.parse().ok()).ok_or_else(bad_line)?;
+                let y: f32 = split.next().and_then(|s| s.parse().ok()).ok_or_else(bad_line)?;
+                vert_txs.push([x, y]);
+            }
+            Some("f") => {
+                // 0 (i) (i + 1)  [for i in 1..(n - 2)]
+                let remaining = split.collect::<Vec<&str>>();
+                let n = remaining.len();
+                if n < 3 { return Err(bad_line()); }
+                for i in 1..(n - 1) {
+                    for j in [0, i, i + 1] {
+                        let mut piece = remaining[j].split('/');
+                        let v = piece.next().and_then(|s| s.parse::<usize>().ok()).ok_or_else(bad_line)?;
+                        let vt = piece.next().and_then(|s| s.parse::<usize>().ok()).ok_or_else(bad_line)?;
+                        let (v, vt) = (v.wrapping_sub(1), vt.wrapping_sub(1));
+                        if v >= vert_poses.len() || vt >= vert_txs.len() { return Err(bad_line()); }
+
+                        let ind = *vert_pairs.entry((v, vt)).or_insert_with(|| {
+                            verts.push(draw::VertTex::new(vert_poses[v], vert_txs[vt]));
+                            verts.len() - 1
+                        });
+                        inds.push(ind);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if inds.is_empty() {
+        return Err("no faces".to_string());
+    }
+    // the index buffers are Uint16
+    if verts.len() > u16::MAX as usize {
+        return Err(format!("{} verts, at most {} are supported", verts.len(), u16::MAX));
+    }
+
+    verts.iter_mut().for_each(|v| {
+        v.pos[0] *= scale / highest_v;
+        v.pos[1] *= scale / highest_v;
+        v.pos[2] *= scale / highest_v;
+    });
+
+    Ok((verts, inds.into_iter().map(|ind| ind as u16).collect()))
+}
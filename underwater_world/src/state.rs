@@ -1,10 +1,19 @@
-use crate::{boid, camera, consts, draw, sub, texture, timer, util, world};
+use crate::{analog_input, biome, boid, boid_compute, camera, camera_rig, chunk, chunk_cache, controls, debug_camera, density, draw, seed, settings, sub, terrain_edit, texture, timer, tuning_panel, util, world};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{export, input_log};
+use crate::density::Density;
+use rand::SeedableRng;
+use std::collections::HashSet;
+use std::sync::Arc;
 use wgpu::util::DeviceExt;
 
 const TEXT_SIZE: f32 = 20.0 / 600.0;
 const TEXT_SPACING: f32 = 10.0 / 600.0;
 const FPSES_TO_KEEP: f32 = 2.0; // seconds
 
+#[cfg(not(target_arch = "wasm32"))]
+const EXPORT_BOX_HALF_SIZE: f32 = 2.0 * chunk::CHUNK_SIZE as f32;
+
 pub struct State<'a> {
     surface: wgpu::Surface,
     device: wgpu::Device,
@@ -23,17 +32,36 @@ pub struct State<'a> {
     camera: camera::Camera,
     camera_buffer: wgpu::Buffer,
     camera_bind_group: wgpu::BindGroup,
+    camera_rigs: camera_rig::CameraRigs,
+    debug_camera: debug_camera::DebugCamera,
 
     fps_counter: timer::FpsCounter,
     fpses: Vec<f32>,
+    fixed_step: timer::FixedStep,
 
-    perlin: noise::Perlin,
+    seed: seed::WorldSeed,
+    // Everything random in the simulation comes from here, seeded by the world seed,
+    // so the seed and the input log replay a session exactly
+    rng: rand::rngs::StdRng,
+    #[cfg(not(target_arch = "wasm32"))]
+    input_log: input_log::InputLog,
+    density: Arc<terrain_edit::EditedDensity>,
 
     sub: sub::Sub,
+    analog_input: analog_input::AnalogInput,
+
+    bindings: controls::Bindings,
+    // actions whose keys are down, so toggles ignore key repeats
+    held_actions: HashSet<controls::Action>,
 
     world: world::World,
+    settings_source: settings::SettingsSource,
 
     boid_manager: boid::BoidManager,
+    tuning_panel: tuning_panel::TuningPanel,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    modifiers: winit::event::ModifiersState,
 
     // The window must be declared after the surface so
     // it gets dropped after it as the surface contains
@@ -42,7 +70,7 @@ pub struct State<'a> {
 }
 
 impl<'a> State<'a> {
-    pub async fn new(window: winit::window::Window) -> Self {
+    pub async fn new(window: winit::window::Window, density_builder: density::DensityBuilder) -> Self {
         let size = window.inner_size();
 
         //--------------------------------------------------------------------//
@@ -93,8 +121,14 @@ impl<'a> State<'a> {
                 &wgpu::DeviceDescriptor {
                     // features: wgpu::Features::POLYGON_MODE_LINE,
                     features: wgpu::Features::empty(),
-                    limits: if cfg!(target_arch = "wasm32") {
+                    limits: if adapter.get_info().backend == wgpu::Backend::Gl && cfg!(target_arch = "wasm32") {
                         wgpu::Limits::downlevel_webgl2_defaults()
+                    } else if cfg!(target_arch = "wasm32") {
+                        // WebGPU, with the storage buffers for the boid step
+                        wgpu::Limits {
+                            max_storage_buffers_per_shader_stage: 5,
+                            ..wgpu::Limits::downlevel_defaults()
+                        }
                     } else {
                         wgpu::Limits::default()
                     },
@@ -138,7 +172,11 @@ impl<'a> State<'a> {
         //--------------------------------------------------------------------//
 
         //--------------------------------------------------------------------//
-        let camera = camera::Camera::new(&config);
+        let mut settings_source = settings::SettingsSource::from_env();
+        let settings = settings_source.load();
+
+        let camera = camera::Camera::new(&config, settings.world.view_dist);
+        let camera_rigs = camera_rig::CameraRigs::new(&camera);
 
         let camera_buffer = device.create_buffer_init(
             &wgpu::util::BufferInitDescriptor {
@@ -219,7 +257,7 @@ impl<'a> State<'a> {
             vertex: wgpu::VertexState {
                 module: &terrain_shader,
                 entry_point: "vs_main",
-                buffers: &[draw::VertColor::desc()],
+                buffers: &[draw::VertColorNormal::desc()],
             },
             fragment: Some(wgpu::FragmentState {
                 module: &terrain_shader,
@@ -355,15 +393,25 @@ impl<'a> State<'a> {
         //--------------------------------------------------------------------//
 
         //--------------------------------------------------------------------//
-        let seed = (instant::now().round() % u32::MAX as f64) as u32;
-        let perlin = noise::Perlin::new(seed);
-
-        let sub = sub::Sub::new(&device, &perlin);
+        #[cfg(not(target_arch = "wasm32"))]
+        let (input_log, seed) = input_log::InputLog::from_env();
+        #[cfg(target_arch = "wasm32")]
+        let seed = seed::WorldSeed::from_env();
+        log::info!("{}", seed.hud_text());
+        let rng = rand::rngs::StdRng::seed_from_u64(seed.value() as u64);
+        let density = Arc::new(terrain_edit::EditedDensity::new(density_builder(seed.value())));
+
+        // Only used for the sub's color variation, not the terrain
+        let perlin = noise::Perlin::new(seed.value());
+        let sub = sub::Sub::new(&device, &perlin, settings.sub);
         
-        let mut world = world::World::new();
+        let chunk_cache = chunk_cache::ChunkCache::from_env(density.cache_id(), seed.value());
+        let mut world = world::World::new(density.clone(), chunk_cache, settings.world);
         world.update_nearby(&sub, &camera);
 
-        let boid_manager = boid::BoidManager::new(&sub, &perlin, &device, &queue, &texture_bind_group_layout);
+        let boid_compute_supported = boid_compute::supported(&adapter, &device);
+        log::info!("Boid step on the {}", if boid_compute_supported { "GPU" } else { "CPU" });
+        let boid_manager = boid::BoidManager::new(settings.boids, &device, &queue, &texture_bind_group_layout, boid_compute_supported);
         //--------------------------------------------------------------------//
 
         Self {
@@ -381,12 +429,26 @@ impl<'a> State<'a> {
             camera,
             camera_buffer,
             camera_bind_group,
+            camera_rigs,
+            debug_camera: debug_camera::DebugCamera::new(),
             fps_counter,
             fpses,
-            perlin,
+            fixed_step: timer::FixedStep::new(),
+            seed,
+            rng,
+            #[cfg(not(target_arch = "wasm32"))]
+            input_log,
+            density,
             sub,
+            analog_input: analog_input::AnalogInput::new(),
+            bindings: controls::Bindings::from_env(),
+            held_actions: HashSet::new(),
             world,
             boid_manager,
+            tuning_panel: tuning_panel::TuningPanel::new(),
+            settings_source,
+            #[cfg(not(target_arch = "wasm32"))]
+            modifiers: winit::event::ModifiersState::empty(),
         }
     }
 
@@ -406,7 +468,122 @@ impl<'a> State<'a> {
     }
 
     pub fn input(&mut self, event: &winit::event::WindowEvent) -> bool {
-        self.sub.process_events(event)
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let winit::event::WindowEvent::ModifiersChanged(modifiers) = event {
+                self.modifiers = *modifiers;
+            }
+        }
+
+        let action = self.bindings.action(event);
+        if let Some((action, pressed)) = action {
+            // held keys repeat their pressed events, toggles only happen on the first one
+            let first_press = pressed && !self.held_actions.contains(&action);
+            if pressed {
+                self.held_actions.insert(action);
+            } else {
+                self.held_actions.remove(&action);
+            }
+
+            match action {
+                controls::Action::ExportObj | controls::Action::ExportPly => {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if first_press {
+                        let format = match action {
+                            controls::Action::ExportObj => export::ExportFormat::Obj,
+                            _ => export::ExportFormat::Ply,
+                        };
+                        self.export_terrain(format, self.modifiers.shift());
+                    }
+                    return true;
+                }
+                controls::Action::Cancel => {
+                    if pressed && self.analog_input.mouse_steering() {
+                        self.analog_input.set_mouse_steering(false, &self.window);
+                        return true;
+                    }
+                    if first_press && self.tuning_panel.open() {
+                        self.tuning_panel.toggle();
+                        return true;
+                    }
+                }
+                controls::Action::CycleCamera => {
+                    if first_press {
+                        let sub_pose = self.sub.interpolated_pose(self.fixed_step.alpha());
+                        self.camera_rigs.next_rig(&self.camera, &sub_pose);
+                    }
+                    return true;
+                }
+                controls::Action::MouseSteering => {
+                    if first_press {
+                        let enabled = !self.analog_input.mouse_steering();
+                        self.analog_input.set_mouse_steering(enabled, &self.window);
+                    }
+                    return true;
+                }
+                controls::Action::DebugCamera => {
+                    if first_press {
+                        self.debug_camera.toggle(&self.camera);
+                        self.sub.release_keys();
+                    }
+                    return true;
+                }
+                controls::Action::TuningPanel => {
+                    if first_press {
+                        self.tuning_panel.toggle();
+                    }
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some((action, pressed)) = action {
+            if self.tuning_panel.process_action(action, pressed, &mut self.boid_manager) {
+                return true;
+            }
+        }
+        if self.debug_camera.active() {
+            return match action {
+                Some((action, pressed)) => self.debug_camera.process_action(action, pressed),
+                None => self.debug_camera.process_events(event),
+            };
+        }
+        if self.camera_rigs.process_events(event) {
+            return true;
+        }
+        match action {
+            Some((action, pressed)) => self.sub.process_action(action, pressed),
+            None => false,
+        }
+    }
+
+    pub fn device_input(&mut self, event: &winit::event::DeviceEvent) {
+        self.analog_input.process_device_event(event);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn export_terrain(&self, format: export::ExportFormat, box_around_sub: bool) {
+        let sub_chunk = self.sub.chunk();
+        let region = if box_around_sub {
+            let half_size = cgmath::Vector3::new(EXPORT_BOX_HALF_SIZE, EXPORT_BOX_HALF_SIZE, EXPORT_BOX_HALF_SIZE);
+            export::ExportRegion::Bounds {
+                min: self.sub.pos() - half_size,
+                max: self.sub.pos() + half_size,
+            }
+        } else {
+            // about the chunks that are built at full detail
+            let dist = self.world.settings().full_lod_dist.floor() as i32;
+            export::ExportRegion::AroundChunk { center: sub_chunk, dist }
+        };
+
+        let file_name = format!("terrain_{}_{}_{}_{}.{}", self.seed.value(), sub_chunk.0, sub_chunk.1, sub_chunk.2, format.extension());
+        let path = std::path::Path::new(&file_name);
+
+        match export::export_to_file(&self.world, region, format, path) {
+            Ok(mesh) => log::info!("Exported {} verts and {} tris to {}", mesh.num_verts(), mesh.num_tris(), file_name),
+            Err(e) => log::error!("Failed to export terrain to {}: {}", file_name, e),
+        }
     }
 
     pub fn update(&mut self) {
@@ -425,6 +602,12 @@ impl<'a> State<'a> {
 
 
         let delta = self.fps_counter.update();
+
+        if let Some(settings) = self.settings_source.poll(delta as f32) {
+            self.world.set_settings(settings.world);
+            self.boid_manager.set_settings(settings.boids);
+            self.sub.set_settings(settings.sub);
+        }
         self.fpses.push(self.fps_counter.fps() as f32);
         
 
@@ -439,15 +622,51 @@ impl<'a> State<'a> {
         }
         self.fpses = new_fpses;
 
-        let reset = self.sub.update(&self.queue, delta as f32);
-        self.sub.update_camera(&mut self.camera, delta as f32);
+        let steps = self.fixed_step.advance(delta);
+        let step_delta = timer::STEP as f32;
 
-        self.world.update(&self.sub, &self.camera, reset, &self.perlin, &self.device);
+        let mut reset = false;
+        for step in 0..steps {
+            // nothing moves until everything it could touch is built, so the chunks loading
+            // faster or slower doesn't change what happens
+            if !self.world.update_collision(self.sub.chunk(), self.boid_manager.collision_dist()) {
+                self.fixed_step.defer(steps - step);
+                break;
+            }
+
+            // the mouse is shared out over the steps that are left, the debug camera takes over the controls
+            let analog = self.analog_input.axes(step_delta, steps - step);
+            let analog = if self.debug_camera.active() { analog_input::AnalogAxes::default() } else { analog };
+            #[cfg(not(target_arch = "wasm32"))]
+            let analog = self.input_log.step(&mut self.sub, analog);
+            reset |= self.sub.update(&self.world, analog, step_delta);
+
+            if let Some(brush) = self.sub.take_drill_brush() {
+                self.density.apply(brush);
+                self.world.remesh_around(brush.center, brush.radius);
+            }
+
+            self.boid_manager.update(&self.device, &self.queue, &self.sub, &self.world, &mut self.rng, step_delta);
+        }
+
+        // drawn part way from the last step to the next
+        let alpha = self.fixed_step.alpha();
+        let sub_pose = self.sub.interpolated_pose(alpha);
+        self.sub.write_instances(&self.queue, &sub_pose);
+        self.camera_rigs.update(&sub_pose, &mut self.camera, delta as f32);
+        sub_pose.update_light(&mut self.camera);
+        self.camera.set_step_alpha(alpha);
+        self.camera.update_uniform();
+        if self.debug_camera.active() {
+            self.debug_camera.update(delta as f32);
+            self.debug_camera.update_camera(&mut self.camera);
+        }
 
-        self.boid_manager.update(&self.queue, &self.perlin, &self.sub, &self.world, delta as f32);
+        self.world.update(&self.sub, &self.camera, reset, &self.device);
 
         let t = self.sub.t();
-        let clear_color_rgb = util::mix_color(consts::LIGHT_COLOR, consts::DARK_COLOR, t);
+        let sub_biome = self.sub_biome();
+        let clear_color_rgb = util::mix_color(sub_biome.light_color(), sub_biome.dark_color(), t);
         let clear_color = util::to_srgb(clear_color_rgb);
         self.camera.set_fog_color(clear_color);
 
@@ -523,14 +742,14 @@ impl<'a> State<'a> {
             //----------------------------------------------------------------//
             render_pass.set_pipeline(&self.fish_render_pipeline);
 
-            for species in &boid::ALL_SPECIES {
-                render_pass.set_bind_group(1, self.boid_manager.diffuse_bind_group(*species), &[]);
+            for species in 0..self.boid_manager.species_count() {
+                render_pass.set_bind_group(1, self.boid_manager.diffuse_bind_group(species), &[]);
 
-                render_pass.set_vertex_buffer(0, self.boid_manager.verts_buffer_slice(*species));
-                render_pass.set_index_buffer(self.boid_manager.inds_buffer_slice(*species), wgpu::IndexFormat::Uint16);
-                render_pass.set_vertex_buffer(1, self.boid_manager.inst_buffer_slice(*species));
+                render_pass.set_vertex_buffer(0, self.boid_manager.verts_buffer_slice(species));
+                render_pass.set_index_buffer(self.boid_manager.inds_buffer_slice(species), wgpu::IndexFormat::Uint16);
+                render_pass.set_vertex_buffer(1, self.boid_manager.inst_buffer_slice(species));
 
-                render_pass.draw_indexed(0..self.boid_manager.num_inds(*species) as u32, 0, 0..self.boid_manager.num_inst(*species) as u32);
+                render_pass.draw_indexed(0..self.boid_manager.num_inds(species) as u32, 0, 0..self.boid_manager.num_inst(species) as u32);
             }
             //----------------------------------------------------------------//
         }
@@ -566,8 +785,20 @@ impl<'a> State<'a> {
             let generate_text = format!("GENERATE: {}", self.world.generate_count());
             let render_text = format!("RENDER: {}", self.world.render_count());
             let total_text = format!("TOTAL: {}", self.world.total_count());
-
-            let texts = vec![fps_text, min_text, pos_text, bearing_text, generate_text, render_text, total_text];
+            let fish_text = format!("FISH: {}", self.boid_manager.count());
+            let seed_text = self.seed.hud_text();
+            let biome_text = format!("BIOME: {}", self.sub_biome().dominant().name());
+            let hull_text = format!("HULL: {:.0}%", self.sub.hull() * 100.0);
+            let camera_text = format!("CAMERA: {}", self.camera_rigs.kind().name());
+
+            let mut texts = vec![fps_text, min_text, pos_text, bearing_text, generate_text, render_text, total_text, fish_text, seed_text, biome_text, hull_text, camera_text];
+            if self.debug_camera.active() {
+                texts.push("DEBUG CAMERA".to_string());
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(text) = self.input_log.hud_text() {
+                texts.push(text.to_string());
+            }
             let overall_text = texts.join("\n");
 
             let selection = wgpu_text::glyph_brush::Section::default()
@@ -583,8 +814,25 @@ impl<'a> State<'a> {
                 .with_screen_position((text_spacing, text_spacing))
                 .to_owned();
 
+            let mut sections = vec![selection];
+            if self.tuning_panel.open() {
+                let panel_text = self.tuning_panel.text(&self.boid_manager, &self.bindings);
+                let panel_section = wgpu_text::glyph_brush::Section::default()
+                    .add_text(wgpu_text::glyph_brush::Text::new(&panel_text)
+                        .with_scale(font_size)
+                        .with_color([236.0 / 255.0, 239.0 / 255.0, 244.0 / 255.0, 1.0])
+                    )
+                    .with_layout(
+                        wgpu_text::glyph_brush::Layout::default()
+                            .h_align(wgpu_text::glyph_brush::HorizontalAlign::Right)
+                            .v_align(wgpu_text::glyph_brush::VerticalAlign::Top),
+                    )
+                    .with_screen_position((self.size.width as f32 - text_spacing, text_spacing))
+                    .to_owned();
+                sections.push(panel_section);
+            }
 
-            let _ = self.brush.queue(&self.device, &self.queue, vec![&selection]);
+            let _ = self.brush.queue(&self.device, &self.queue, sections.iter().collect());
 
             self.brush.draw(&mut brush_render_pass);
         }
@@ -598,6 +846,11 @@ impl<'a> State<'a> {
         Ok(())
     }
 
+    fn sub_biome(&self) -> biome::BiomeWeights {
+        let pos = self.sub.pos();
+        self.density.biome_at(pos.x as f64 / chunk::CHUNK_SIZE as f64, pos.y as f64 / chunk::CHUNK_SIZE as f64)
+    }
+
     pub fn window(&self) -> &winit::window::Window {
         &self.window
     }
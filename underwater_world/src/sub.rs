@@ -1,9 +1,10 @@
-use crate::{camera, chunk, draw, sub_obj, util};
+use crate::{analog_input, camera, chunk, controls, density, draw, sub_obj, terrain_edit, util, world};
 use wgpu::util::DeviceExt;
-use cgmath::{EuclideanSpace, One, Rotation, Rotation3};
+use cgmath::{InnerSpace, One, Rotation, Rotation3, Zero};
 use noise::NoiseFn;
 use std::collections::HashMap;
 
+// Defaults for SubSettings
 const MIN_SPEED: f32 = 0.5;
 const MAX_SPEED: f32 = 5.0;
 const MIDDLE_SPEED: f32 = 4.0;
@@ -16,104 +17,175 @@ const MAX_DIVE_SPEED: f32 = std::f32::consts::PI / 3.0;
 const TURN_ACCELERATION: f32 = std::f32::consts::PI;
 const TURN_DECAY: f32 = 3.0;
 
-const TARGET_DOWN: f32 = 0.6;
-const HORIZONTAL_OFFSET: f32 = 7.0;
-const VERTICAL_OFFSET: f32 = 6.0;
-
 const LIGHT_DOWN_OFFSET: f32 = 0.25;
+// the cone the shaders light, angle * LIGHT_CONE_SQUISH goes from 0 to pi/2 across it
+const LIGHT_CONE_SQUISH: f32 = 3.0;
 
 const PROP_START_X: f32 = -120.0;
 const SUB_MODEL_SCALE: f32 = 2.5;
 const PERLIN_FACTOR: f32 = 2.0;
 
-const CAMERA_FOLLOW_SPEED: f32 = 10.0;
 const START_Y_OFFSET: f32 = 0.5 * chunk::CHUNK_SIZE as f32;
 const START_Z_OFFSET: f32 = 0.75 * chunk::CHUNK_SIZE as f32;
 
 const MAX_Z: f32 = chunk::CHUNK_SIZE as f32 * 2.0;
 const MIN_Z: f32 = chunk::CHUNK_SIZE as f32 * -1.5;
 
+// The hull is a sphere around the sub's center for terrain collision
+const HULL_RADIUS: f32 = 1.5;
+// Moves are split so one step never goes further than this much of the hull radius into a wall
+const MAX_COLLISION_STEP: f32 = 0.5;
+const COLLISION_ITERATIONS: usize = 3;
+// Hitting a wall head on slower than this only slides
+const BUMP_MIN_SPEED: f32 = 1.5;
+const BUMP_SPEED_LOSS: f32 = 0.5;
+const DAMAGE_PER_SPEED: f32 = 0.05;
+
+// The drill edits a sphere in front of the sub a few times a second while held
+const DRILL_REACH: f32 = 4.0;
+const DRILL_RADIUS: f32 = 3.0;
+const DRILL_STRENGTH: f32 = 0.5;
+const DRILL_INTERVAL: f32 = 0.1;
+
+// The [sub] section of the settings file
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct SubSettings {
+    pub min_speed: f32,
+    pub max_speed: f32,
+    // starting speed, turning slows down below it
+    pub middle_speed: f32,
+    pub acceleration: f32,
+    // radians per second
+    pub max_turn_speed: f32,
+    pub max_dive_speed: f32,
+    pub turn_acceleration: f32,
+    pub turn_decay: f32,
+    pub hull_radius: f32,
+    pub bump_min_speed: f32,
+    pub bump_speed_loss: f32,
+    pub damage_per_speed: f32,
+    pub drill_reach: f32,
+    pub drill_radius: f32,
+    pub drill_strength: f32,
+    pub drill_interval: f32,
+}
+impl Default for SubSettings {
+    fn default() -> Self {
+        Self {
+            min_speed: MIN_SPEED,
+            max_speed: MAX_SPEED,
+            middle_speed: MIDDLE_SPEED,
+            acceleration: ACCELERATION,
+            max_turn_speed: MAX_TURN_SPEED,
+            max_dive_speed: MAX_DIVE_SPEED,
+            turn_acceleration: TURN_ACCELERATION,
+            turn_decay: TURN_DECAY,
+            hull_radius: HULL_RADIUS,
+            bump_min_speed: BUMP_MIN_SPEED,
+            bump_speed_loss: BUMP_SPEED_LOSS,
+            damage_per_speed: DAMAGE_PER_SPEED,
+            drill_reach: DRILL_REACH,
+            drill_radius: DRILL_RADIUS,
+            drill_strength: DRILL_STRENGTH,
+            drill_interval: DRILL_INTERVAL,
+        }
+    }
+}
+
 struct Keys {
-	w_down: bool,
-	s_down: bool,
-	a_down: bool,
-	d_down: bool,
-	q_down: bool,
-	e_down: bool,
-	space_down: bool,
-	control_down: bool,
-    r_down: bool,
+	pitch_up: bool,
+	pitch_down: bool,
+	yaw_left: bool,
+	yaw_right: bool,
+	roll_left: bool,
+	roll_right: bool,
+	throttle: bool,
+	brake: bool,
+    reset: bool,
+    dig: bool,
+    fill: bool,
 }
 impl Keys {
 	fn new() -> Self {
 		Self {
-			w_down: false,
-			s_down: false,
-			a_down: false,
-			d_down: false,
-			q_down: false,
-			e_down: false,
-			space_down: false,
-			control_down: false,
-            r_down: false,
+			pitch_up: false,
+			pitch_down: false,
+			yaw_left: false,
+			yaw_right: false,
+			roll_left: false,
+			roll_right: false,
+			throttle: false,
+			brake: false,
+            reset: false,
+            dig: false,
+            fill: false,
 		}
 	}
 
-	fn process_events(&mut self, event: &winit::event::WindowEvent) -> bool {
-        match event {
-            winit::event::WindowEvent::KeyboardInput {
-                input:
-                winit::event::KeyboardInput {
-                        state,
-                        virtual_keycode: Some(keycode),
-                        ..
-                    },
-                ..
-            } => {
-                let pressed = *state == winit::event::ElementState::Pressed;
-                match keycode {
-                    winit::event::VirtualKeyCode::W | winit::event::VirtualKeyCode::Up => {
-                        self.w_down = pressed;
-                        true
-                    }
-                    winit::event::VirtualKeyCode::S | winit::event::VirtualKeyCode::Down => {
-                        self.s_down = pressed;
-                        true
-                    }
-                    winit::event::VirtualKeyCode::A | winit::event::VirtualKeyCode::Left => {
-                        self.a_down = pressed;
-                        true
-                    }
-                    winit::event::VirtualKeyCode::D | winit::event::VirtualKeyCode::Right => {
-                        self.d_down = pressed;
-                        true
-                    }
-                    winit::event::VirtualKeyCode::Q | winit::event::VirtualKeyCode::PageUp => {
-                        self.q_down = pressed;
-                        true
-                    }
-                    winit::event::VirtualKeyCode::E | winit::event::VirtualKeyCode::PageDown => {
-                        self.e_down = pressed;
-                        true
-                    }
-					winit::event::VirtualKeyCode::Space => {
-						self.space_down = pressed;
-						true
-					}
-					winit::event::VirtualKeyCode::LControl | winit::event::VirtualKeyCode::RControl => {
-						self.control_down = pressed;
-						true
-					}
-                    winit::event::VirtualKeyCode::R | winit::event::VirtualKeyCode::Return => {
-                        self.r_down = pressed;
-                        true
-                    }
-                    _ => false,
-                }
-            }
-            _ => false,
+	fn process_action(&mut self, action: controls::Action, pressed: bool) -> bool {
+        match action {
+            controls::Action::PitchUp => self.pitch_up = pressed,
+            controls::Action::PitchDown => self.pitch_down = pressed,
+            controls::Action::YawLeft => self.yaw_left = pressed,
+            controls::Action::YawRight => self.yaw_right = pressed,
+            controls::Action::RollLeft => self.roll_left = pressed,
+            controls::Action::RollRight => self.roll_right = pressed,
+            controls::Action::Throttle => self.throttle = pressed,
+            controls::Action::Brake => self.brake = pressed,
+            controls::Action::Reset => self.reset = pressed,
+            controls::Action::Dig => self.dig = pressed,
+            controls::Action::Fill => self.fill = pressed,
+            _ => return false,
         }
+        true
 	}
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn held_actions(&self) -> Vec<controls::Action> {
+        let held = [
+            (controls::Action::PitchUp, self.pitch_up),
+            (controls::Action::PitchDown, self.pitch_down),
+            (controls::Action::YawLeft, self.yaw_left),
+            (controls::Action::YawRight, self.yaw_right),
+            (controls::Action::RollLeft, self.roll_left),
+            (controls::Action::RollRight, self.roll_right),
+            (controls::Action::Throttle, self.throttle),
+            (controls::Action::Brake, self.brake),
+            (controls::Action::Reset, self.reset),
+            (controls::Action::Dig, self.dig),
+            (controls::Action::Fill, self.fill),
+        ];
+        held.iter().filter(|(_action, held)| *held).map(|(action, _held)| *action).collect()
+    }
+}
+
+// Where the sub is drawn, which is between its last two steps
+#[derive(Copy, Clone, Debug)]
+pub struct SubPose {
+    pos: cgmath::Vector3<f32>,
+    rotation: cgmath::Quaternion<f32>,
+    prop_rot: f32,
+}
+impl SubPose {
+    fn lerp(&self, other: &SubPose, alpha: f32) -> Self {
+        Self {
+            pos: self.pos + (other.pos - self.pos) * alpha,
+            rotation: self.rotation.slerp(other.rotation, alpha),
+            prop_rot: self.prop_rot + (other.prop_rot - self.prop_rot) * alpha,
+        }
+    }
+
+    // The headlight, the camera rigs in camera_rig.rs place the camera itself
+    pub fn update_light(&self, camera: &mut camera::Camera) {
+        camera.set_sub_pos(self.pos.into());
+
+        camera.set_sub_dir(light_dir(self.bearing(), self.up()).into());
+    }
+
+    pub fn pos(&self) -> cgmath::Vector3<f32> { self.pos }
+    pub fn bearing(&self) -> cgmath::Vector3<f32> { self.rotation.rotate_vector(cgmath::Vector3::unit_x()) }
+    pub fn up(&self) -> cgmath::Vector3<f32> { self.rotation.rotate_vector(cgmath::Vector3::unit_z()) }
 }
 
 pub struct Sub {
@@ -124,6 +196,8 @@ pub struct Sub {
     right: cgmath::Vector3<f32>,
 
 	overall_rotation: cgmath::Quaternion<f32>,
+    // before the last step, for drawing between steps
+    prev_pose: SubPose,
 
 	yaw: f32,
 	pitch: f32,
@@ -138,6 +212,12 @@ pub struct Sub {
 	speed: f32,
 
 	keys: Keys,
+    drill_cooldown: f32,
+
+    // 1 is undamaged, reset at 0
+    hull: f32,
+
+    settings: SubSettings,
 
 	verts_buffer: wgpu::Buffer,
     prop_verts_buffer: wgpu::Buffer,
@@ -152,7 +232,7 @@ pub struct Sub {
 }
 
 impl Sub {
-	pub fn new(device: &wgpu::Device, perlin: &noise::Perlin) -> Self {
+	pub fn new(device: &wgpu::Device, perlin: &noise::Perlin, settings: SubSettings) -> Self {
         //--------------------------------------------------------------------//
         let mut mats = HashMap::new();
 
@@ -286,14 +366,20 @@ impl Sub {
         );
         //--------------------------------------------------------------------//
 
+        let start_pos = cgmath::Vector3::new(0.0, START_Y_OFFSET, START_Z_OFFSET);
 		Self {
-			pos: cgmath::Vector3::new(0.0, START_Y_OFFSET, START_Z_OFFSET),
+			pos: start_pos,
 
             up: cgmath::Vector3::unit_z(),
             forward: cgmath::Vector3::unit_x(),
             right: cgmath::Vector3::unit_y(),
 
 			overall_rotation: cgmath::Quaternion::one(),
+            prev_pose: SubPose {
+                pos: start_pos,
+                rotation: cgmath::Quaternion::one(),
+                prop_rot: 0.0,
+            },
 
 			yaw: 0.0,
             pitch: 0.0,
@@ -305,9 +391,14 @@ impl Sub {
 
             prop_rot: 0.0,
 			
-			speed: MIDDLE_SPEED,
+			speed: settings.middle_speed,
 
 			keys: Keys::new(),
+            drill_cooldown: 0.0,
+
+            hull: 1.0,
+
+            settings,
 
 			verts_buffer,
             prop_verts_buffer,
@@ -322,47 +413,36 @@ impl Sub {
 		}
 	}
 
-    fn decay_turn_rates(&mut self, delta: f32) {
-        let min_turn_decay = TURN_DECAY * MAX_TURN_SPEED * delta;
-
-        if self.keys.w_down || self.keys.s_down {
-			self.pitch_speed = self.pitch_speed.clamp(-MAX_DIVE_SPEED, MAX_DIVE_SPEED);
-		} else if self.pitch_speed.abs() < min_turn_decay {
-			self.pitch_speed = 0.0;
-		} else {
-			self.pitch_speed -= min_turn_decay * self.pitch_speed.signum();
-		}
-
-        if self.keys.a_down || self.keys.d_down {
-			self.yaw_speed = self.yaw_speed.clamp(-MAX_TURN_SPEED, MAX_TURN_SPEED);
-		} else if self.yaw_speed.abs() < min_turn_decay {
-			self.yaw_speed = 0.0;
-		} else {
-			self.yaw_speed -= min_turn_decay * self.yaw_speed.signum();
-		}
+    // Keys count as full deflection, and are added to the mouse/gamepad axes
+    fn steering(&self, analog: analog_input::AnalogAxes) -> analog_input::AnalogAxes {
+        let key_axis = |positive: bool, negative: bool| positive as i32 as f32 - negative as i32 as f32;
+        analog_input::AnalogAxes {
+            pitch: (key_axis(self.keys.pitch_down, self.keys.pitch_up) + analog.pitch).clamp(-1.0, 1.0),
+            yaw: (key_axis(self.keys.yaw_left, self.keys.yaw_right) + analog.yaw).clamp(-1.0, 1.0),
+            roll: (key_axis(self.keys.roll_right, self.keys.roll_left) + analog.roll).clamp(-1.0, 1.0),
+            throttle: (key_axis(self.keys.throttle, self.keys.brake) + analog.throttle).clamp(-1.0, 1.0),
+        }
+    }
 
-		if self.keys.q_down || self.keys.e_down {
-			self.roll_speed = self.roll_speed.clamp(-MAX_TURN_SPEED, MAX_TURN_SPEED);
-		} else if self.roll_speed.abs() < min_turn_decay {
-			self.roll_speed = 0.0;
-		} else {
-			self.roll_speed -= min_turn_decay * self.roll_speed.signum();
-		}
+    fn update_turn_rates(&mut self, steering: analog_input::AnalogAxes, delta: f32) {
+        let settings = &self.settings;
+        self.pitch_speed = steer(self.pitch_speed, steering.pitch, settings.max_dive_speed, settings, delta);
+        self.yaw_speed = steer(self.yaw_speed, steering.yaw, settings.max_turn_speed, settings, delta);
+        self.roll_speed = steer(self.roll_speed, steering.roll, settings.max_turn_speed, settings, delta);
     }
 
-	pub fn update(&mut self, queue: &wgpu::Queue, delta: f32) -> bool {
-		if self.keys.w_down { self.pitch_speed -= TURN_ACCELERATION * delta; }
-		if self.keys.s_down { self.pitch_speed += TURN_ACCELERATION * delta; }
-		if self.keys.a_down { self.yaw_speed   += TURN_ACCELERATION * delta; }
-		if self.keys.d_down { self.yaw_speed   -= TURN_ACCELERATION * delta; }
-		if self.keys.q_down { self.roll_speed  -= TURN_ACCELERATION * delta; }
-		if self.keys.e_down { self.roll_speed  += TURN_ACCELERATION * delta; }
-		if self.keys.space_down { self.speed   += ACCELERATION * delta; }
-		if self.keys.control_down { self.speed -= ACCELERATION * delta; }
+	pub fn update(&mut self, world: &world::World, analog: analog_input::AnalogAxes, delta: f32) -> bool {
+        self.prev_pose = self.pose();
 
-        if self.keys.r_down {
-            self.speed = MIDDLE_SPEED;
-            self.pos.z = START_Z_OFFSET;
+        let steering = self.steering(analog);
+		self.speed += self.settings.acceleration * steering.throttle * delta;
+        self.drill_cooldown -= delta;
+
+        let destroyed = self.hull <= 0.0;
+        if self.keys.reset || destroyed {
+            self.hull = 1.0;
+            self.speed = self.settings.middle_speed;
+            self.pos.z = self.open_water_z(world, START_Z_OFFSET);
 
             self.pitch = 0.0;
             self.yaw = 0.0;
@@ -377,12 +457,15 @@ impl Sub {
             self.forward = cgmath::Vector3::unit_x();
             self.up = cgmath::Vector3::unit_z();
             self.right = cgmath::Vector3::unit_y();
+
+            // jumps straight there instead of drawing it sliding back
+            self.prev_pose = self.pose();
         } else {
-            self.decay_turn_rates(delta);
+            self.update_turn_rates(steering, delta);
 
-            self.speed = self.speed.clamp(MIN_SPEED, MAX_SPEED);
+            self.speed = self.speed.clamp(self.settings.min_speed, self.settings.max_speed);
 
-            let angle_change_mod = (self.speed / MIDDLE_SPEED).clamp(0.0, 1.0);
+            let angle_change_mod = (self.speed / self.settings.middle_speed).clamp(0.0, 1.0);
 
             let pitch_change = self.pitch_speed * delta * angle_change_mod;
             self.pitch += pitch_change;
@@ -407,51 +490,153 @@ impl Sub {
             self.up = overall_change_quat.rotate_vector(self.up);
             self.right = overall_change_quat.rotate_vector(self.right);
             
-            self.pos += self.forward * self.speed * delta;
+            self.move_and_collide(world, self.forward * self.speed * delta);
+            // too deep in the rock for any face to reach (a fill brush over the hull), so it
+            // comes up out of it instead
+            if !in_water(world.density(), self.pos) {
+                self.pos.z = self.open_water_z(world, self.pos.z);
+                self.prev_pose = self.pose();
+            }
             self.pos.z = self.pos.z.clamp(MIN_Z, MAX_Z);
         }
 
-		let inst_mat = cgmath::Matrix4::from_translation(self.pos) * cgmath::Matrix4::from(self.overall_rotation);
+        self.color_mix = util::create_mix_ratio(MIN_Z, MAX_Z, self.pos.z);
+
+        self.keys.reset || destroyed
+	}
+
+    // Up the sub's column from z to where the whole hull is in open water
+    fn open_water_z(&self, world: &world::World, z: f32) -> f32 {
+        let hull_radius = self.settings.hull_radius;
+        let mut z = z;
+        while z < MAX_Z && !hull_in_water(world.density(), cgmath::Vector3::new(self.pos.x, self.pos.y, z), hull_radius) {
+            z += hull_radius;
+        }
+        z.min(MAX_Z)
+    }
+
+    fn move_and_collide(&mut self, world: &world::World, movement: cgmath::Vector3<f32>) {
+        let hull_radius = self.settings.hull_radius;
+        let steps = (movement.magnitude() / (hull_radius * MAX_COLLISION_STEP)).ceil().max(1.0);
+        let step = movement / steps;
+
+        let mut hit_normal = None;
+        for _ in 0..steps as usize {
+            self.pos += step;
+
+            // pushing out along the normal keeps the movement along the wall, so the sub slides
+            let tris = world.tris_near(self.pos, hull_radius);
+            for _ in 0..COLLISION_ITERATIONS {
+                // a center in the rock goes out the front of the nearest face, since pushing
+                // away from it would only go further in
+                let push = if in_water(world.density(), self.pos) {
+                    tris.iter()
+                        .filter_map(|tri| {
+                            let away = self.pos - tri.closest_point(self.pos);
+                            let dist = away.magnitude();
+                            if dist >= hull_radius { return None; }
+
+                            let normal = if dist > 0.0 { away / dist } else { tri.normal };
+                            Some((normal, hull_radius - dist))
+                        })
+                        .max_by(|(_normal_a, depth_a), (_normal_b, depth_b)| depth_a.total_cmp(depth_b))
+                } else {
+                    tris.iter()
+                        .map(|tri| (tri.normal, (self.pos - tri.closest_point(self.pos)).magnitude()))
+                        .filter(|(_normal, dist)| *dist < hull_radius)
+                        .min_by(|(_normal_a, dist_a), (_normal_b, dist_b)| dist_a.total_cmp(dist_b))
+                        .map(|(normal, dist)| (normal, hull_radius + dist))
+                };
+
+                match push {
+                    Some((normal, depth)) => {
+                        self.pos += normal * depth;
+                        hit_normal = Some(normal);
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        if let Some(normal) = hit_normal {
+            self.bump(normal);
+        }
+    }
+
+    // only the part of the speed going into the wall counts
+    fn bump(&mut self, normal: cgmath::Vector3<f32>) {
+        let impact_speed = self.speed * (-self.forward.dot(normal)).max(0.0);
+        if impact_speed < self.settings.bump_min_speed { return; }
+
+        self.speed *= self.settings.bump_speed_loss;
+        self.hull -= impact_speed * self.settings.damage_per_speed;
+    }
+
+    // Picked up on the next update
+    pub fn set_settings(&mut self, settings: SubSettings) {
+        self.settings = settings;
+    }
+
+    fn pose(&self) -> SubPose {
+        SubPose {
+            pos: self.pos,
+            rotation: self.overall_rotation,
+            prop_rot: self.prop_rot,
+        }
+    }
+
+    // alpha is how far the frame is from the last step to the next, see timer::FixedStep
+    pub fn interpolated_pose(&self, alpha: f32) -> SubPose {
+        self.prev_pose.lerp(&self.pose(), alpha)
+    }
+
+    pub fn write_instances(&self, queue: &wgpu::Queue, pose: &SubPose) {
+		let inst_mat = cgmath::Matrix4::from_translation(pose.pos) * cgmath::Matrix4::from(pose.rotation);
 		let inst = draw::Instance::new(inst_mat);
 		queue.write_buffer(&self.inst_buffer, 0, bytemuck::cast_slice(&[inst]));
 
-        let prop_inst_mat = inst_mat * cgmath::Matrix4::from_angle_x(cgmath::Rad(self.prop_rot));
+        let prop_inst_mat = inst_mat * cgmath::Matrix4::from_angle_x(cgmath::Rad(pose.prop_rot));
         let prop_inst = draw::Instance::new(prop_inst_mat);
         queue.write_buffer(&self.prop_inst_buffer, 0, bytemuck::cast_slice(&[prop_inst]));
+    }
 
-        self.color_mix = util::create_mix_ratio(MIN_Z, MAX_Z, self.pos.z);
+    pub fn light_dir(&self) -> cgmath::Vector3<f32> {
+        light_dir(self.forward, self.up)
+    }
 
-        self.keys.r_down
-	}
+    // 0 to 1, how brightly the headlight lights pos, the same falloff as in fish.wgsl and terrain.wgsl
+    pub fn headlight_at(&self, pos: cgmath::Vector3<f32>) -> f32 {
+        let angle = (pos - self.pos).angle(self.light_dir()).0;
+        if !angle.is_finite() { return 0.0; }
 
-	pub fn update_camera(&self, camera: &mut camera::Camera, delta: f32) {
-		let eye_goal = self.pos - self.forward * HORIZONTAL_OFFSET + self.up * VERTICAL_OFFSET;
-		let eye_diff = eye_goal - camera.eye.to_vec();
-		let eye_move = eye_diff * delta * CAMERA_FOLLOW_SPEED;
-		camera.eye += eye_move;
-        // camera.eye = eye_goal;
-
-        let target_goal = eye_goal + self.forward - self.up * TARGET_DOWN;
-        let target_diff = target_goal - camera.target.to_vec();
-        let target_move = target_diff * delta * CAMERA_FOLLOW_SPEED;
-        camera.target += target_move;
-        // camera.target = target_goal;
-
-		let up_diff = self.up - camera.up;
-		let up_move = up_diff * delta * CAMERA_FOLLOW_SPEED;
-		camera.up += up_move;
-        // camera.up = self.up;
-        
-		camera.set_sub_pos(self.pos.into());
-
-        let light_forward = self.forward - self.up * LIGHT_DOWN_OFFSET;
-        camera.set_sub_dir(light_forward.into());
-
-        camera.update_uniform();
-	}
+        let squished = angle * LIGHT_CONE_SQUISH;
+        if squished >= std::f32::consts::FRAC_PI_2 { return 0.0; }
+        let light = squished.cos();
+        light * light * (3.0 - 2.0 * light)
+    }
+
+	pub fn process_action(&mut self, action: controls::Action, pressed: bool) -> bool {
+		self.keys.process_action(action, pressed)
+    }
+
+    // So keys held while switching to the debug camera don't stay stuck down
+    pub fn release_keys(&mut self) {
+        self.keys = Keys::new();
+    }
+
+    // For input_log.rs, the sub's actions that are held down
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn held_actions(&self) -> Vec<controls::Action> {
+        self.keys.held_actions()
+    }
 
-	pub fn process_events(&mut self, event: &winit::event::WindowEvent) -> bool {
-		self.keys.process_events(event)
+    // Holds exactly these actions
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn hold_actions(&mut self, actions: &[controls::Action]) {
+        self.keys = Keys::new();
+        for action in actions {
+            self.keys.process_action(*action, true);
+        }
     }
 
     pub fn chunk(&self) -> (i32, i32, i32) {
@@ -462,7 +647,29 @@ impl Sub {
         )
     }
 
+    // Dig or Fill held, None if neither is held or the drill is cooling down
+    pub fn take_drill_brush(&mut self) -> Option<terrain_edit::Brush> {
+        let mode = if self.keys.dig {
+            terrain_edit::BrushMode::Dig
+        } else if self.keys.fill {
+            terrain_edit::BrushMode::Fill
+        } else {
+            return None;
+        };
+        if self.drill_cooldown > 0.0 { return None; }
+        self.drill_cooldown = self.settings.drill_interval;
+
+        Some(terrain_edit::Brush {
+            center: self.pos + self.forward * self.settings.drill_reach,
+            radius: self.settings.drill_radius,
+            strength: self.settings.drill_strength,
+            mode,
+        })
+    }
+
     pub fn pos(&self) -> cgmath::Vector3<f32> { self.pos }
+    pub fn hull(&self) -> f32 { self.hull.max(0.0) }
+    pub fn speed(&self) -> f32 { self.speed }
     pub fn bearing(&self) -> cgmath::Vector3<f32> { self.forward }
 
     pub fn t(&self) -> f32 { self.color_mix }
@@ -475,4 +682,37 @@ impl Sub {
 
     pub fn num_verts(&self) -> usize { self.num_verts }
     pub fn num_prop_verts(&self) -> usize { self.num_prop_verts }
-}
\ No newline at end of file
+}
+
+fn light_dir(forward: cgmath::Vector3<f32>, up: cgmath::Vector3<f32>) -> cgmath::Vector3<f32> {
+    forward - up * LIGHT_DOWN_OFFSET
+}
+
+// Turns the rate towards input * max_rate, half a stick gives half the turn rate.
+// With no input it decays back to 0.
+fn steer(rate: f32, input: f32, max_rate: f32, settings: &SubSettings, delta: f32) -> f32 {
+    if input == 0.0 {
+        let decay = settings.turn_decay * settings.max_turn_speed * delta;
+        if rate.abs() < decay { 0.0 } else { rate - decay * rate.signum() }
+    } else {
+        let goal = input * max_rate;
+        let step = settings.turn_acceleration * delta;
+        rate + (goal - rate).clamp(-step, step)
+    }
+}
+
+fn in_water(density: &dyn density::Density, pos: cgmath::Vector3<f32>) -> bool {
+    let chunk_size = chunk::CHUNK_SIZE as f64;
+    density.iso_at(pos.x as f64 / chunk_size, pos.y as f64 / chunk_size, pos.z as f64 / chunk_size) > chunk::ISO_LEVEL
+}
+
+// Whether a sphere around pos is clear of the terrain, checked at its center and the ends of each axis
+fn hull_in_water(density: &dyn density::Density, pos: cgmath::Vector3<f32>, radius: f32) -> bool {
+    let offsets = [
+        cgmath::Vector3::zero(),
+        cgmath::Vector3::unit_x(), -cgmath::Vector3::unit_x(),
+        cgmath::Vector3::unit_y(), -cgmath::Vector3::unit_y(),
+        cgmath::Vector3::unit_z(), -cgmath::Vector3::unit_z(),
+    ];
+    offsets.iter().all(|offset| in_water(density, pos + offset * radius))
+}
@@ -1,11 +1,11 @@
 struct CameraUniform {
     view_proj: mat4x4<f32>,
     fog_color: vec3<f32>,
-    _padding1: f32,
+    fog_near: f32,
     sub_pos: vec3<f32>,
-    _padding2: f32,
+    fog_far: f32,
     sub_forward: vec3<f32>,
-    _padding3: f32,
+    step_alpha: f32,
 };
 
 @group(0) @binding(0)
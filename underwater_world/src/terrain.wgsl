@@ -1,11 +1,11 @@
 struct CameraUniform {
     view_proj: mat4x4<f32>,
     fog_color: vec3<f32>,
-    _padding1: f32,
+    fog_near: f32,
     sub_pos: vec3<f32>,
-    _padding2: f32,
+    fog_far: f32,
     sub_forward: vec3<f32>,
-    _padding3: f32,
+    step_alpha: f32,
 };
 
 @group(0) @binding(0)
@@ -14,6 +14,7 @@ var<uniform> camera: CameraUniform;
 struct VertexInput {
     @location(0) position: vec3<f32>,
     @location(1) color: vec3<f32>,
+    @location(2) normal: vec3<f32>,
 }
 
 struct VertexOutput {
@@ -21,8 +22,14 @@ struct VertexOutput {
     @location(0) color: vec3<f32>,
     @location(1) dist: f32,
     @location(2) light: f32,
+    @location(3) normal: vec3<f32>,
+    @location(4) world_pos: vec3<f32>,
 }
 
+const AMBIENT: f32 = 0.45;
+const SUN_STRENGTH: f32 = 0.35;
+const HEADLIGHT_STRENGTH: f32 = 0.5;
+
 @vertex
 fn vs_main(
     model: VertexInput,
@@ -32,6 +39,8 @@ fn vs_main(
     var out: VertexOutput;
     out.color = model.color;
     out.clip_position = camera.view_proj * vec4<f32>(model.position, 1.0);
+    out.normal = model.normal;
+    out.world_pos = model.position;
 
     let dist_vec = model.position - camera.sub_pos;
     out.dist = length(dist_vec);
@@ -51,10 +60,19 @@ fn vs_main(
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    let min_dist_value = smoothstep(0.0, 20.0, clamp(in.dist, 0.0, 20.0));
-    let max_dist_value = smoothstep(0.0, 45.0, clamp(in.dist, 0.0, 45.0));
+    let normal = normalize(in.normal);
+
+    // lambert from a sun straight above and from the sub's headlight (only inside its cone)
+    let sun = max(normal.z, 0.0);
+    let to_sub = normalize(camera.sub_pos - in.world_pos);
+    let headlight = max(dot(normal, to_sub), 0.0) * in.light;
+    let shade = AMBIENT + SUN_STRENGTH * sun + HEADLIGHT_STRENGTH * headlight;
+    let shaded_color = in.color * shade;
+
+    let min_dist_value = smoothstep(0.0, camera.fog_near, clamp(in.dist, 0.0, camera.fog_near));
+    let max_dist_value = smoothstep(0.0, camera.fog_far, clamp(in.dist, 0.0, camera.fog_far));
     let dark_value = clamp(1.0 - in.light, max_dist_value, min_dist_value);
 
-    let output = mix(in.color, camera.fog_color, dark_value);
+    let output = mix(shaded_color, camera.fog_color, dark_value);
     return vec4<f32>(output, 1.0);
 }
\ No newline at end of file
@@ -0,0 +1,170 @@
+use crate::{biome, chunk, density};
+use cgmath::InnerSpace;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+// Edits are stored on the full detail iso grid, which the coarser LODs sample a subset of
+const GRID_SIZE: usize = chunk::INTERNAL_SIZE;
+const GRID_SCALE: f32 = chunk::CHUNK_SIZE as f32 / GRID_SIZE as f32;
+// Keeps repeated drilling from piling up forever
+const MAX_EDIT: f32 = 4.0;
+// iso_at is given exactly the grid points while meshing, only interpolate otherwise
+const ON_GRID_EPSILON: f64 = 1e-6;
+
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BrushMode {
+    Dig,
+    Fill,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Brush {
+    pub center: cgmath::Vector3<f32>,
+    pub radius: f32,
+    pub strength: f32,
+    pub mode: BrushMode,
+}
+impl Brush {
+    // iso increases towards open water, so digging adds and filling subtracts
+    fn iso_delta(&self, pos: cgmath::Vector3<f32>) -> f32 {
+        let dist_sq = (pos - self.center).magnitude2();
+        let radius_sq = self.radius * self.radius;
+        if dist_sq >= radius_sq { return 0.0; }
+
+        let falloff = 1.0 - dist_sq / radius_sq;
+        let delta = self.strength * falloff * falloff;
+        match self.mode {
+            BrushMode::Dig => delta,
+            BrushMode::Fill => -delta,
+        }
+    }
+}
+
+
+// The generated terrain plus every brush applied to it so far
+pub struct EditedDensity {
+    base: Box<dyn density::Density>,
+    // iso offsets for the grid points of each edited chunk, x then y then z
+    edits: RwLock<HashMap<(i32, i32, i32), Vec<f32>>>,
+}
+
+impl EditedDensity {
+    pub fn new(base: Box<dyn density::Density>) -> Self {
+        Self {
+            base,
+            edits: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn apply(&self, brush: Brush) {
+        let min = grid_coord(brush.center - cgmath::Vector3::new(brush.radius, brush.radius, brush.radius), f32::ceil);
+        let max = grid_coord(brush.center + cgmath::Vector3::new(brush.radius, brush.radius, brush.radius), f32::floor);
+
+        let mut edits = self.edits.write().unwrap();
+        for x in min[0]..=max[0] {
+            for y in min[1]..=max[1] {
+                for z in min[2]..=max[2] {
+                    let pos = cgmath::Vector3::new(x as f32, y as f32, z as f32) * GRID_SCALE;
+                    let delta = brush.iso_delta(pos);
+                    if delta == 0.0 { continue; }
+
+                    let (chunk_pos, idx) = split_grid_coord([x, y, z]);
+                    let chunk_edits = edits.entry(chunk_pos).or_insert_with(|| vec![0.0; GRID_SIZE * GRID_SIZE * GRID_SIZE]);
+                    chunk_edits[idx] = (chunk_edits[idx] + delta).clamp(-MAX_EDIT, MAX_EDIT);
+                }
+            }
+        }
+    }
+
+    fn delta_at_grid(edits: &HashMap<(i32, i32, i32), Vec<f32>>, grid: [i64; 3]) -> f32 {
+        let (chunk_pos, idx) = split_grid_coord(grid);
+        edits.get(&chunk_pos).map_or(0.0, |chunk_edits| chunk_edits[idx])
+    }
+}
+
+impl density::Density for EditedDensity {
+    fn iso_at(&self, x: f64, y: f64, z: f64) -> f32 {
+        let iso = self.base.iso_at(x, y, z);
+
+        let edits = self.edits.read().unwrap();
+        if edits.is_empty() { return iso; }
+
+        // chunk units -> grid units
+        let grid = [x * GRID_SIZE as f64, y * GRID_SIZE as f64, z * GRID_SIZE as f64];
+        let rounded = [grid[0].round(), grid[1].round(), grid[2].round()];
+        let on_grid = (0..3).all(|axis| (grid[axis] - rounded[axis]).abs() < ON_GRID_EPSILON);
+
+        if on_grid {
+            let grid = [rounded[0] as i64, rounded[1] as i64, rounded[2] as i64];
+            return iso + Self::delta_at_grid(&edits, grid);
+        }
+
+        // trilinear between the 8 surrounding grid points
+        let low = [grid[0].floor(), grid[1].floor(), grid[2].floor()];
+        let t = [grid[0] - low[0], grid[1] - low[1], grid[2] - low[2]];
+        let mut delta = 0.0;
+        for corner in 0..8 {
+            let offset = [corner & 1, (corner >> 1) & 1, (corner >> 2) & 1];
+            let mut weight = 1.0;
+            let mut corner_grid = [0; 3];
+            for axis in 0..3 {
+                weight *= if offset[axis] == 1 { t[axis] } else { 1.0 - t[axis] };
+                corner_grid[axis] = low[axis] as i64 + offset[axis];
+            }
+            delta += weight as f32 * Self::delta_at_grid(&edits, corner_grid);
+        }
+
+        iso + delta
+    }
+
+    fn biome_at(&self, x: f64, y: f64) -> biome::BiomeWeights {
+        self.base.biome_at(x, y)
+    }
+
+    // the border layer and the coarse LODs reach into the neighbouring chunks' grids
+    fn cacheable(&self, chunk_pos: (i32, i32, i32)) -> bool {
+        let edits = self.edits.read().unwrap();
+        if edits.is_empty() { return true; }
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    if edits.contains_key(&(chunk_pos.0 + x, chunk_pos.1 + y, chunk_pos.2 + z)) {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    fn cache_id(&self) -> Option<&str> {
+        self.base.cache_id()
+    }
+}
+
+// world position -> grid point, rounded with `round` (ceil or floor)
+fn grid_coord(pos: cgmath::Vector3<f32>, round: fn(f32) -> f32) -> [i64; 3] {
+    [
+        round(pos.x / GRID_SCALE) as i64,
+        round(pos.y / GRID_SCALE) as i64,
+        round(pos.z / GRID_SCALE) as i64,
+    ]
+}
+
+// grid point -> (chunk pos, index into that chunk's edits)
+fn split_grid_coord(grid: [i64; 3]) -> ((i32, i32, i32), usize) {
+    let size = GRID_SIZE as i64;
+    let chunk_pos = (
+        grid[0].div_euclid(size) as i32,
+        grid[1].div_euclid(size) as i32,
+        grid[2].div_euclid(size) as i32,
+    );
+    let local = [
+        grid[0].rem_euclid(size) as usize,
+        grid[1].rem_euclid(size) as usize,
+        grid[2].rem_euclid(size) as usize,
+    ];
+    (chunk_pos, local[0] * GRID_SIZE * GRID_SIZE + local[1] * GRID_SIZE + local[2])
+}
@@ -27,4 +27,80 @@ impl FpsCounter {
             1.0 / self.delta
         }
     }
-}
\ No newline at end of file
+}
+
+// The simulation always moves in steps of STEP seconds, however long the frames take,
+// so a run can be played back exactly. Frames draw between the last two steps (see alpha).
+pub const STEP: f64 = 1.0 / 60.0;
+// after a long hitch the simulation falls behind rather than freezing up catching up
+const MAX_STEPS_PER_FRAME: u32 = 8;
+
+pub struct FixedStep {
+    accumulator: f64,
+}
+impl FixedStep {
+    pub fn new() -> Self {
+        Self { accumulator: 0.0 }
+    }
+
+    // How many steps to run this frame
+    pub fn advance(&mut self, delta: f64) -> u32 {
+        self.accumulator += delta;
+        let mut count = 0;
+        while self.accumulator >= STEP && count < MAX_STEPS_PER_FRAME {
+            self.accumulator -= STEP;
+            count += 1;
+        }
+        if count == MAX_STEPS_PER_FRAME {
+            self.accumulator %= STEP;
+        }
+        count
+    }
+
+    // Gives back steps from advance that couldn't run yet, they run on a later frame
+    pub fn defer(&mut self, count: u32) {
+        self.accumulator += count as f64 * STEP;
+    }
+
+    // 0 to 1, how far the frame is from the last step to the next
+    pub fn alpha(&self) -> f32 { (self.accumulator / STEP).clamp(0.0, 1.0) as f32 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_whole_steps() {
+        let mut fixed_step = FixedStep::new();
+        assert_eq!(fixed_step.advance(STEP * 0.5), 0);
+        assert!((fixed_step.alpha() - 0.5).abs() < 1e-5);
+        assert_eq!(fixed_step.advance(STEP * 2.0), 2);
+        assert!((fixed_step.alpha() - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn advance_clamps_after_a_hitch() {
+        let mut fixed_step = FixedStep::new();
+        assert_eq!(fixed_step.advance(STEP * 100.25), MAX_STEPS_PER_FRAME);
+        // the rest is dropped instead of caught up on, except the part of a step
+        assert!((fixed_step.alpha() - 0.25).abs() < 1e-5);
+        assert_eq!(fixed_step.advance(0.0), 0);
+    }
+
+    #[test]
+    fn deferred_steps_carry_over() {
+        let mut fixed_step = FixedStep::new();
+        assert_eq!(fixed_step.advance(STEP * 3.5), 3);
+        fixed_step.defer(2);
+        // alpha stays within a step even while steps are waiting
+        assert_eq!(fixed_step.alpha(), 1.0);
+        assert_eq!(fixed_step.advance(0.0), 2);
+        assert!((fixed_step.alpha() - 0.5).abs() < 1e-5);
+
+        // deferring every step of a clamped frame keeps them all for the next one
+        assert_eq!(fixed_step.advance(STEP * 20.0), MAX_STEPS_PER_FRAME);
+        fixed_step.defer(MAX_STEPS_PER_FRAME);
+        assert_eq!(fixed_step.advance(0.0), MAX_STEPS_PER_FRAME);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{boid, controls};
+
+// How much one press of PanelDecrease/PanelIncrease changes each row
+const WEIGHT_STEP: f32 = 0.1;
+const MAX_STEER_FORCE_STEP: f32 = 0.5;
+const DOWN_STEER_MULT_STEP: f32 = 0.02;
+
+const ROWS: [&str; 6] = [
+    "SEPARATION",
+    "ALIGNMENT",
+    "COHESION",
+    "MAX STEER FORCE",
+    "WALL FORCE",
+    "DOWN STEER",
+];
+
+
+// On screen panel for adjusting each species' flocking weights while the fish swim.
+// While open: PanelUp/PanelDown pick a row, PanelDecrease/PanelIncrease change it,
+// PanelNextSpecies switches species and PanelReset resets the species.
+// The changes last until the settings file is reloaded.
+pub struct TuningPanel {
+    open: bool,
+    // index into the boid manager's species
+    species: usize,
+    // index into ROWS
+    row: usize,
+}
+
+impl TuningPanel {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            species: 0,
+            row: 0,
+        }
+    }
+
+    pub fn open(&self) -> bool { self.open }
+
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    // Only takes the panel's own actions, the rest still go to the sub
+    pub fn process_action(&mut self, action: controls::Action, pressed: bool, boid_manager: &mut boid::BoidManager) -> bool {
+        if !self.open { return false; }
+
+        let species = self.species;
+        // held keys repeat their pressed events, which keeps changing the value
+        match action {
+            controls::Action::PanelUp if pressed => self.row = (self.row + ROWS.len() - 1) % ROWS.len(),
+            controls::Action::PanelDown if pressed => self.row = (self.row + 1) % ROWS.len(),
+            controls::Action::PanelDecrease if pressed => self.adjust(boid_manager.flock_weights_mut(species), -1.0),
+            controls::Action::PanelIncrease if pressed => self.adjust(boid_manager.flock_weights_mut(species), 1.0),
+            controls::Action::PanelNextSpecies if pressed => self.species = (self.species + 1) % boid_manager.species_count(),
+            controls::Action::PanelReset if pressed => *boid_manager.flock_weights_mut(species) = boid::FlockWeights::default(),
+            controls::Action::PanelUp
+            | controls::Action::PanelDown
+            | controls::Action::PanelDecrease
+            | controls::Action::PanelIncrease
+            | controls::Action::PanelNextSpecies
+            | controls::Action::PanelReset => {}
+            _ => return false,
+        }
+        true
+    }
+
+    fn adjust(&self, weights: &mut boid::FlockWeights, dir: f32) {
+        let (value, step) = match self.row {
+            0 => (&mut weights.separation, WEIGHT_STEP),
+            1 => (&mut weights.alignment, WEIGHT_STEP),
+            2 => (&mut weights.cohesion, WEIGHT_STEP),
+            3 => (&mut weights.max_steer_force, MAX_STEER_FORCE_STEP),
+            4 => (&mut weights.wall_force_mult, WEIGHT_STEP),
+            _ => (&mut weights.down_steer_mult, DOWN_STEER_MULT_STEP),
+        };
+        // rounded so repeated steps don't drift away from the step size
+        *value = ((*value + step * dir) / step).round() * step;
+        *value = value.max(0.0);
+    }
+
+    pub fn text(&self, boid_manager: &boid::BoidManager, bindings: &controls::Bindings) -> String {
+        let name = boid_manager.species_name(self.species);
+        let weights = boid_manager.settings().flock_weights(name);
+        let values = [
+            weights.separation,
+            weights.alignment,
+            weights.cohesion,
+            weights.max_steer_force,
+            weights.wall_force_mult,
+            weights.down_steer_mult,
+        ];
+
+        let mut lines = vec![format!("FLOCKING: {}", name.to_uppercase())];
+        for (i, (row_name, value)) in ROWS.iter().zip(values).enumerate() {
+            if i == self.row {
+                lines.push(format!("{}: < {:.2} >", row_name, value));
+            } else {
+                lines.push(format!("{}: {:.2}", row_name, value));
+            }
+        }
+        lines.push(format!(
+            "{}/{}: ROW  {}/{}: CHANGE  {}: SPECIES  {}: RESET",
+            bindings.key_name(controls::Action::PanelUp),
+            bindings.key_name(controls::Action::PanelDown),
+            bindings.key_name(controls::Action::PanelDecrease),
+            bindings.key_name(controls::Action::PanelIncrease),
+            bindings.key_name(controls::Action::PanelNextSpecies),
+            bindings.key_name(controls::Action::PanelReset),
+        ).to_uppercase());
+        lines.join("\n")
+    }
+}
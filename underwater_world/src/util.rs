@@ -56,6 +56,67 @@ impl Tri {
             None
         }
     }
+
+    // Real-Time Collision Detection (Ericson) 5.1.5, checks which vert/edge/face region p is in
+    pub fn closest_point(&self, p: cgmath::Vector3<f32>) -> cgmath::Vector3<f32> {
+        let [a, b, c] = self.verts;
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = p - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0.0 && d2 <= 0.0 { return a; }
+
+        let bp = p - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0.0 && d4 <= d3 { return b; }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = p - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0.0 && d5 <= d6 { return c; }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        // inside the face, zero area tris have no face to be inside of
+        let denom = va + vb + vc;
+        if denom.abs() < EPSILON { return a; }
+        a + ab * (vb / denom) + ac * (vc / denom)
+    }
+}
+
+// A command line option's value, given as `--name=<value>` or `--name <value>`
+#[cfg(not(target_arch = "wasm32"))]
+pub fn arg_value(name: &str) -> Option<String> {
+    find_arg_value(std::env::args().skip(1), name)
+}
+
+// the value after a space can't be another option, so a bare flag followed by one isn't read as having a value
+#[cfg(not(target_arch = "wasm32"))]
+fn find_arg_value(mut args: impl Iterator<Item = String>, name: &str) -> Option<String> {
+    while let Some(arg) = args.next() {
+        if arg == name {
+            return args.next().filter(|value| !value.starts_with("--"));
+        } else if let Some(value) = arg.strip_prefix(name).and_then(|rest| rest.strip_prefix('=')) {
+            return Some(value.to_string());
+        }
+    }
+    None
 }
 
 pub fn safe_normalize(v: cgmath::Vector3<f32>) -> cgmath::Vector3<f32> {
@@ -117,6 +178,14 @@ pub fn to_srgb_decimal(color: [f32; 3]) -> [f32; 3] {
         ((color[2] + 0.055) / 1.055).powf(2.4),
     ]
 }
+// inverse of to_srgb_decimal
+pub fn from_srgb_decimal(color: [f32; 3]) -> [f32; 3] {
+    [
+        color[0].powf(1.0 / 2.4) * 1.055 - 0.055,
+        color[1].powf(1.0 / 2.4) * 1.055 - 0.055,
+        color[2].powf(1.0 / 2.4) * 1.055 - 0.055,
+    ]
+}
 
 // https://github.com/jayber/hsv/blob/main/src/lib.rs
 pub fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> [f32; 3] {
@@ -151,3 +220,24 @@ pub fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> [f32; 3] {
         ((b + m) * 255.0),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(args: &[&str], name: &str) -> Option<String> {
+        find_arg_value(args.iter().map(|arg| arg.to_string()), name)
+    }
+
+    #[test]
+    fn arg_value_forms() {
+        assert_eq!(value(&["--seed=coral cave"], "--seed").as_deref(), Some("coral cave"));
+        assert_eq!(value(&["--seed", "1234"], "--seed").as_deref(), Some("1234"));
+        assert_eq!(value(&["--fish=fish", "--seed", "1234"], "--seed").as_deref(), Some("1234"));
+        // only the whole name, not options it's the start of
+        assert_eq!(value(&["--seeds=1"], "--seed"), None);
+        // a bare flag doesn't take the next option as its value
+        assert_eq!(value(&["--chunk-cache", "--seed=1"], "--chunk-cache"), None);
+        assert_eq!(value(&["--chunk-cache"], "--chunk-cache"), None);
+    }
+}
@@ -1,24 +1,93 @@
-use crate::{camera, chunk, sub, util};
+use crate::{camera, chunk, chunk_cache, density, sub, util};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::chunk_workers;
 use cgmath::InnerSpace;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 const RECHECK_NEARBY_DIST: f32 = 4.0;
 const RECHECK_NEARBY_ANGLE: f32 = 0.33;
 
-pub const VIEW_DIST: i32 = 4;
-const GENERATION_DIST: i32 = 5;
-const KEEP_DIST: i32 = 6;
+// Defaults for WorldSettings
+const VIEW_DIST: i32 = 8;
 pub const MAX_Z: i32 = 2;
 pub const MIN_Z: i32 = -2;
 
-const STOP_FULL_BUILD: i32 = GENERATION_DIST * GENERATION_DIST * GENERATION_DIST;
+// chunks closer than this (in chunks) get the full 12 cells, then 6, then 3
+const FULL_LOD_DIST: f32 = 4.0;
+const HALF_LOD_DIST: f32 = 6.0;
+
+// only the area around the sub is built before the first frames
+#[cfg(target_arch = "wasm32")]
+const FULL_BUILD_DIST: i32 = 5;
+#[cfg(target_arch = "wasm32")]
+const STOP_FULL_BUILD: i32 = FULL_BUILD_DIST * FULL_BUILD_DIST * FULL_BUILD_DIST;
+
+// uploading is the only part left on the main thread
+const MAX_UPLOADS_PER_FRAME: usize = 8;
+
+// wasm has no workers, so edited chunks are remeshed on the main thread
+const MAX_REMESHES_PER_FRAME: usize = 4;
+// and collision is built on it too, for about this long per step
+#[cfg(target_arch = "wasm32")]
+const MAX_COLLISION_BUILD_MS: f64 = 8.0;
 
 const VIEW_FRUST_FOVY: f32 = 55.0;
 const GENERATE_FRUST_FOVY: f32 = 90.0;
 
+
+// The [world] section of the settings file
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct WorldSettings {
+    // in chunks, only read at startup as it also sets the camera's far plane and fog
+    pub view_dist: i32,
+    pub full_lod_dist: f32,
+    pub half_lod_dist: f32,
+    // wasm only
+    pub max_remeshes_per_frame: usize,
+    // native only
+    pub max_uploads_per_frame: usize,
+    // picked up by the chunks built after it changes
+    pub borders: chunk::Borders,
+}
+impl Default for WorldSettings {
+    fn default() -> Self {
+        Self {
+            view_dist: VIEW_DIST,
+            full_lod_dist: FULL_LOD_DIST,
+            half_lod_dist: HALF_LOD_DIST,
+            max_remeshes_per_frame: MAX_REMESHES_PER_FRAME,
+            max_uploads_per_frame: MAX_UPLOADS_PER_FRAME,
+            borders: chunk::Borders::default(),
+        }
+    }
+}
+impl WorldSettings {
+    fn generation_dist(&self) -> i32 { self.view_dist + 1 }
+    fn keep_dist(&self) -> i32 { self.view_dist + 2 }
+
+    // dist is from the sub to the chunk's center
+    fn lod_for_dist(&self, dist: f32) -> chunk::Lod {
+        let chunk_dist = dist / chunk::CHUNK_SIZE as f32;
+        if chunk_dist < self.full_lod_dist {
+            chunk::Lod::Full
+        } else if chunk_dist < self.half_lod_dist {
+            chunk::Lod::Half
+        } else {
+            chunk::Lod::Quarter
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
 struct GeneratingChunk {
     chunk_pos: (i32, i32, i32),
-    chunk: chunk::Chunk,
+    lod: chunk::Lod,
+    edit_version: u64,
+    mesher: chunk::ChunkMesher,
+    // still waiting to hear whether the cache has it
+    cached: Option<chunk_cache::Lookup>,
 }
 
 struct RemoveState {
@@ -35,6 +104,7 @@ struct GenPrio {
     z: f32,
     in_view: bool,
     in_gen: bool,
+    lod: chunk::Lod,
 }
 impl GenPrio {
     fn compare(&self, other: &GenPrio) -> std::cmp::Ordering {
@@ -64,34 +134,89 @@ pub struct World {
     chunks: HashMap<(i32, i32, i32), chunk::Chunk>,
     chunks_to_render: Vec<(i32, i32, i32)>,
     chunks_to_generate: Vec<((i32, i32, i32), GenPrio)>,
-    generating_chunk: Option<GeneratingChunk>,
     remove_state: RemoveState,
+    density: Arc<dyn density::Density>,
+    settings: WorldSettings,
+
+    // bumped on every terrain edit, chunks started before their last edit are out of date.
+    // An edit is forgotten once nothing older than it is loaded or building (see prune_edits)
+    edit_version: u64,
+    chunk_edits: HashMap<(i32, i32, i32), u64>,
+    remesh_queue: Vec<((i32, i32, i32), chunk::Lod)>,
+
+    // What the sub and the fish collide with, with the edit version each was built at.
+    // The chunks above load in their own time and at less detail further away, these are always
+    // full detail and every step waits for the ones around the sub (see update_collision),
+    // so the simulation doesn't depend on how fast the chunks load.
+    collision: HashMap<(i32, i32, i32), (chunk::CollisionChunk, u64)>,
+    // (center, dist) from update_collision, the simulation only sees the collision within it
+    collision_area: Option<((i32, i32, i32), i32)>,
+    // sent to the workers, with the edit version they were sent at
+    #[cfg(not(target_arch = "wasm32"))]
+    collision_requested: HashMap<(i32, i32, i32), u64>,
+
+    // wasm has no threads, so chunks are built a slice at a time between frames
+    #[cfg(target_arch = "wasm32")]
+    cache: Option<chunk_cache::ChunkCache>,
+    #[cfg(target_arch = "wasm32")]
+    generating_chunk: Option<GeneratingChunk>,
+    #[cfg(target_arch = "wasm32")]
     should_full_build: bool,
 
+    #[cfg(not(target_arch = "wasm32"))]
+    workers: chunk_workers::ChunkWorkers,
+
     last_sub_pos: cgmath::Vector3<f32>,
     last_sub_bearing: cgmath::Vector3<f32>,
 }
 
 impl World {
-    pub fn new() -> Self {
+    pub fn new(density: Arc<dyn density::Density>, cache: Option<chunk_cache::ChunkCache>, settings: WorldSettings) -> Self {
         Self {
             chunks: HashMap::new(),
             chunks_to_render: Vec::new(),
             chunks_to_generate: Vec::new(),
-            generating_chunk: None,
             remove_state: RemoveState::new(),
+            density: density.clone(),
+            settings,
+            edit_version: 0,
+            chunk_edits: HashMap::new(),
+            remesh_queue: Vec::new(),
+            collision: HashMap::new(),
+            collision_area: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            collision_requested: HashMap::new(),
+            #[cfg(target_arch = "wasm32")]
+            cache,
+            #[cfg(target_arch = "wasm32")]
+            generating_chunk: None,
+            #[cfg(target_arch = "wasm32")]
             should_full_build: true,
+            #[cfg(not(target_arch = "wasm32"))]
+            workers: chunk_workers::ChunkWorkers::new(density, cache),
             last_sub_pos: cgmath::Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
             last_sub_bearing: cgmath::Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
         }
     }
 
+    // view_dist only changes at startup, the rest is picked up on the next update
+    pub fn set_settings(&mut self, settings: WorldSettings) {
+        if settings.view_dist != self.settings.view_dist {
+            log::warn!("world.view_dist only changes after a restart");
+        }
+        self.settings = WorldSettings { view_dist: self.settings.view_dist, ..settings };
+    }
+
     pub fn get_chunk(&self, pos: (i32, i32, i32)) -> Option<&chunk::Chunk> {
         self.chunks.get(&pos)
     }
 
-    pub fn update(&mut self, sub: &sub::Sub, camera: &camera::Camera, sub_reset: bool, perlin: &noise::Perlin, device: &wgpu::Device) {
+    pub fn density(&self) -> &dyn density::Density { self.density.as_ref() }
+    pub fn settings(&self) -> &WorldSettings { &self.settings }
+
+    pub fn update(&mut self, sub: &sub::Sub, camera: &camera::Camera, sub_reset: bool, device: &wgpu::Device) {
         self.remove_far_way(sub);
+        self.prune_edits();
 
         let dist = (sub.pos() - self.last_sub_pos).magnitude();
         let angle = sub.bearing().angle(self.last_sub_bearing);
@@ -102,48 +227,203 @@ impl World {
             self.last_sub_bearing = sub.bearing();
         }
 
-        if self.should_full_build {
-            self.build_full_step(perlin, device);
-            self.should_full_build = !(self.chunks_to_generate.is_empty() || self.chunks.len() >= STOP_FULL_BUILD as usize);
-        } else {
-            self.build_step(sub, perlin, device);
+        cfg_if::cfg_if! {
+            if #[cfg(target_arch = "wasm32")] {
+                self.build_step(sub, device);
+                if self.should_full_build {
+                    self.should_full_build = !(self.chunks_to_generate.is_empty() || self.chunks.len() >= STOP_FULL_BUILD as usize);
+                }
+                self.remesh_step(sub, device);
+            } else {
+                self.receive_built(sub, device);
+                self.remesh_step();
+            }
         }
     }
 
-    fn build_full_step(&mut self, perlin: &noise::Perlin, device: &wgpu::Device) {
-        if let Some((pos, _gen_prio)) = self.chunks_to_generate.pop() {
-            let mut chunk = chunk::Chunk::new(pos);
-            chunk.build_full(perlin, device);
-            if chunk.not_blank() {
-                self.chunks_to_render.push(pos);
+    // Call after changing the density inside the sphere, the chunks it touches
+    // (and the neighbours whose border samples reach into it) get remeshed
+    pub fn remesh_around(&mut self, center: cgmath::Vector3<f32>, radius: f32) {
+        self.edit_version += 1;
+
+        // the coarsest LOD samples a whole cell past its faces
+        let margin = radius + chunk::CHUNK_SIZE as f32 / chunk::Lod::Quarter.cells() as f32;
+        let chunk_size = chunk::CHUNK_SIZE as f32;
+        let min_x = ((center.x - margin) / chunk_size).floor() as i32;
+        let max_x = ((center.x + margin) / chunk_size).floor() as i32;
+        let min_y = ((center.y - margin) / chunk_size).floor() as i32;
+        let max_y = ((center.y + margin) / chunk_size).floor() as i32;
+        let min_z = ((center.z - margin) / chunk_size).floor() as i32;
+        let max_z = ((center.z + margin) / chunk_size).floor() as i32;
+
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                for z in min_z..=max_z {
+                    let pos = (x, y, z);
+                    // the collision stays until it is rebuilt, the next step waits for that
+                    self.chunk_edits.insert(pos, self.edit_version);
+
+                    if let Some(chunk) = self.chunks.get(&pos) {
+                        let lod = chunk.lod();
+                        self.queue_remesh(pos, lod);
+                    }
+                }
             }
+        }
+    }
 
-            self.chunks.insert(pos, chunk);
+    fn queue_remesh(&mut self, pos: (i32, i32, i32), lod: chunk::Lod) {
+        match self.remesh_queue.iter_mut().find(|(queued_pos, _lod)| *queued_pos == pos) {
+            Some((_pos, queued_lod)) => *queued_lod = (*queued_lod).min(lod),
+            None => self.remesh_queue.push((pos, lod)),
         }
     }
 
-    fn build_step(&mut self, sub: &sub::Sub, perlin: &noise::Perlin, device: &wgpu::Device) {
-        if let Some(generating_chunk) = &mut self.generating_chunk {
-            let finished = generating_chunk.chunk.build_partial(perlin, device);
-            if finished {
-                let pos = generating_chunk.chunk_pos;
-                let sub_chunk = sub.chunk();
-                let dist_sq = util::dist_sq(pos, sub_chunk);
-                if generating_chunk.chunk.not_blank() && dist_sq <= (VIEW_DIST + 1) * (VIEW_DIST + 1) {
-                    self.chunks_to_render.push(pos);
-                }
-                self.chunks.insert(pos, self.generating_chunk.take().unwrap().chunk);
-                self.generating_chunk = None;
+    // a chunk that was started before the terrain around it was last edited
+    fn is_stale(&self, pos: (i32, i32, i32), edit_version: u64) -> bool {
+        self.chunk_edits.get(&pos).is_some_and(|last_edit| *last_edit > edit_version)
+    }
+
+    // Forgets the edits that every chunk and collision at their position was built after,
+    // as long as nothing still building could have been started before them
+    fn prune_edits(&mut self) {
+        cfg_if::cfg_if! {
+            if #[cfg(target_arch = "wasm32")] {
+                let oldest_building = self.generating_chunk.as_ref().map(|generating_chunk| generating_chunk.edit_version);
+            } else {
+                let oldest_building = self.workers.oldest_edit_version();
+            }
+        }
+
+        self.chunk_edits.retain(|pos, last_edit| {
+            let building = oldest_building.is_some_and(|oldest| oldest < *last_edit);
+            let old_chunk = self.chunks.get(pos).is_some_and(|chunk| chunk.edit_version() < *last_edit);
+            let old_collision = self.collision.get(pos).is_some_and(|(_collision, edit_version)| *edit_version < *last_edit);
+            building || old_chunk || old_collision
+        });
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn remesh_step(&mut self, sub: &sub::Sub, device: &wgpu::Device) {
+        let count = self.remesh_queue.len().min(self.settings.max_remeshes_per_frame);
+        for (pos, lod) in self.remesh_queue.drain(..count).collect::<Vec<_>>() {
+            let mesh = chunk::mesh_chunk(pos, lod, self.settings.borders, self.density.as_ref());
+            let chunk = chunk::Chunk::new(pos, mesh, self.edit_version, device);
+            self.insert_chunk(pos, chunk, self.render_when_built(pos, sub));
+        }
+    }
+
+    // ahead of everything else on the workers, they come back through receive_built
+    #[cfg(not(target_arch = "wasm32"))]
+    fn remesh_step(&mut self) {
+        for (pos, lod) in self.remesh_queue.drain(..) {
+            self.workers.add_urgent(chunk_workers::ChunkJob {
+                pos,
+                lod,
+                borders: self.settings.borders,
+                edit_version: self.edit_version,
+                kind: chunk_workers::JobKind::Render,
+            });
+        }
+    }
+
+    // replaces any coarser chunk that was already there
+    fn insert_chunk(&mut self, pos: (i32, i32, i32), chunk: chunk::Chunk, render: bool) {
+        let finer_exists = self.chunks.get(&pos).is_some_and(|existing| existing.lod() < chunk.lod());
+        if finer_exists { return; }
+
+        self.chunks_to_render.retain(|render_pos| *render_pos != pos);
+        if chunk.not_blank() && render {
+            self.chunks_to_render.push(pos);
+        }
+        self.chunks.insert(pos, chunk);
+    }
+
+    fn render_when_built(&self, pos: (i32, i32, i32), sub: &sub::Sub) -> bool {
+        let dist_sq = util::dist_sq(pos, sub.chunk());
+        let generation_dist = self.settings.generation_dist();
+        dist_sq <= generation_dist * generation_dist
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn receive_built(&mut self, sub: &sub::Sub, device: &wgpu::Device) {
+        for _ in 0..self.settings.max_uploads_per_frame {
+            let Some((job, mesh)) = self.workers.try_recv() else { break; };
+            if self.is_stale(job.pos, job.edit_version) {
+                self.queue_remesh(job.pos, job.lod);
+                continue;
             }
-        } else if let Some((pos, _dist)) = self.chunks_to_generate.pop() {
-            let chunk = chunk::Chunk::new(pos);
-            self.generating_chunk = Some(GeneratingChunk { chunk_pos: pos, chunk });
 
-            // Did no work so far on this frame otherwise
-            self.build_step(sub, perlin, device);
+            let chunk = chunk::Chunk::new(job.pos, mesh, job.edit_version, device);
+            self.insert_chunk(job.pos, chunk, self.render_when_built(job.pos, sub));
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn store_cached(&self, pos: (i32, i32, i32), mesh: &chunk::ChunkMesh) {
+        if let Some(cache) = &self.cache {
+            if !self.density.cacheable(pos) { return; }
+            cache.store(pos, mesh.lod, mesh);
         }
     }
 
+    // One chunk at a time, split over frames once the area around the sub is built
+    #[cfg(target_arch = "wasm32")]
+    fn build_step(&mut self, sub: &sub::Sub, device: &wgpu::Device) {
+        if self.generating_chunk.is_none() {
+            let Some((pos, gen_prio)) = self.chunks_to_generate.pop() else { return; };
+            let cached = self.cache.as_ref()
+                .filter(|_cache| self.density.cacheable(pos))
+                .map(|cache| cache.start_load(pos, gen_prio.lod, self.settings.borders));
+
+            self.generating_chunk = Some(GeneratingChunk {
+                chunk_pos: pos,
+                lod: gen_prio.lod,
+                edit_version: self.edit_version,
+                mesher: chunk::ChunkMesher::new(pos, gen_prio.lod, self.settings.borders),
+                cached,
+            });
+        }
+        let generating_chunk = self.generating_chunk.as_mut().unwrap();
+
+        let mut cached_mesh = None;
+        if let (Some(lookup), Some(cache)) = (&generating_chunk.cached, &self.cache) {
+            match cache.poll(lookup) {
+                chunk_cache::Loaded::Waiting => return,
+                chunk_cache::Loaded::Hit(mesh) => cached_mesh = Some(mesh),
+                chunk_cache::Loaded::Miss => generating_chunk.cached = None,
+            }
+        }
+
+        if cached_mesh.is_none() {
+            let finished = if self.should_full_build {
+                generating_chunk.mesher.build_full(self.density.as_ref());
+                true
+            } else {
+                generating_chunk.mesher.build_partial(self.density.as_ref())
+            };
+            if !finished { return; }
+        }
+
+        let generating_chunk = self.generating_chunk.take().unwrap();
+        let pos = generating_chunk.chunk_pos;
+        if self.is_stale(pos, generating_chunk.edit_version) {
+            self.queue_remesh(pos, generating_chunk.lod);
+            return;
+        }
+
+        let mesh = match cached_mesh {
+            Some(mesh) => mesh,
+            None => {
+                let mesh = generating_chunk.mesher.into_mesh();
+                self.store_cached(pos, &mesh);
+                mesh
+            }
+        };
+        let chunk = chunk::Chunk::new(pos, mesh, generating_chunk.edit_version, device);
+        self.insert_chunk(pos, chunk, self.render_when_built(pos, sub));
+    }
+
 
     pub fn update_nearby(&mut self, sub: &sub::Sub, camera: &camera::Camera) {
         self.chunks_to_render.clear();
@@ -155,16 +435,19 @@ impl World {
         let view_view_proj = camera.chunk_generation_frustum_matrix(VIEW_FRUST_FOVY);
         let gen_view_proj = camera.chunk_generation_frustum_matrix(GENERATE_FRUST_FOVY);
 
-        let max_view_dist = VIEW_DIST as f32 * chunk::CHUNK_SIZE as f32;
-        let max_generation_dist = GENERATION_DIST as f32 * chunk::CHUNK_SIZE as f32;
+        let generating = self.generating();
+
+        let generation_dist = self.settings.generation_dist();
+        let max_view_dist = self.settings.view_dist as f32 * chunk::CHUNK_SIZE as f32;
+        let max_generation_dist = generation_dist as f32 * chunk::CHUNK_SIZE as f32;
 
-        let start_z = (sub_chunk.2 - GENERATION_DIST).max(MIN_Z);
-        let end_z =   (sub_chunk.2 + GENERATION_DIST).min(MAX_Z);
+        let start_z = (sub_chunk.2 - generation_dist).max(MIN_Z);
+        let end_z =   (sub_chunk.2 + generation_dist).min(MAX_Z);
 
-        for x in -GENERATION_DIST..GENERATION_DIST {
+        for x in -generation_dist..generation_dist {
             let chunk_x = sub_chunk.0 + x;
 
-            for y in -GENERATION_DIST..GENERATION_DIST {
+            for y in -generation_dist..generation_dist {
                 let chunk_y = sub_chunk.1 + y;
 
                 for chunk_z in start_z..=end_z {
@@ -207,22 +490,30 @@ impl World {
                     }
 
                     let chunk_pos = (chunk_x, chunk_y, chunk_z);
+                    let lod = self.settings.lod_for_dist(dist);
 
-                    match self.get_chunk(chunk_pos) {
+                    // the coarse chunk is still drawn until the finer one is done
+                    let needs_generating = match self.chunks.get(&chunk_pos) {
                         Some(chunk) => {
                             if dist < max_view_dist && chunk.not_blank() && in_view {
                                 self.chunks_to_render.push(chunk_pos);
                             }
+                            chunk.lod() > lod
                         }
-                        None => {
-                            let gen_prio = GenPrio {
-                                dist,
-                                z: chunk_z as f32 * chunk::CHUNK_SIZE as f32,
-                                in_view,
-                                in_gen,
-                            };
-                            self.chunks_to_generate.push((chunk_pos, gen_prio));
-                        }
+                        None => true,
+                    };
+                    let already_generating = generating.iter()
+                        .any(|(generating_pos, generating_lod)| *generating_pos == chunk_pos && *generating_lod <= lod);
+
+                    if needs_generating && !already_generating {
+                        let gen_prio = GenPrio {
+                            dist,
+                            z: chunk_z as f32 * chunk::CHUNK_SIZE as f32,
+                            in_view,
+                            in_gen,
+                            lod,
+                        };
+                        self.chunks_to_generate.push((chunk_pos, gen_prio));
                     }
                 }
             }
@@ -231,6 +522,36 @@ impl World {
         self.chunks_to_generate.sort_unstable_by(|(_pos1, gen_prio1), (_pos2, gen_prio2)| {
             gen_prio2.compare(gen_prio1)
         });
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let jobs = self.chunks_to_generate.drain(..)
+                .map(|(pos, gen_prio)| chunk_workers::ChunkJob {
+                    pos,
+                    lod: gen_prio.lod,
+                    borders: self.settings.borders,
+                    edit_version: self.edit_version,
+                    kind: chunk_workers::JobKind::Render,
+                })
+                .collect();
+            self.workers.set_jobs(jobs);
+        }
+    }
+
+    // (pos, lod) of the chunks being built right now
+    fn generating(&self) -> Vec<((i32, i32, i32), chunk::Lod)> {
+        cfg_if::cfg_if! {
+            if #[cfg(target_arch = "wasm32")] {
+                self.generating_chunk.iter()
+                    .map(|generating_chunk| (generating_chunk.chunk_pos, generating_chunk.lod))
+                    .collect()
+            } else {
+                self.workers.building().iter()
+                    .filter(|job| job.kind == chunk_workers::JobKind::Render)
+                    .map(|job| (job.pos, job.lod))
+                    .collect()
+            }
+        }
     }
 
     fn remove_far_way(&mut self, sub: &sub::Sub) {
@@ -238,7 +559,8 @@ impl World {
             let sub_chunk = sub.chunk();
             let dist_sq = util::dist_sq(pos, sub_chunk);
 
-            if dist_sq >= KEEP_DIST * KEEP_DIST {
+            let keep_dist = self.settings.keep_dist();
+            if dist_sq >= keep_dist * keep_dist {
                 self.chunks.remove(&pos);
             }
         } else {
@@ -246,9 +568,167 @@ impl World {
         }
     }
 
+    // Call before every step with the chunk the sub is in. False until the collision of every chunk
+    // within dist is built and up to date, the step has to wait until then. The ring past dist
+    // is built ahead of time so moving into it doesn't wait, and the rest is dropped.
+    pub fn update_collision(&mut self, center: (i32, i32, i32), dist: i32) -> bool {
+        self.collision_area = Some((center, dist));
+        #[cfg(not(target_arch = "wasm32"))]
+        self.receive_collision();
+
+        let keep_dist = dist + 1;
+        self.collision.retain(|pos, _collision| util::dist_sq(*pos, center) <= keep_dist * keep_dist);
+
+        // closest first
+        let mut missing = Vec::new();
+        for x in -keep_dist..=keep_dist {
+            for y in -keep_dist..=keep_dist {
+                for z in MIN_Z..=MAX_Z {
+                    let pos = (center.0 + x, center.1 + y, z);
+                    let dist_sq = util::dist_sq(pos, center);
+                    if dist_sq > keep_dist * keep_dist { continue; }
+
+                    let up_to_date = self.collision.get(&pos)
+                        .is_some_and(|(_collision, edit_version)| !self.is_stale(pos, *edit_version));
+                    if !up_to_date {
+                        missing.push((dist_sq, pos));
+                    }
+                }
+            }
+        }
+        missing.sort_unstable();
+
+        let missing = self.build_collision(missing.into_iter().map(|(_dist_sq, pos)| pos));
+        missing.iter().all(|pos| util::dist_sq(*pos, center) > dist * dist)
+    }
+
+    // A full detail chunk that's already drawn is used as it is, the rest go to the workers.
+    // Gives back the ones that aren't built yet
+    #[cfg(not(target_arch = "wasm32"))]
+    fn build_collision(&mut self, missing: impl Iterator<Item = (i32, i32, i32)>) -> Vec<(i32, i32, i32)> {
+        let mut still_missing = Vec::new();
+        for pos in missing {
+            if let Some(collision) = self.collision_from_chunk(pos) {
+                self.collision.insert(pos, collision);
+                continue;
+            }
+
+            still_missing.push(pos);
+            let requested = self.collision_requested.get(&pos).is_some_and(|edit_version| !self.is_stale(pos, *edit_version));
+            if !requested {
+                self.collision_requested.insert(pos, self.edit_version);
+                self.workers.add_urgent(chunk_workers::ChunkJob {
+                    pos,
+                    lod: chunk::Lod::Full,
+                    borders: self.settings.borders,
+                    edit_version: self.edit_version,
+                    kind: chunk_workers::JobKind::Collision,
+                });
+            }
+        }
+        still_missing
+    }
+
+    // wasm has no workers, so they're built here until the time is up
+    #[cfg(target_arch = "wasm32")]
+    fn build_collision(&mut self, missing: impl Iterator<Item = (i32, i32, i32)>) -> Vec<(i32, i32, i32)> {
+        let start = instant::now();
+        let mut still_missing = Vec::new();
+        for pos in missing {
+            let collision = match self.collision_from_chunk(pos) {
+                Some(collision) => collision,
+                None if instant::now() - start < MAX_COLLISION_BUILD_MS => {
+                    let mesh = chunk::mesh_chunk(pos, chunk::Lod::Full, self.settings.borders, self.density.as_ref());
+                    (chunk::CollisionChunk::new(mesh), self.edit_version)
+                }
+                None => {
+                    still_missing.push(pos);
+                    continue;
+                }
+            };
+            self.collision.insert(pos, collision);
+        }
+        still_missing
+    }
+
+    // with the chunk's edit version, unless it is still waiting on its remesh
+    fn collision_from_chunk(&self, pos: (i32, i32, i32)) -> Option<(chunk::CollisionChunk, u64)> {
+        let chunk = self.chunks.get(&pos)?;
+        let usable = chunk.lod() == chunk::Lod::Full
+            && chunk.mesh().borders == self.settings.borders
+            && !self.is_stale(pos, chunk.edit_version());
+        usable.then(|| (chunk::CollisionChunk::from_chunk(chunk), chunk.edit_version()))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn receive_collision(&mut self) {
+        while let Some((job, mesh)) = self.workers.try_recv_collision() {
+            if self.collision_requested.get(&job.pos) == Some(&job.edit_version) {
+                self.collision_requested.remove(&job.pos);
+            }
+            // stale ones are asked for again by update_collision
+            if self.is_stale(job.pos, job.edit_version) { continue; }
+            self.collision.insert(job.pos, (chunk::CollisionChunk::new(mesh), job.edit_version));
+        }
+    }
+
+    // Whether the simulation has the chunk loaded, which is every chunk in the area once
+    // update_collision has said it's ready
+    pub fn has_collision(&self, pos: (i32, i32, i32)) -> bool {
+        self.in_collision_area(pos) && self.collision.contains_key(&pos)
+    }
+
+    fn in_collision_area(&self, pos: (i32, i32, i32)) -> bool {
+        self.collision_area.is_some_and(|(center, dist)| util::dist_sq(pos, center) <= dist * dist)
+    }
+
+    // every full detail triangle within about range of pos, from the chunks update_collision last waited for.
+    // Collision built ahead of time past them is left out, it's there sooner on some runs than others
+    pub fn tris_near(&self, pos: cgmath::Vector3<f32>, range: f32) -> Vec<util::Tri> {
+        let chunk_size = chunk::CHUNK_SIZE as f32;
+        let cell_size = chunk_size / chunk::Lod::Full.cells() as f32;
+        let cell_range = (range / cell_size).ceil() as i32;
+
+        let (start, end) = chunks_in_range(pos, range);
+        let mut tris = Vec::new();
+        for x in start.0..=end.0 {
+            for y in start.1..=end.1 {
+                for z in start.2..=end.2 {
+                    if !self.in_collision_area((x, y, z)) { continue; }
+                    let Some((collision, _edit_version)) = self.collision.get(&(x, y, z)) else { continue; };
+
+                    let local_pos_percent = (
+                        pos.x / chunk_size - x as f32,
+                        pos.y / chunk_size - y as f32,
+                        pos.z / chunk_size - z as f32,
+                    );
+                    tris.extend(collision.tris_around(local_pos_percent, cell_range));
+                }
+            }
+        }
+        tris
+    }
+
+    pub fn chunks(&self) -> impl Iterator<Item = (&(i32, i32, i32), &chunk::Chunk)> { self.chunks.iter() }
     pub fn chunks_to_render(&self) -> &[(i32, i32, i32)] { &self.chunks_to_render }
 
-    pub fn generate_count(&self) -> usize { self.chunks_to_generate.len() }
+    pub fn generate_count(&self) -> usize {
+        cfg_if::cfg_if! {
+            if #[cfg(target_arch = "wasm32")] {
+                self.chunks_to_generate.len()
+            } else {
+                self.workers.count()
+            }
+        }
+    }
     pub fn render_count(&self) -> usize { self.chunks_to_render.len() }
     pub fn total_count(&self) -> usize { self.chunks.len() }
-}
\ No newline at end of file
+}
+
+// the first and last chunk touched by the box range around pos
+fn chunks_in_range(pos: cgmath::Vector3<f32>, range: f32) -> ((i32, i32, i32), (i32, i32, i32)) {
+    let chunk_size = chunk::CHUNK_SIZE as f32;
+    let start = (pos.map(|v| v - range) / chunk_size).map(|v| v.floor() as i32);
+    let end = (pos.map(|v| v + range) / chunk_size).map(|v| v.floor() as i32);
+    (start.into(), end.into())
+}